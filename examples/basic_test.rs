@@ -1,7 +1,13 @@
 use std::time::Duration;
 
 use async_std::task::sleep;
-use bevy::{ ecs::world::{ Command, CommandQueue }, prelude::*, tasks::IoTaskPool };
+use bevy::{
+    app::ScheduleRunnerPlugin,
+    ecs::world::{ Command, CommandQueue },
+    log::LogPlugin,
+    prelude::*,
+    tasks::IoTaskPool,
+};
 
 use bevy_lazy_signals::{ api::LazySignals, LazySignalsPlugin, StaticStrRef };
 
@@ -59,7 +65,11 @@ type MyAuthArgs = MyClosureArgs;
 
 fn main() {
     App::new()
-        .add_plugins(DefaultPlugins)
+        // no window or renderer needed, just run the schedules at roughly 60 fps and log to stdout
+        .add_plugins((
+            MinimalPlugins.set(ScheduleRunnerPlugin::run_loop(Duration::from_secs_f64(1.0 / 60.0))),
+            LogPlugin::default(),
+        ))
         // resource to simulate something external to update
         .init_resource::<MyExampleAuthResource>()
         // resource to hold the entity ID of each lazy signals primitive
@@ -84,7 +94,7 @@ fn effect_system(example_auth_resource: Res<MyExampleAuthResource>) {
 
 fn init(mut test: ResMut<MyTestResource>, mut commands: Commands) {
     // create a signal (you need to register data types if not `bool`, `i32`, `f64`, or `&'static str`)
-    // (see `LazySignalsPlugin`)
+    // (add them with `LazySignalsPlugin::with_types::<(MyType, MyOtherType)>()` in `main`)
 
     // this will reflect a `LazySignalsState<T>` type based on the provided concrete `T`
 
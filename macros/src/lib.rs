@@ -0,0 +1,53 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{ parse_macro_input, Data, DeriveInput, Error, Fields };
+
+/// ## LazySignalsParams
+/// Derive `LazySignalsArgs` for a struct with named fields so it can be used as the args of a
+/// computed memo or effect instead of a tuple. Field N gets the value of `sources[N]`, so declare
+/// the fields in the same order as the sources. The struct must also derive `Reflect`.
+#[proc_macro_derive(LazySignalsParams)]
+pub fn derive_lazy_signals_params(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let fields = match &input.data {
+        Data::Struct(data) =>
+            match &data.fields {
+                Fields::Named(fields) => &fields.named,
+                _ => {
+                    return Error::new_spanned(
+                        &input.ident,
+                        "LazySignalsParams needs named fields, use a tuple for positional args"
+                    )
+                        .to_compile_error()
+                        .into();
+                }
+            }
+        _ => {
+            return Error::new_spanned(&input.ident, "LazySignalsParams can only derive a struct")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let len = fields.len();
+    let idents = fields.iter().map(|field| &field.ident);
+    let indices = 0..len;
+
+    let lazy_signals = quote! { ::bevy_lazy_signals::framework };
+    (
+        quote! {
+            impl #impl_generics #lazy_signals::LazySignalsArgs for #name #ty_generics #where_clause {
+                const LEN: usize = #len;
+
+                fn from_args(args: &#lazy_signals::DynamicTuple) -> Option<Self> {
+                    Some(Self {
+                        #( #idents: #lazy_signals::arg_at(args, #indices)?, )*
+                    })
+                }
+            }
+        }
+    ).into()
+}
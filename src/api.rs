@@ -1,14 +1,102 @@
-use std::sync::Mutex;
+use std::{
+    borrow::Cow,
+    marker::PhantomData,
+    ops::{ Add, Mul, Sub },
+    sync::{ atomic::{ AtomicBool, Ordering }, Arc, Mutex },
+    time::Duration,
+};
 
-use bevy::{ ecs::system::BoxedSystem, prelude::* };
+use bevy::{
+    core::FrameCount,
+    ecs::{ component::Tick, system::BoxedSystem, world::Command },
+    input::InputSystem,
+    prelude::*,
+    reflect::DynamicTuple,
+};
 
 use crate::{
-    arcane_wizardry::make_tuple,
-    commands::LazySignalsCommandsExt,
+    arcane_wizardry::{ is_ready, make_args, make_unwrapped_args },
+    backpressure::{ Backpressure, SignalBackpressure },
+    buffer::TimeWindow,
+    clock::now,
+    clock_sync::SendTimestampedCommand,
+    commands::{
+        guard_signal,
+        merge_signal_reflect,
+        validate_reflect,
+        validate_signal,
+        LazySignalsCommandsExt,
+        PassiveSourcesCommand,
+        ResetSignalCommand,
+    },
+    context::{ in_active_context, subgraph_of, ActiveSubgraph, SignalContext },
+    cooldown::Cooldown,
+    dead_letters::{ dead_letter, DeadLetterReason },
+    despawn::despawn_with_policy,
+    family::{ LazySignalsFamily, LazySignalsFamilyKey, LazySignalsMemoKeys, MemoEviction },
     framework::*,
+    fsm::FsmBuilder,
+    handles::{ ReadSignal, RwSignal, WriteSignal },
+    hot_reload::{
+        CreateNamedComputedCommand,
+        CreateNamedEffectCommand,
+        RegisterComputedCommand,
+        RegisterEffectCommand,
+    },
+    interpolation::{ Interpolate, InterpolatedSignal },
+    introspection::{
+        analyze_graph,
+        enumerate_signals,
+        tagged,
+        GraphReport,
+        LazySignalsMeta,
+        SignalInfo,
+    },
+    leaks::LastRead,
+    namespace::{ LazySignalsNamespace, NamespaceSnapshot },
+    optimistic::SendOptimisticCommand,
+    processing::{ in_active_class, ProcessingClass },
+    retry::{ resolve, Resolve, RetryPolicy },
+    sequence::{ cancel_sequence, start_sequence, LazySignalsSequence },
+    spatial::WithinDistance,
     lazy_immutable::{ LazySignalsImmutable, LazySignalsState },
+    middleware::run_middleware,
+    LazySignalsFlush,
+    LazySignalsSystemSet,
 };
 
+#[cfg(feature = "assets")]
+use crate::assets::LazySignalsAssetsExt;
+
+#[cfg(feature = "async")]
+use bevy::tasks::futures_lite::Stream;
+
+#[cfg(feature = "savegame")]
+use bevy::reflect::GetTypeRegistration;
+
+#[cfg(feature = "net")]
+use crate::fetch::{ fetch, Fetch };
+
+#[cfg(feature = "localization")]
+use crate::localization::{ Localization, LocalizedBy };
+
+#[cfg(feature = "savegame")]
+use crate::savegame::{ RegisterMigrationCommand, SchemaVersionCommand };
+
+#[cfg(feature = "camera")]
+use crate::spatial::ScreenPositionOf;
+
+#[cfg(feature = "async")]
+use crate::stream::{ from_stream, into_stream };
+
+/// Upper bound on the passes `LazySignals.flush` makes before giving up on a graph that never settles.
+const MAX_FLUSH_PASSES: usize = 100;
+
+type PendingFilter = (
+    Or<(With<InitDependencies>, With<SendSignal>, With<ComputeMemo>, With<DeferredEffect>)>,
+    Without<Paused>,
+);
+
 /// This is the reference user API, patterned after the TC39 proposal.
 pub fn make_effect_with<P: LazySignalsArgs>(
     mut closure: impl Effect<P>
@@ -16,7 +104,7 @@ pub fn make_effect_with<P: LazySignalsArgs>(
     Mutex::new(
         Box::new(move |tuple, world| {
             trace!("-running effect context with args {:?}", tuple);
-            closure(make_tuple::<P>(tuple), world)
+            closure(make_args::<P>(tuple), world)
         })
     )
 }
@@ -27,10 +115,40 @@ pub fn make_computed_with<P: LazySignalsArgs, R: LazySignalsData>(
     Mutex::new(
         Box::new(move |tuple, entity, world| {
             trace!("-running computed context with args {:?}", tuple);
-            let result = closure(make_tuple::<P>(tuple));
-            if let Some(error) = result.error {
+            let result = closure(make_args::<P>(tuple));
+            if let Some(error) = &result.error {
                 // TODO process errors
-                error!("ERROR running computed: {}", error.to_string());
+                error!("ERROR running computed {}: {}", entity_label(*entity, world), error);
+            }
+            store_result::<R>(result, entity, world)
+        })
+    )
+}
+
+pub fn make_unwrapped_effect_with<P: LazySignalsArgs>(
+    mut closure: impl Effect<P>
+) -> Mutex<Box<dyn EffectWrapper>> {
+    Mutex::new(
+        Box::new(move |tuple, world| {
+            trace!("-running unwrapped effect context with args {:?}", tuple);
+            closure(make_unwrapped_args::<P>(tuple)?, world)
+        })
+    )
+}
+
+pub fn make_unwrapped_computed_with<P: LazySignalsArgs, R: LazySignalsData>(
+    closure: impl Computed<P, R>
+) -> Mutex<Box<dyn ComputedContext>> {
+    Mutex::new(
+        Box::new(move |tuple, entity, world| {
+            trace!("-running unwrapped computed context with args {:?}", tuple);
+            // keep the last value until every source has one
+            let Some(args) = make_unwrapped_args::<P>(tuple) else {
+                return false;
+            };
+            let result = closure(args);
+            if let Some(error) = &result.error {
+                error!("ERROR running computed {}: {}", entity_label(*entity, world), error);
             }
             store_result::<R>(result, entity, world)
         })
@@ -43,7 +161,36 @@ pub fn make_action_with<P: LazySignalsArgs>(
     Mutex::new(
         Box::new(move |tuple| {
             trace!("-running task context with args {:?}", tuple);
-            closure(make_tuple::<P>(tuple))
+            closure(make_args::<P>(tuple))
+        })
+    )
+}
+
+pub fn make_pooled_effect_with<P: LazySignalsArgs>(
+    closure: impl PooledEffect<P>
+) -> Arc<dyn PooledEffectWrapper> {
+    Arc::new(move |tuple: &DynamicTuple| {
+        trace!("-running pooled effect context with args {:?}", tuple);
+        closure(make_args::<P>(tuple))
+    })
+}
+
+pub fn make_tracked_computed_with<R: LazySignalsData>(
+    closure: impl TrackedComputed<R>
+) -> Mutex<Box<dyn ComputedContext>> {
+    Mutex::new(
+        Box::new(move |_tuple, entity, world| {
+            trace!("-running tracked computed context");
+            let mut context = TrackingContext::new(world);
+            let result = closure(&mut context);
+            let sources = context.into_sources();
+            if let Some(error) = &result.error {
+                error!("ERROR running computed {}: {}", entity_label(*entity, world), error);
+            }
+            if let Some(mut tracked) = world.get_mut::<TrackedSources>(*entity) {
+                tracked.sources = sources;
+            }
+            store_result::<R>(result, entity, world)
         })
     )
 }
@@ -56,13 +203,73 @@ pub fn store_result<T: LazySignalsData>(
 ) -> bool {
     let mut entity = world.entity_mut(*entity);
     let mut component = entity.get_mut::<LazySignalsState<T>>().unwrap();
-    component.update(data)
+    // compute_memos marks the component changed if the value actually changed
+    component.bypass_change_detection().update(data)
+}
+
+/// Convenience function to make a `Computed` of two sources of the same type, which has no value
+/// unless both of them do.
+fn combine<T: LazySignalsData, R: LazySignalsData>(
+    a: Entity,
+    b: Entity,
+    op: impl Fn(T, T) -> R + Send + Sync + 'static,
+    commands: &mut Commands
+) -> Entity {
+    LazySignals.computed::<(Option<T>, Option<T>), R>(
+        move |(a, b)| LazySignals::option(a.zip(b).map(|(a, b)| op(a, b))),
+        vec![a, b],
+        commands
+    )
+}
+
+/// Whether anything the flush passes would process is still marked among the entities the filter
+/// accepts. Primitives of another context and effects of another processing class are left out,
+/// since only their own chain or class pass clears their markers.
+fn has_pending(world: &mut World, within: impl Fn(Entity) -> bool) -> bool {
+    let mut query_pending = world.query_filtered::<Entity, PendingFilter>();
+    query_pending.iter(world).any(|entity| {
+        within(entity) && in_active_context(entity, world) && in_active_class(entity, world)
+    })
 }
 
 /// ## Main Signal primitive factory.
 /// Convenience functions for `Signal` creation and manipulation inspired by the TC39 proposal.
 pub struct LazySignals;
 impl LazySignals {
+    /// Create a `Computed` of `a + b`.
+    pub fn add<T: LazySignalsData + Add<Output = T>>(
+        &self,
+        a: Entity,
+        b: Entity,
+        commands: &mut Commands
+    ) -> Entity {
+        combine::<T, T>(a, b, |a, b| a + b, commands)
+    }
+
+    /// Create a `Computed` that forwards the value of `target`, so many consumers can read and
+    /// subscribe to it as if it were the target, e.g. the settings of the current profile. Point
+    /// it at another `Signal` or `Computed` of the same type with `repoint`, and every consumer
+    /// follows without being retargeted.
+    pub fn alias<T: LazySignalsData>(&self, target: Entity, commands: &mut Commands) -> Entity {
+        let alias = self.computed::<(Option<T>,), T>(
+            |(value,)| LazySignals::option(value),
+            vec![target],
+            commands
+        );
+        commands.entity(alias).insert(InitialEvaluation(true));
+        alias
+    }
+
+    /// Analyze the depth, fan-out, cycles, and unreachable nodes of the dependency graph.
+    pub fn analyze(&self, world: &World) -> GraphReport {
+        analyze_graph(world)
+    }
+
+    /// Create a `Computed` of `a && b`.
+    pub fn and(&self, a: Entity, b: Entity, commands: &mut Commands) -> Entity {
+        combine::<bool, bool>(a, b, |a, b| a && b, commands)
+    }
+
     /// Create an `Action` that will run as an `AsyncTask`.
     pub fn action<P: LazySignalsArgs>(
         &self,
@@ -76,11 +283,125 @@ impl LazySignals {
         entity
     }
 
+    /// Give a `Signal` a `Backpressure` policy for bursts of sends, e.g. one fed by a network
+    /// bridge that may deliver hundreds of updates in a frame. Replaces any earlier policy.
+    pub fn backpressure(&self, signal: Entity, policy: Backpressure, commands: &mut Commands) {
+        commands.entity(signal).insert(SignalBackpressure::new(policy));
+    }
+
+    /// Create an `Effect` that copies the value of `source` into a field of an asset (typically a
+    /// custom `Material`, e.g. a dissolve amount or team color) whenever it changes.
+    #[cfg(feature = "assets")]
+    pub fn bind_material_field<M: Asset, T: LazySignalsData>(
+        &self,
+        source: Entity,
+        material: Handle<M>,
+        setter: impl Fn(&mut M, T) + Send + Sync + 'static,
+        commands: &mut Commands
+    ) -> Entity {
+        self.effect::<(Option<T>,)>(
+            move |args, world| {
+                if let Some(value) = args.0 {
+                    match world.asset_mut(&material) {
+                        Some(asset) => setter(asset, value),
+                        None => warn!("bound material {:?} is not loaded", material),
+                    }
+                }
+                None
+            },
+            vec![source],
+            Vec::<Entity>::new(),
+            commands
+        )
+    }
+
     /// Create a `BoxedSystem` to be chained after the `Effect` that returns it.
     pub fn box_system<M>(&self, effect_system: impl IntoSystem<(), (), M>) -> Option<BoxedSystem> {
         Some(Box::new(IntoSystem::into_system(effect_system)))
     }
 
+    /// Create a `Vec<T>` signal holding the last `count` values of `source`, oldest first.
+    /// `Vec<T>` must be registered with `register_signal_type`.
+    pub fn buffer_count<T: LazySignalsData>(
+        &self,
+        source: Entity,
+        count: usize,
+        commands: &mut Commands
+    ) -> Entity {
+        let buffer = self.state(Vec::<T>::new(), commands);
+        self.effect::<(Option<T>,)>(
+            move |(data,), world| {
+                let (Some(data), Some(mut values)) = (
+                    data,
+                    LazySignals.value::<Vec<T>>(buffer, world),
+                ) else {
+                    return None;
+                };
+                values.push(data);
+                if values.len() > count {
+                    values.drain(..values.len() - count);
+                }
+                LazySignals.send_now(buffer, values, world);
+                None
+            },
+            vec![source],
+            vec![],
+            commands
+        );
+        buffer
+    }
+
+    /// Create a `Vec<T>` signal holding the values `source` was sent during the last `window`
+    /// (measured with the `LazySignalsClock`, or `Time`), oldest first. `Vec<T>` must be
+    /// registered with `register_signal_type`.
+    pub fn buffer_time<T: LazySignalsData>(
+        &self,
+        source: Entity,
+        window: Duration,
+        commands: &mut Commands
+    ) -> Entity {
+        let buffer = self.state(Vec::<T>::new(), commands);
+        commands.entity(buffer).insert(TimeWindow::new(window));
+        self.effect::<(Option<T>,)>(
+            move |(data,), world| {
+                let (Some(data), Some(mut values)) = (
+                    data,
+                    LazySignals.value::<Vec<T>>(buffer, world),
+                ) else {
+                    return None;
+                };
+                let now = now(world);
+                if let Some(mut window) = world.get_mut::<TimeWindow>(buffer) {
+                    window.push(now);
+                }
+                values.push(data);
+                LazySignals.send_now(buffer, values, world);
+                None
+            },
+            vec![source],
+            vec![],
+            commands
+        );
+        buffer
+    }
+
+    /// Create a `Computed` of the source value clamped between `min` and `max`.
+    pub fn clamp<T: LazySignalsData + PartialOrd + Copy>(
+        &self,
+        source: Entity,
+        min: T,
+        max: T,
+        commands: &mut Commands
+    ) -> Entity {
+        self.lens::<T, T>(
+            source,
+            move |value| {
+                if *value < min { min } else if *value > max { max } else { *value }
+            },
+            commands
+        )
+    }
+
     /// Create a `Computed` that passes its sources to and evaluate a closure, memoizing the result.
     pub fn computed<P: LazySignalsArgs, R: LazySignalsData>(
         &self,
@@ -93,6 +414,189 @@ impl LazySignals {
         entity
     }
 
+    /// Create a `Computed` from a fn registered with `register_computed`, so it picks up the new fn
+    /// when the name is registered again after a hot reload.
+    pub fn computed_by_name<P: LazySignalsArgs, R: LazySignalsData>(
+        &self,
+        name: impl Into<Cow<'static, str>>,
+        sources: Vec<Entity>,
+        commands: &mut Commands
+    ) -> Entity {
+        let computed = commands.spawn_empty().id();
+        commands.add(CreateNamedComputedCommand::<P, R> {
+            computed,
+            name: name.into(),
+            sources,
+            args_type: PhantomData,
+            result_type: PhantomData,
+        });
+        computed
+    }
+
+    /// Create a `Computed` like `LazySignals.computed`, unless one was already made with the same
+    /// stable function key, types, and sources, in which case that memo is returned instead. E.g.
+    /// a widget factory spawning hundreds of rows bound to the same settings can share one memo.
+    /// The key must identify what the closure computes, since a second closure is dropped unused.
+    pub fn computed_keyed<P: LazySignalsArgs, R: LazySignalsData>(
+        &self,
+        key: impl Into<Cow<'static, str>>,
+        closure: impl Computed<P, R>,
+        sources: Vec<Entity>,
+        keys: &mut LazySignalsMemoKeys,
+        commands: &mut Commands
+    ) -> Entity {
+        keys.get_or_create::<P, R>(key.into(), sources, |sources| {
+            self.computed::<P, R>(closure, sources, commands)
+        })
+    }
+
+    /// Create a `Computed` that also writes its value into a component of `target` whenever it
+    /// changes, collapsing the usual memo and effect pair into one node. It is evaluated as soon as
+    /// it is created so the target starts out in sync.
+    pub fn computed_into<C: Component, P: LazySignalsArgs, R: LazySignalsData>(
+        &self,
+        target: Entity,
+        propagator_closure: impl Computed<P, R>,
+        sources: Vec<Entity>,
+        writer: impl Fn(&mut C, &R) + Send + Sync + 'static,
+        commands: &mut Commands
+    ) -> Entity {
+        let entity = self.computed::<P, R>(propagator_closure, sources, commands);
+        let write: WriteThroughFn = Arc::new(move |computed, world| {
+            let Some(value) = world.get::<LazySignalsState<R>>(computed).and_then(|s| s.get()) else {
+                return;
+            };
+            match world.get_mut::<C>(target) {
+                Some(mut component) => writer(&mut component, &value),
+                None =>
+                    warn!(
+                        "{} has no {} to write {} into",
+                        entity_label(target, world),
+                        std::any::type_name::<C>(),
+                        entity_label(computed, world)
+                    ),
+            }
+        });
+        commands.entity(entity).insert((WriteThrough { write }, InitialEvaluation(true)));
+        entity
+    }
+
+    /// Create a `Computed` whose closure gets the values of its sources instead of `Option`s of
+    /// them, e.g. `(bool, &str)` rather than `(Option<bool>, Option<&str>)`. It is only invoked
+    /// once every source has a value, and keeps its last value while any of them has none.
+    pub fn computed_unwrapped<P: LazySignalsArgs, R: LazySignalsData>(
+        &self,
+        propagator_closure: impl Computed<P, R>,
+        sources: Vec<Entity>,
+        commands: &mut Commands
+    ) -> Entity {
+        let entity = commands.spawn_empty().id();
+        let function = make_unwrapped_computed_with(propagator_closure);
+        commands.create_computed::<P, R>(entity, function, sources);
+        commands.entity(entity).insert(UnwrappedArgs);
+        entity
+    }
+
+    /// Create a `bool` signal that becomes `true` for `duration` whenever `trigger` fires. Triggers
+    /// that fire while it is still `true` are ignored.
+    pub fn cooldown(&self, trigger: Entity, duration: Duration, commands: &mut Commands) -> Entity {
+        let cooldown = self.state(false, commands);
+        commands.entity(cooldown).insert(Cooldown::new(duration));
+        self.effect::<()>(
+            move |_, world| {
+                let now = now(world);
+                let started = world
+                    .get_mut::<Cooldown>(cooldown)
+                    .is_some_and(|mut cooldown| cooldown.start(now));
+                if started {
+                    LazySignals.send_now(cooldown, true, world);
+                }
+                None
+            },
+            vec![],
+            vec![trigger],
+            commands
+        );
+        cooldown
+    }
+
+    /// Create a `Signal` state and return separate typed handles to read and send it.
+    pub fn create<T: LazySignalsData>(
+        &self,
+        data: T,
+        commands: &mut Commands
+    ) -> (ReadSignal<T>, WriteSignal<T>) {
+        let state = self.state(data, commands);
+        (ReadSignal::new(state), WriteSignal::new(state))
+    }
+
+    /// Create a `Signal` state and return a typed handle that can read and send it.
+    pub fn create_rw<T: LazySignalsData>(&self, data: T, commands: &mut Commands) -> RwSignal<T> {
+        RwSignal::new(self.state(data, commands))
+    }
+
+    /// Despawn a primitive, removing it from the subscriber lists of its sources. Whatever depends
+    /// on it is despawned too, left with a `SourceRemoved` error, or keeps it from being despawned
+    /// at all, depending on `policy`.
+    pub fn despawn(&self, entity: Entity, policy: DependentPolicy, commands: &mut Commands) {
+        commands.add(move |world: &mut World| {
+            if let Err(error) = despawn_with_policy(entity, policy, world) {
+                error!("could not despawn {:?}: {}", entity, error);
+            }
+        });
+    }
+
+    /// Despawn every primitive tagged with `tag` in its `LazySignalsMeta`, e.g. to tear down a
+    /// feature. One that fails because another tagged primitive still depends on it is tried
+    /// again once that one is gone, so `DependentPolicy::Error` only fails for dependents outside
+    /// the tag.
+    pub fn despawn_tagged(
+        &self,
+        tag: impl Into<String>,
+        policy: DependentPolicy,
+        commands: &mut Commands
+    ) {
+        let tag = tag.into();
+        commands.add(move |world: &mut World| {
+            let mut remaining = tagged(&tag, world);
+            loop {
+                let before = remaining.len();
+                let mut errors = Vec::new();
+                remaining.retain(|entity| {
+                    if world.get_entity(*entity).is_none() {
+                        return false;
+                    }
+                    match despawn_with_policy(*entity, policy, world) {
+                        Ok(_) => false,
+                        Err(error) => {
+                            errors.push((*entity, error));
+                            true
+                        }
+                    }
+                });
+
+                // retry the ones that failed as long as the others made progress
+                if remaining.is_empty() || remaining.len() == before {
+                    for (entity, error) in errors {
+                        error!("could not despawn {:?} tagged {}: {}", entity, tag, error);
+                    }
+                    break;
+                }
+            }
+        });
+    }
+
+    /// Dispatch an action to a store. The reducer runs when the command is applied, so several
+    /// actions dispatched in the same tick each see the state left by the previous one.
+    pub fn dispatch<S: LazySignalsData, A: Send + Sync + 'static>(
+        &self,
+        store: Entity,
+        action: A,
+        commands: &mut Commands
+    ) {
+        commands.dispatch_action::<S, A>(store, action);
+    }
+
     /// Create an `Effect` that passes its sources to and evaluate a closure that runs side-effects.
     pub fn effect<P: LazySignalsArgs>(
         &self,
@@ -106,11 +610,163 @@ impl LazySignals {
         entity
     }
 
+    /// Create an `Effect` from a fn registered with `register_effect`, so it picks up the new fn
+    /// when the name is registered again after a hot reload.
+    pub fn effect_by_name<P: LazySignalsArgs>(
+        &self,
+        name: impl Into<Cow<'static, str>>,
+        sources: Vec<Entity>,
+        triggers: Vec<Entity>,
+        commands: &mut Commands
+    ) -> Entity {
+        let effect = commands.spawn_empty().id();
+        commands.add(CreateNamedEffectCommand::<P> {
+            effect,
+            name: name.into(),
+            sources,
+            triggers,
+            args_type: PhantomData,
+        });
+        effect
+    }
+
+    /// Create an `Effect` whose closure gets the values of its sources instead of `Option`s of
+    /// them. It does not run until every source has a value (see `wait_for_sources`).
+    pub fn effect_unwrapped<P: LazySignalsArgs>(
+        &self,
+        effect_closure: impl Effect<P>,
+        sources: Vec<Entity>,
+        triggers: Vec<Entity>,
+        commands: &mut Commands
+    ) -> Entity {
+        let entity = commands.spawn_empty().id();
+        let function = make_unwrapped_effect_with(effect_closure);
+        commands.create_effect::<P>(entity, function, sources, triggers);
+        commands.entity(entity).insert((UnwrappedArgs, WaitForSources));
+        entity
+    }
+
+    /// Create an `Effect` whose return value is sent to a companion `Signal`, starting with
+    /// `initial`. Returns the effect entity and a read handle to the companion.
+    pub fn effect_with_result<P: LazySignalsArgs, R: LazySignalsData>(
+        &self,
+        mut effect_closure: impl FnMut(P, &mut World) -> R + Send + Sync + 'static,
+        sources: Vec<Entity>,
+        triggers: Vec<Entity>,
+        initial: R,
+        commands: &mut Commands
+    ) -> (Entity, ReadSignal<R>) {
+        let output = self.state(initial, commands);
+        let effect = self.effect::<P>(
+            move |args, world| {
+                let result = effect_closure(args, world);
+                LazySignals.send_now(output, result, world);
+                None
+            },
+            sources,
+            triggers,
+            commands
+        );
+        (effect, ReadSignal::new(output))
+    }
+
+    /// Send a `SignalChanged` event whenever the value of the given `Signal` or `Computed` changes.
+    pub fn emit_changes(&self, immutable: Entity, commands: &mut Commands) {
+        commands.entity(immutable).insert(EmitSignalChanged);
+    }
+
+    /// Create a `Signal` state holding an `Entity` that is cleared (or set to an error) according
+    /// to the given policy when that entity is despawned.
+    pub fn entity_state(
+        &self,
+        target: Entity,
+        policy: LivenessPolicy,
+        commands: &mut Commands
+    ) -> Entity {
+        let state = self.state(target, commands);
+        commands.entity(state).insert(TrackLiveness { policy });
+        state
+    }
+
     /// Return an error from a computed closure.
     pub fn error<T: LazySignalsData>(error: LazySignalsError) -> LazySignalsResult<T> {
         LazySignalsResult { data: None, error: Some(error) }
     }
 
+    /// List every live `Signal`, `Computed`, `Effect`, and `Action`, grouped by type.
+    pub fn enumerate(&self, world: &World) -> Vec<SignalInfo> {
+        enumerate_signals(world)
+    }
+
+    /// Create a `bool` computed that is `true` while `a` and `b` hold equal values.
+    pub fn equals<T: LazySignalsData>(
+        &self,
+        a: Entity,
+        b: Entity,
+        commands: &mut Commands
+    ) -> Entity {
+        combine::<T, bool>(a, b, |a, b| a == b, commands)
+    }
+
+    /// Create a family that makes one member per key on demand, evicting them per `eviction`.
+    /// Ask the `LazySignalsFamily` component on the returned entity for each member.
+    pub fn family<K: LazySignalsFamilyKey>(
+        &self,
+        factory: impl Fn(&K, &mut Commands) -> Entity + Send + Sync + 'static,
+        eviction: MemoEviction,
+        commands: &mut Commands
+    ) -> Entity {
+        commands.spawn(LazySignalsFamily::new(factory, eviction)).id()
+    }
+
+    /// GET the URL held by a `String` signal into status and body signals, again whenever the URL
+    /// changes, canceling the request in flight. Needs `fetch::LazySignalsFetchPlugin`.
+    #[cfg(feature = "net")]
+    pub fn fetch(&self, url: Entity, commands: &mut Commands) -> Fetch {
+        fetch(url, commands)
+    }
+
+    /// Run the init, signal, memo, and effect passes right away (exclusive), repeating until
+    /// nothing is left to send, compute, or run. Returns `false` if the graph did not settle within
+    /// a reasonable number of passes. `Action` tasks that are still running are not waited on, and
+    /// neither are primitives of a labeled context or effects of a processing class, which are left
+    /// marked for their own schedules.
+    pub fn flush(&self, world: &mut World) -> bool {
+        for pass in 0..MAX_FLUSH_PASSES {
+            if world.try_run_schedule(LazySignalsFlush).is_err() {
+                error!("LazySignalsFlush schedule not found, is LazySignalsPlugin added?");
+                return false;
+            }
+
+            if !has_pending(world, |_| true) {
+                trace!("-settled after {} passes", pass + 1);
+                return true;
+            }
+        }
+        warn!("signals did not settle after {} passes", MAX_FLUSH_PASSES);
+        false
+    }
+
+    /// Create the state machine declared by the builder. Returns the `Signal` holding its current
+    /// state.
+    pub fn fsm<S: LazySignalsData>(
+        &self,
+        builder: FsmBuilder<S>,
+        commands: &mut Commands
+    ) -> Entity {
+        builder.build(commands)
+    }
+
+    /// Create a `Signal` with no value that is sent each item of a `Stream`.
+    #[cfg(feature = "async")]
+    pub fn from_stream<T: LazySignalsData>(
+        &self,
+        stream: impl Stream<Item = T> + Send + 'static,
+        commands: &mut Commands
+    ) -> Entity {
+        from_stream(stream, commands)
+    }
+
     /// Alias for value.
     pub fn get<R: LazySignalsData>(&self, immutable: Entity, world: &World) -> Option<R> {
         self.value(immutable, world)
@@ -129,21 +785,670 @@ impl LazySignals {
         }
     }
 
+    /// Create a `bool` computed that is `true` while the source is greater than `threshold`.
+    pub fn greater_than<T: LazySignalsData + PartialOrd>(
+        &self,
+        source: Entity,
+        threshold: T,
+        commands: &mut Commands
+    ) -> Entity {
+        self.lens::<T, bool>(source, move |value| *value > threshold, commands)
+    }
+
+    /// Create a `bool` computed with hysteresis: it becomes `true` when the source rises above
+    /// `high` and stays `true` until the source falls below `low`, so a value hovering around a
+    /// single threshold does not make it flicker.
+    pub fn hysteresis<T: LazySignalsData + PartialOrd>(
+        &self,
+        source: Entity,
+        high: T,
+        low: T,
+        commands: &mut Commands
+    ) -> Entity {
+        let on = AtomicBool::new(false);
+        self.lens::<T, bool>(
+            source,
+            move |value| {
+                if *value > high {
+                    on.store(true, Ordering::Relaxed);
+                } else if *value < low {
+                    on.store(false, Ordering::Relaxed);
+                }
+                on.load(Ordering::Relaxed)
+            },
+            commands
+        )
+    }
+
+    /// Put a primitive in a labeled context (see `LazySignalsPlugin::labeled`) and return the
+    /// entity, so it can wrap the call that creates it.
+    pub fn in_context(
+        &self,
+        label: &'static str,
+        entity: Entity,
+        commands: &mut Commands
+    ) -> Entity {
+        commands.entity(entity).insert(SignalContext(label));
+        entity
+    }
+
+    /// Keep the value a `Signal` or `Computed` had at the end of each fixed timestep, so
+    /// `read_interpolated` can blend it with the current one, and return the entity.
+    pub fn interpolated(&self, immutable: Entity, commands: &mut Commands) -> Entity {
+        commands.entity(immutable).insert(InterpolatedSignal::default());
+        immutable
+    }
+
+    /// Make a `Stream` that yields each new value of a `Signal` or `Computed`.
+    #[cfg(feature = "async")]
+    pub fn into_stream<T: LazySignalsData>(
+        &self,
+        immutable: Entity,
+        commands: &mut Commands
+    ) -> impl Stream<Item = T> {
+        into_stream(immutable, commands)
+    }
+
+    /// Give any `LazySignals` entity a `Name`, which is used in logs instead of the raw entity id.
+    /// Returns the entity so it can wrap the call that created it.
+    pub fn label(
+        &self,
+        entity: Entity,
+        label: impl Into<Cow<'static, str>>,
+        commands: &mut Commands
+    ) -> Entity {
+        commands.entity(entity).insert(Name::new(label));
+        entity
+    }
+
+    /// Create a `bool` signal that becomes `true` when `set` fires and `false` when `reset` fires.
+    /// If both fire during the same batch, reset wins and the latch ends up `false`.
+    pub fn latch(&self, set: Entity, reset: Entity, commands: &mut Commands) -> Entity {
+        let latch = self.state(false, commands);
+
+        // the two effects run in no particular order, so the set one checks whether the reset one
+        // already ran during this pass, going by the change tick that holds for the whole pass
+        let reset_at = Arc::new(Mutex::new(None::<Tick>));
+        let last_reset = reset_at.clone();
+        self.effect::<()>(
+            move |_, world| {
+                if *last_reset.lock().unwrap() != Some(world.change_tick()) {
+                    LazySignals.send_now(latch, true, world);
+                }
+                None
+            },
+            vec![],
+            vec![set],
+            commands
+        );
+        self.effect::<()>(
+            move |_, world| {
+                *reset_at.lock().unwrap() = Some(world.change_tick());
+                LazySignals.send_now(latch, false, world);
+                None
+            },
+            vec![],
+            vec![reset],
+            commands
+        );
+        latch
+    }
+
+    /// Create a `Computed` that projects part of a larger source value, e.g. one field of a config
+    /// struct. Since it only changes when the projection does, effects reading it do not run for
+    /// changes to the rest of the source.
+    pub fn lens<S: LazySignalsData, T: LazySignalsData>(
+        &self,
+        source: Entity,
+        projection: impl Fn(&S) -> T + Send + Sync + 'static,
+        commands: &mut Commands
+    ) -> Entity {
+        self.computed::<(Option<S>,), T>(
+            move |(data,)| LazySignals::option(data.as_ref().map(&projection)),
+            vec![source],
+            commands
+        )
+    }
+
+    /// Create a `bool` computed that is `true` while the source is less than `threshold`.
+    pub fn less_than<T: LazySignalsData + PartialOrd>(
+        &self,
+        source: Entity,
+        threshold: T,
+        commands: &mut Commands
+    ) -> Entity {
+        self.lens::<T, bool>(source, move |value| *value < threshold, commands)
+    }
+
+    /// Read a save made by `LazySignals.save`, migrate values saved at an older schema version, and
+    /// send them to the signals at their paths.
+    #[cfg(feature = "savegame")]
+    pub fn load(&self, json: &str, world: &mut World) -> Result<(), serde_json::Error> {
+        NamespaceSnapshot::from_json(json, world)?.restore(world);
+        Ok(())
+    }
+
+    /// Create a `String` signal holding the text of `key` in `locale`, which is updated when either
+    /// of those signals changes or when the table finishes loading or is hot-reloaded. Missing
+    /// translations fall back to the first locale of the table and then to the key itself.
+    #[cfg(feature = "localization")]
+    pub fn localize<K: LazySignalsData + AsRef<str>, L: LazySignalsData + AsRef<str>>(
+        &self,
+        key: Entity,
+        locale: Entity,
+        table: Handle<Localization>,
+        commands: &mut Commands
+    ) -> Entity {
+        let text = commands.spawn_empty().id();
+        commands.create_empty_state::<String>(text);
+
+        let handle = table.clone();
+        let effect = self.effect::<(Option<K>, Option<L>)>(
+            move |(key, locale), world| {
+                let (Some(key), Some(locale)) = (key, locale) else {
+                    return None;
+                };
+                let localized = world
+                    .get_resource::<Assets<Localization>>()
+                    .and_then(|tables| tables.get(&handle))
+                    .map(|table| table.localize(key.as_ref(), locale.as_ref()))?;
+                LazySignals.send_now(text, localized, world);
+                None
+            },
+            vec![key, locale],
+            vec![],
+            commands
+        );
+        commands.entity(effect).insert(LocalizedBy(table));
+        text
+    }
+
+    /// Find the entity at a namespace path such as `"ui.settings.volume"`.
+    pub fn lookup(&self, path: &str, world: &World) -> Option<Entity> {
+        world.get_resource::<LazySignalsNamespace>()?.lookup(path)
+    }
+
+    /// Find every entity whose namespace path starts with the prefix (e.g. `"ui.settings."`).
+    pub fn lookup_prefix(&self, prefix: &str, world: &World) -> Vec<(String, Entity)> {
+        match world.get_resource::<LazySignalsNamespace>() {
+            Some(namespace) =>
+                namespace
+                    .lookup_prefix(prefix)
+                    .map(|(path, entity)| (path.to_string(), entity))
+                    .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Create a `Computed` of the larger of `a` and `b`.
+    pub fn max<T: LazySignalsData + PartialOrd>(
+        &self,
+        a: Entity,
+        b: Entity,
+        commands: &mut Commands
+    ) -> Entity {
+        combine::<T, T>(a, b, |a, b| if b > a { b } else { a }, commands)
+    }
+
+    /// Set a time budget for each run of a `Computed` or `Effect`. Runs that take longer are
+    /// counted in `LazySignalsStats` and send a `BudgetExceeded` event with the measured time.
+    pub fn max_duration(&self, entity: Entity, max_duration: Duration, commands: &mut Commands) {
+        commands.entity(entity).insert(MaxDuration(max_duration));
+    }
+
+    /// Create a `Signal` that fans in several sources of the same type, holding the latest value
+    /// sent to any of them tagged with the source it came from. `MergedValue<T>` must be
+    /// registered with `register_signal_type`. If several sources are sent in the same batch, the
+    /// last one wins.
+    pub fn merge<T: LazySignalsData>(
+        &self,
+        sources: Vec<Entity>,
+        commands: &mut Commands
+    ) -> Entity {
+        let tags = sources.clone();
+        self.merge_with::<T, MergedValue<T>>(
+            sources,
+            move |index, value| MergedValue { index, source: tags[index], value },
+            commands
+        )
+    }
+
+    /// Same as `merge` but the index of the source and its value are mapped into a value of the
+    /// caller's choosing, such as an enum with one variant per source. To merge sources of
+    /// different types, map each one into a common type with a `lens` first.
+    pub fn merge_with<T: LazySignalsData, M: LazySignalsData>(
+        &self,
+        sources: Vec<Entity>,
+        map: impl Fn(usize, T) -> M + Send + Sync + 'static,
+        commands: &mut Commands
+    ) -> Entity {
+        let merged = commands.spawn_empty().id();
+        commands.create_empty_state::<M>(merged);
+
+        // one effect per source, so each knows which source it is
+        let map = Arc::new(map);
+        for (index, source) in sources.into_iter().enumerate() {
+            let map = Arc::clone(&map);
+            self.effect::<(Option<T>,)>(
+                move |(data,), world| {
+                    if let Some(data) = data {
+                        LazySignals.send_now(merged, map(index, data), world);
+                    }
+                    None
+                },
+                vec![source],
+                vec![],
+                commands
+            );
+        }
+        merged
+    }
+
+    /// Describe any `LazySignals` entity for tooling with a `LazySignalsMeta`, which also gives it
+    /// a `Name` from `meta.name` if it has none. Returns the entity so it can wrap the call that
+    /// created it.
+    pub fn meta(&self, entity: Entity, meta: LazySignalsMeta, commands: &mut Commands) -> Entity {
+        let name = meta.name.clone();
+        commands.add(move |world: &mut World| {
+            let Some(mut entity) = world.get_entity_mut(entity) else {
+                return;
+            };
+            if let Some(name) = name.filter(|_| !entity.contains::<Name>()) {
+                entity.insert(Name::new(name));
+            }
+            entity.insert(meta);
+        });
+        entity
+    }
+
+    /// Register a migration for values saved at a path with schema version `from_version`, run
+    /// when an older save is loaded, e.g. `|old: SettingsV1| Settings { volume: old.volume }`.
+    /// The schema version of the path is raised to at least `from_version + 1`.
+    #[cfg(feature = "savegame")]
+    pub fn migration<O: FromReflect + GetTypeRegistration, N: Reflect>(
+        &self,
+        path: impl Into<String>,
+        from_version: u32,
+        function: impl Fn(O) -> N + Send + Sync + 'static,
+        commands: &mut Commands
+    ) {
+        commands.add(RegisterMigrationCommand {
+            path: path.into(),
+            from_version,
+            function,
+            types: PhantomData::<(O, N)>,
+        });
+    }
+
+    /// Create a `Computed` of the smaller of `a` and `b`.
+    pub fn min<T: LazySignalsData + PartialOrd>(
+        &self,
+        a: Entity,
+        b: Entity,
+        commands: &mut Commands
+    ) -> Entity {
+        combine::<T, T>(a, b, |a, b| if b < a { b } else { a }, commands)
+    }
+
+    /// Create a `Computed` of `a * b`.
+    pub fn mul<T: LazySignalsData + Mul<Output = T>>(
+        &self,
+        a: Entity,
+        b: Entity,
+        commands: &mut Commands
+    ) -> Entity {
+        combine::<T, T>(a, b, |a, b| a * b, commands)
+    }
+
+    /// Create a `Computed` of `!source`.
+    pub fn not(&self, source: Entity, commands: &mut Commands) -> Entity {
+        self.lens::<bool, bool>(source, |value| !value, commands)
+    }
+
     /// Return an optional value from a computed closure.
     pub fn option<T: LazySignalsData>(data: Option<T>) -> LazySignalsResult<T> {
         LazySignalsResult { data, error: None }
     }
 
+    /// Create a `Computed` of `a || b`.
+    pub fn or(&self, a: Entity, b: Entity, commands: &mut Commands) -> Entity {
+        combine::<bool, bool>(a, b, |a, b| a || b, commands)
+    }
+
+    /// Make some sources of a `Computed` passive: their current value is still passed in whenever
+    /// it recomputes, but a change to them alone does not make it recompute. E.g. a memo of the
+    /// time a button was last clicked that should not recompute every frame the clock ticks.
+    pub fn passive_sources(&self, computed: Entity, sources: Vec<Entity>, commands: &mut Commands) {
+        commands.add(PassiveSourcesCommand { computed, sources });
+    }
+
+    /// Stop processing every primitive tagged with `tag` in its `LazySignalsMeta` until
+    /// `resume_tagged`, e.g. to suspend a feature. Values sent to a paused `Signal` and changes
+    /// upstream of a paused `Computed` or `Effect` are processed once it resumes.
+    pub fn pause_tagged(&self, tag: impl Into<String>, commands: &mut Commands) {
+        let tag = tag.into();
+        commands.add(move |world: &mut World| {
+            for entity in tagged(&tag, world) {
+                world.entity_mut(entity).insert(Paused);
+            }
+        });
+    }
+
+    /// Create a trigger that delivers a payload to its subscribers for a single batch only.
+    /// Fire it with `trigger_with`. Reading it between batches returns `None`.
+    pub fn payload_trigger<T: LazySignalsData>(&self, commands: &mut Commands) -> Entity {
+        let entity = commands.spawn_empty().id();
+        commands.create_payload_trigger::<T>(entity);
+        entity
+    }
+
+    /// Create an `Effect` that runs on the `ComputeTaskPool` instead of blocking the exclusive
+    /// pass, e.g. to generate terrain when a seed changes. It gets no world; instead it queues the
+    /// changes to make in the returned `CommandQueue`. All pooled effects that need to run in a
+    /// pass run in parallel, and their queues are applied in order once the last one finishes.
+    pub fn pooled_effect<P: LazySignalsArgs>(
+        &self,
+        effect_closure: impl PooledEffect<P>,
+        sources: Vec<Entity>,
+        triggers: Vec<Entity>,
+        commands: &mut Commands
+    ) -> Entity {
+        let entity = commands.spawn_empty().id();
+        let function = make_pooled_effect_with(effect_closure);
+        commands.create_pooled_effect::<P>(entity, function, sources, triggers);
+        entity
+    }
+
+    /// Like `flush`, but only processes the primitives reachable from the root: the root itself,
+    /// the entities below it in the hierarchy, and everything downstream of those. E.g. to resolve
+    /// a dialog right after spawning it without running the rest of the graph. Subscribers outside
+    /// the subgraph are still marked and catch up during the next regular pass, and like with
+    /// `flush`, primitives of a labeled context and effects of a processing class wait for their
+    /// own schedules.
+    pub fn process_subgraph(&self, root: Entity, world: &mut World) -> bool {
+        for pass in 0..MAX_FLUSH_PASSES {
+            let subgraph = subgraph_of(root, world);
+            if !has_pending(world, |entity| subgraph.contains(&entity)) {
+                trace!("-subgraph settled after {} passes", pass);
+                return true;
+            }
+
+            world.insert_resource(ActiveSubgraph(subgraph));
+            let ran = world.try_run_schedule(LazySignalsFlush);
+            world.remove_resource::<ActiveSubgraph>();
+            if ran.is_err() {
+                error!("LazySignalsFlush schedule not found, is LazySignalsPlugin added?");
+                return false;
+            }
+        }
+        warn!("subgraph of {:?} did not settle after {} passes", root, MAX_FLUSH_PASSES);
+        false
+    }
+
     /// Alias for value.
     pub fn read<R: LazySignalsData>(&self, immutable: Entity, world: &World) -> Option<R> {
         self.value(immutable, world)
     }
 
+    /// Read a value for occasional polling, without subscribing, failing with
+    /// `LazySignalsError::Stale` if it was not updated within the last `max_age_frames` frames,
+    /// e.g. because whatever sends it stopped running. A signal that still holds the value it was
+    /// created with counts as stale. An error held by the signal is returned as is.
+    pub fn read_fresh<R: LazySignalsData>(
+        &self,
+        immutable: Entity,
+        max_age_frames: u32,
+        world: &World
+    ) -> Result<Option<R>, LazySignalsError> {
+        let entity = world.get_entity(immutable).ok_or(LazySignalsError::NoSignalError)?;
+        let state = entity
+            .get::<LazySignalsState<R>>()
+            .ok_or(LazySignalsError::ReadError(immutable))?;
+        if let Some(last_read) = entity.get::<LastRead>() {
+            last_read.touch(world);
+        }
+
+        let frame = world.get_resource::<FrameCount>().map_or(0, |frame_count| frame_count.0);
+        let age = entity.get::<LastUpdated>().map(|last| frame.wrapping_sub(last.frame()));
+        if age.map_or(true, |age| age > max_age_frames) {
+            return Err(LazySignalsError::Stale { entity: immutable, age });
+        }
+        match state.error() {
+            Some(error) => Err(error),
+            None => Ok(state.get()),
+        }
+    }
+
+    /// Read a value that is updated at the simulation rate (e.g. in `FixedUpdate`) for display at
+    /// the render rate, blended between its value at the end of the last fixed timestep and its
+    /// current value by `overstep_fraction` (see `Time::<Fixed>::overstep_fraction`), so it moves
+    /// smoothly even at a low tick rate. Needs `interpolated`, otherwise this is the same as
+    /// `read`. Does not subscribe.
+    pub fn read_interpolated<R: LazySignalsData + Interpolate>(
+        &self,
+        immutable: Entity,
+        overstep_fraction: f32,
+        world: &World
+    ) -> Option<R> {
+        let current = self.value::<R>(immutable, world)?;
+        let previous = world
+            .get::<InterpolatedSignal>(immutable)
+            .and_then(|interpolated| interpolated.previous::<R>());
+        let t = overstep_fraction.clamp(0.0, 1.0);
+        Some(match previous {
+            Some(previous) => previous.interpolate(&current, t),
+            None => current,
+        })
+    }
+
+    /// Read several signals at once, e.g. `LazySignals.read_many((hp, max_hp, name), world)`. The
+    /// values all come from the same `&World`, so no send can land between them, unlike separate
+    /// `read` calls made around other work in an `Effect`.
+    pub fn read_many<E: ReadMany<R>, R>(&self, immutables: E, world: &World) -> R {
+        immutables.read_many(world)
+    }
+
+    /// Whether every source of a `Computed` or `Effect` has a value, so none of its args will be
+    /// `None`. A `Signal`, or anything else without sources, is always ready.
+    pub fn ready(&self, entity: Entity, world: &World) -> bool {
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        is_ready(entity, world, &type_registry)
+    }
+
+    /// Register a `Computed` fn under a stable name for `computed_by_name`. Registering the same
+    /// name again, e.g. after a hot reload of the code that defines it, swaps the new fn into every
+    /// `Computed` made from it (keeping its sources, subscribers, and value) and recomputes them.
+    pub fn register_computed<P: LazySignalsArgs, R: LazySignalsData>(
+        &self,
+        name: impl Into<Cow<'static, str>>,
+        closure: impl Computed<P, R>,
+        commands: &mut Commands
+    ) {
+        commands.add(RegisterComputedCommand::<P, R> {
+            name: name.into(),
+            function: Arc::new(closure),
+        });
+    }
+
+    /// Register an `Effect` fn under a stable name for `effect_by_name`. Registering the same name
+    /// again swaps the new fn into every `Effect` made from it, which runs the next time one of
+    /// its sources changes.
+    pub fn register_effect<P: LazySignalsArgs>(
+        &self,
+        name: impl Into<Cow<'static, str>>,
+        closure: impl Effect<P> + Clone,
+        commands: &mut Commands
+    ) {
+        commands.add(RegisterEffectCommand::<P, _> {
+            name: name.into(),
+            function: closure,
+            args_type: PhantomData,
+        });
+    }
+
+    /// Give an entity a dot-separated namespace path, replacing whatever was there.
+    pub fn register_path(&self, path: impl Into<String>, entity: Entity, commands: &mut Commands) {
+        let path = path.into();
+        commands.add(move |world: &mut World| {
+            world.resource_mut::<LazySignalsNamespace>().insert(path, entity);
+        });
+    }
+
+    /// Point an `alias` at another target. Its subscribers see the value of the new target. A
+    /// target holding a different type than the alias is refused like in `retarget`.
+    pub fn repoint(&self, alias: Entity, target: Entity, commands: &mut Commands) {
+        commands.retarget(alias, vec![target], vec![]);
+    }
+
+    /// Send a `Signal` the value it was created with, propagating as usual.
+    pub fn reset(&self, signal: Entity, commands: &mut Commands) {
+        commands.reset_signal(signal, false);
+    }
+
+    /// Reset every `Signal` under a namespace prefix (e.g. `"ui.settings."`).
+    pub fn reset_prefix(&self, prefix: impl Into<String>, commands: &mut Commands) {
+        let prefix = prefix.into();
+        commands.add(move |world: &mut World| {
+            for (_, signal) in LazySignals.lookup_prefix(&prefix, world) {
+                ResetSignalCommand { signal, descendants: false }.apply(world);
+            }
+        });
+    }
+
+    /// Reset the given entity, if it is a `Signal`, and every `Signal` among its descendants.
+    pub fn reset_scope(&self, scope: Entity, commands: &mut Commands) {
+        commands.reset_signal(scope, true);
+    }
+
+    /// Reset every `Signal` tagged with `tag` in its `LazySignalsMeta`.
+    pub fn reset_tagged(&self, tag: impl Into<String>, commands: &mut Commands) {
+        let tag = tag.into();
+        commands.add(move |world: &mut World| {
+            for signal in tagged(&tag, world) {
+                ResetSignalCommand { signal, descendants: false }.apply(world);
+            }
+        });
+    }
+
+    /// Run a fallible closure (e.g. a network or disk read) on the `AsyncComputeTaskPool` with the
+    /// current values of its sources, right away and again whenever one changes, into status and
+    /// value signals. A failed attempt is retried with backoff according to the policy, and the
+    /// status signal says which attempt is running or whether it finally failed.
+    pub fn resolve<P: LazySignalsArgs, R: LazySignalsData>(
+        &self,
+        closure: impl Fn(&P) -> Result<R, String> + Send + Sync + 'static,
+        sources: Vec<Entity>,
+        policy: RetryPolicy,
+        commands: &mut Commands
+    ) -> Resolve {
+        resolve(closure, sources, policy, commands)
+    }
+
     /// Return a value from a computed closure.
     pub fn result<T: LazySignalsData>(data: T) -> LazySignalsResult<T> {
         LazySignalsResult { data: Some(data), error: None }
     }
 
+    /// Resume processing the primitives paused with `pause_tagged`.
+    pub fn resume_tagged(&self, tag: impl Into<String>, commands: &mut Commands) {
+        let tag = tag.into();
+        commands.add(move |world: &mut World| {
+            for entity in tagged(&tag, world) {
+                world.entity_mut(entity).remove::<Paused>();
+            }
+        });
+    }
+
+    /// Replace the sources of a `Computed` (same number, in the same order as its args) or the
+    /// sources and triggers of an `Effect`. Only the subscriptions that changed are touched, and a
+    /// `Computed` is recomputed with its new sources, along with everything downstream of it. New
+    /// sources that do not fit the args (wrong number or type) are refused with a
+    /// `SignalNodeRejected` event, leaving the old ones in place.
+    pub fn retarget(
+        &self,
+        entity: Entity,
+        sources: Vec<Entity>,
+        triggers: Vec<Entity>,
+        commands: &mut Commands
+    ) {
+        commands.retarget(entity, sources, triggers);
+    }
+
+    /// Create a `Signal` that captures the current value of `value` each time `trigger` fires, and
+    /// holds it until the next time. It has no value until the first trigger.
+    pub fn sample<T: LazySignalsData>(
+        &self,
+        value: Entity,
+        trigger: Entity,
+        commands: &mut Commands
+    ) -> Entity {
+        let sample = commands.spawn_empty().id();
+        commands.create_empty_state::<T>(sample);
+
+        // the value is read, not subscribed to, so only the trigger runs the effect
+        self.effect::<()>(
+            move |_, world| {
+                if let Some(data) = LazySignals.value::<T>(value, world) {
+                    LazySignals.send_now(sample, data, world);
+                }
+                None
+            },
+            vec![],
+            vec![trigger],
+            commands
+        );
+        sample
+    }
+
+    /// Save the values of every `Signal` and `Computed` under a namespace prefix as JSON, along
+    /// with the schema version of each path.
+    #[cfg(feature = "savegame")]
+    pub fn save(&self, prefix: &str, world: &mut World) -> Result<String, serde_json::Error> {
+        NamespaceSnapshot::capture(prefix, world).to_json(world)
+    }
+
+    /// Set the schema version values saved at a path are tagged with. Bump it when the data type of
+    /// the signal changes, and register a `migration` from the previous version.
+    #[cfg(feature = "savegame")]
+    pub fn schema_version(&self, path: impl Into<String>, version: u32, commands: &mut Commands) {
+        commands.add(SchemaVersionCommand { path: path.into(), version });
+    }
+
+    /// Create an `Option<Vec2>` signal with the position of `entity` in the viewport of `camera`,
+    /// updated when the transform of either one or the camera changes. See `ScreenPositionOf`.
+    #[cfg(feature = "camera")]
+    pub fn screen_position_of(
+        &self,
+        entity: Entity,
+        camera: Entity,
+        commands: &mut Commands
+    ) -> Entity {
+        let signal = self.state(Option::<Vec2>::None, commands);
+        commands.entity(signal).insert(ScreenPositionOf { target: entity, camera });
+        signal
+    }
+
+    /// Create a family of `Computed` memos over the same sources, one per key, so every reader
+    /// asking for the same key (via `LazySignalsFamily::member`) shares one computation.
+    pub fn selector<P: LazySignalsArgs, K: LazySignalsFamilyKey, R: LazySignalsData>(
+        &self,
+        sources: Vec<Entity>,
+        compute: impl Fn(&K, P) -> LazySignalsResult<R> + Send + Sync + 'static,
+        eviction: MemoEviction,
+        commands: &mut Commands
+    ) -> Entity {
+        let compute = Arc::new(compute);
+        self.family::<K>(
+            move |key, commands| {
+                let compute = Arc::clone(&compute);
+                let key = key.clone();
+                LazySignals.computed::<P, R>(move |args| compute(&key, args), sources.clone(), commands)
+            },
+            eviction,
+            commands
+        )
+    }
+
     /// Send a signal to be applied during the next batch.
     pub fn send<T: LazySignalsData>(&self, signal: Entity, data: T, commands: &mut Commands) {
         commands.send_signal::<T>(signal, data);
@@ -159,6 +1464,142 @@ impl LazySignals {
         commands.trigger_signal::<T>(signal, data);
     }
 
+    /// Send a batch of signals of the same type with a single command, e.g. one per map cell on
+    /// load. If a signal appears more than once, its values go through middleware, validation, and
+    /// backpressure in order like separate sends, so without backpressure the last one wins.
+    pub fn send_many<T: LazySignalsData>(
+        &self,
+        batch: impl IntoIterator<Item = (Entity, T)>,
+        commands: &mut Commands
+    ) {
+        commands.send_many::<T>(batch);
+    }
+
+    /// Send a signal from an exclusive system and merge it right away, so a read later in the same
+    /// system sees the new value. Subscribers are notified during the next batch as usual.
+    pub fn send_now<T: LazySignalsData>(&self, signal: Entity, data: T, world: &mut World) {
+        if !guard_signal(signal, world) {
+            return;
+        }
+        let Some(data) = run_middleware(signal, data, world).and_then(|data| {
+            validate_signal(signal, data, world)
+        }) else {
+            return;
+        };
+        if let Some(mut entity) = world.get_entity_mut(signal) {
+            if let Some(mut immutable) = entity.get_mut::<LazySignalsState<T>>() {
+                immutable
+                    .bypass_change_detection()
+                    .merge_now(LazySignalsResult { data: Some(data), error: None }, false);
+                entity.insert(SendSignal);
+                return;
+            }
+            error!("could not get State of {}", entity_label(signal, entity.world()));
+            dead_letter(signal, DeadLetterReason::TypeMismatch, &data, false, world);
+        } else {
+            error!("could not get Signal {:?}", signal);
+            dead_letter(signal, DeadLetterReason::MissingEntity, &data, false, world);
+        }
+    }
+
+    /// Send a signal right away, e.g. client-predicted UI state, and roll it back to the value it
+    /// had before unless the `confirmation` trigger fires within `timeout`. See `OptimisticUpdate`.
+    pub fn send_optimistic<T: LazySignalsData>(
+        &self,
+        signal: Entity,
+        data: T,
+        confirmation: Entity,
+        timeout: Duration,
+        commands: &mut Commands
+    ) {
+        commands.add(SendOptimisticCommand { signal, data, confirmation, timeout });
+    }
+
+    /// Send new shared data, which always counts as a change since it is a new allocation.
+    pub fn send_shared<T: Send + Sync + TypePath>(
+        &self,
+        signal: Entity,
+        data: T,
+        commands: &mut Commands
+    ) {
+        commands.send_signal(signal, LazySignalsShared::new(data));
+    }
+
+    /// Send a value that was made remotely at the given time (e.g. the server time from
+    /// `ClockSync`) and received over the network. It is merged like `send` unless a send with the
+    /// same or a later timestamp already was, so remote sends that arrive out of order or twice
+    /// are dropped instead of overwriting a newer value.
+    pub fn send_with_timestamp<T: LazySignalsData>(
+        &self,
+        signal: Entity,
+        data: T,
+        timestamp: Duration,
+        commands: &mut Commands
+    ) {
+        commands.add(SendTimestampedCommand { signal, data, timestamp });
+    }
+
+    /// Create a sequence of `Effect` or `Action` steps that run one after another, each once the
+    /// previous one completes. Trigger the returned entity to start (or restart) the sequence. If
+    /// the `cancel` trigger fires, the remaining steps do not run and the task of an `Action` in
+    /// progress is dropped (a step already scheduled in the same pass still runs).
+    pub fn sequence(
+        &self,
+        steps: Vec<Entity>,
+        cancel: Option<Entity>,
+        commands: &mut Commands
+    ) -> Entity {
+        let sequence = self.state((), commands);
+        commands.entity(sequence).insert(LazySignalsSequence::new(steps));
+        self.effect::<()>(
+            move |_, world| {
+                start_sequence(sequence, world);
+                None
+            },
+            vec![],
+            vec![sequence],
+            commands
+        );
+        if let Some(cancel) = cancel {
+            self.effect::<()>(
+                move |_, world| {
+                    cancel_sequence(sequence, world);
+                    None
+                },
+                vec![],
+                vec![cancel],
+                commands
+            );
+        }
+        sequence
+    }
+
+    /// Set the value of a signal from a value only known through reflection (e.g. from an editor
+    /// or a remote protocol handler). A value of another numeric type is converted when it fits,
+    /// so an `i64` or `f64` can be sent to a `u32` signal. Like `send`, the write guard, middleware,
+    /// and validator of the signal see the value first, and it is propagated during the next batch.
+    /// A value they refuse is `LazySignalsError::SendRefused`.
+    pub fn set_from_reflect(
+        &self,
+        signal: Entity,
+        value: &dyn Reflect,
+        world: &mut World
+    ) -> Result<(), LazySignalsError> {
+        merge_signal_reflect(signal, Some(value.clone_value()), world)
+    }
+
+    /// Capture the values of every `Signal` and `Computed` under a namespace prefix.
+    /// Call `restore` on the result to send the values back later.
+    pub fn snapshot(&self, prefix: &str, world: &mut World) -> NamespaceSnapshot {
+        NamespaceSnapshot::capture(prefix, world)
+    }
+
+    /// Capture the values of every `Signal` and `Computed` tagged with `tag` in its
+    /// `LazySignalsMeta`. Call `restore` on the result to send the values back later.
+    pub fn snapshot_tagged(&self, tag: &str, world: &mut World) -> NamespaceSnapshot {
+        NamespaceSnapshot::capture_tagged(tag, world)
+    }
+
     /// Create a `Signal` state that is the entrypoint for data into the structure.
     pub fn state<T: LazySignalsData>(&self, data: T, commands: &mut Commands) -> Entity {
         let state = commands.spawn_empty().id();
@@ -166,17 +1607,186 @@ impl LazySignals {
         state
     }
 
+    /// Create a `Signal` state for large or non-`Clone` data, delivered to subscribers by `Arc`.
+    /// (remember to register `LazySignalsShared<T>`)
+    pub fn state_shared<T: Send + Sync + TypePath>(
+        &self,
+        data: T,
+        commands: &mut Commands
+    ) -> Entity {
+        self.state(LazySignalsShared::new(data), commands)
+    }
+
+    /// Create a Redux-style store: a `Signal` whose state only changes by dispatching actions of
+    /// type `A`, which the reducer turns into the next state.
+    pub fn store<S: LazySignalsData, A: Send + Sync + 'static>(
+        &self,
+        initial_state: S,
+        reducer: impl Fn(&S, A) -> S + Send + Sync + 'static,
+        commands: &mut Commands
+    ) -> Entity {
+        let store = self.state(initial_state, commands);
+        commands.entity(store).insert(SignalReducer::<S, A> { reduce: Box::new(reducer) });
+        store
+    }
+
+    /// Create a `Computed` of `a - b`.
+    pub fn sub<T: LazySignalsData + Sub<Output = T>>(
+        &self,
+        a: Entity,
+        b: Entity,
+        commands: &mut Commands
+    ) -> Entity {
+        combine::<T, T>(a, b, |a, b| a - b, commands)
+    }
+
+    /// Create a `Computed` whose closure reads its sources through a `TrackingContext`. It only
+    /// subscribes to the sources read on its last run, so conditional dependencies work as
+    /// expected. It runs once right away to find its initial sources.
+    pub fn tracked_computed<R: LazySignalsData>(
+        &self,
+        propagator_closure: impl TrackedComputed<R>,
+        commands: &mut Commands
+    ) -> Entity {
+        let entity = commands.spawn_empty().id();
+        commands.create_computed::<(), R>(
+            entity,
+            make_tracked_computed_with(propagator_closure),
+            Vec::<Entity>::new()
+        );
+        commands.entity(entity).insert((TrackedSources::default(), ComputeMemo));
+        entity
+    }
+
     /// Trigger a Signal that takes the unit type as its generic param..
     pub fn trigger(&self, signal: Entity, commands: &mut Commands) {
         commands.trigger_signal::<()>(signal, ());
     }
 
+    /// Create a trigger fired on the rising edge of a run condition, i.e. each frame it is true
+    /// after being false, so existing run-condition logic (e.g. `input_just_pressed`,
+    /// `resource_exists_and_changed`) can feed the graph. The condition is checked in `PreUpdate`,
+    /// after input is read and before the signals are processed.
+    pub fn trigger_when<M>(&self, condition: impl Condition<M>, app: &mut App) -> Entity {
+        let world = app.world_mut();
+        let trigger = self.state((), &mut world.commands());
+        world.flush();
+
+        let fire_on_rising_edge = move |
+            In(met): In<bool>,
+            mut was_met: Local<bool>,
+            mut commands: Commands
+        | {
+            if met && !*was_met {
+                LazySignals.trigger(trigger, &mut commands);
+            }
+            *was_met = met;
+        };
+        app.add_systems(
+            PreUpdate,
+            condition.pipe(fire_on_rising_edge).after(InputSystem).before(LazySignalsSystemSet)
+        );
+        trigger
+    }
+
+    /// Fire a `payload_trigger`, delivering the payload to its subscribers during the next batch.
+    pub fn trigger_with<T: LazySignalsData>(
+        &self,
+        trigger: Entity,
+        payload: T,
+        commands: &mut Commands
+    ) {
+        commands.trigger_signal::<T>(trigger, payload);
+    }
+
+    /// Check each value sent to a `Signal`, dropping invalid ones.
+    pub fn validate<T: LazySignalsData>(
+        &self,
+        signal: Entity,
+        validator: impl Fn(&T) -> Result<(), String> + Send + Sync + 'static,
+        commands: &mut Commands
+    ) {
+        commands.entity(signal).insert((
+            SignalValidator::<T> {
+                validate: Box::new(validator),
+                clamp: None,
+            },
+            ReflectSignalValidator { validate: validate_reflect::<T> },
+        ));
+    }
+
+    /// Check each value sent to a `Signal`, replacing invalid ones with the result of `clamp`.
+    pub fn validate_clamped<T: LazySignalsData>(
+        &self,
+        signal: Entity,
+        validator: impl Fn(&T) -> Result<(), String> + Send + Sync + 'static,
+        clamp: impl Fn(T) -> T + Send + Sync + 'static,
+        commands: &mut Commands
+    ) {
+        commands.entity(signal).insert((
+            SignalValidator::<T> {
+                validate: Box::new(validator),
+                clamp: Some(Box::new(clamp)),
+            },
+            ReflectSignalValidator { validate: validate_reflect::<T> },
+        ));
+    }
+
     /// Get the value from the given `World`.
     pub fn value<R: LazySignalsData>(&self, immutable: Entity, world: &World) -> Option<R> {
         let entity = world.entity(immutable);
+        if let Some(last_read) = entity.get::<LastRead>() {
+            last_read.touch(world);
+        }
         match entity.get::<LazySignalsState<R>>() {
             Some(observable) => observable.get(),
             None => None,
         }
     }
+
+    /// Keep an `Effect` from running until every one of its sources has a value, and return it.
+    /// Changes to some of the sources before the rest have been sent are skipped. See
+    /// `WaitForSources`.
+    pub fn wait_for_sources(&self, effect: Entity, commands: &mut Commands) -> Entity {
+        commands.entity(effect).insert(WaitForSources);
+        effect
+    }
+
+    /// Give an `Effect` or `Action` a processing class, so it runs in the schedule set for that
+    /// class by `LazySignalsProcessingPlugin`, and return the entity.
+    pub fn with_class(
+        &self,
+        class: ProcessingClass,
+        effect: Entity,
+        commands: &mut Commands
+    ) -> Entity {
+        commands.entity(effect).insert(class);
+        effect
+    }
+
+    /// Create a `bool` signal that is `true` while `a` and `b` are no further than `radius` apart,
+    /// checked when either one moves. See `WithinDistance`.
+    pub fn within_distance(
+        &self,
+        a: Entity,
+        b: Entity,
+        radius: f32,
+        commands: &mut Commands
+    ) -> Entity {
+        let signal = self.state(false, commands);
+        commands.entity(signal).insert(WithinDistance { a, b, radius });
+        signal
+    }
+
+    /// Only let values be sent to a `Signal` while `allow` returns `true`, e.g. while a resource
+    /// says the server sync system is running. Denied sends are dropped and `SignalWriteDenied` is
+    /// sent.
+    pub fn write_guard(
+        &self,
+        signal: Entity,
+        allow: impl Fn(&World) -> bool + Send + Sync + 'static,
+        commands: &mut Commands
+    ) {
+        commands.entity(signal).insert(WriteGuard { allow: Box::new(allow) });
+    }
 }
@@ -1,6 +1,7 @@
 use std::{ any::TypeId, sync::RwLockReadGuard };
 
 use bevy::{
+    core::FrameCount,
     ecs::{
         change_detection::MutUntyped,
         component::ComponentId,
@@ -8,42 +9,111 @@ use bevy::{
         world::EntityWorldMut,
     },
     prelude::*,
-    reflect::{ DynamicTuple, ReflectFromPtr, TypeRegistry },
+    reflect::{
+        DynamicTuple,
+        ReflectFromPtr,
+        ReflectRef,
+        Tuple,
+        TypeInfo,
+        TypeRegistry,
+    },
 };
 
 use crate::{
     framework::*,
-    lazy_immutable::{ LazySignalsObservable, ReflectLazySignalsObservable },
+    lazy_immutable::{
+        LazySignalsObservable,
+        ReflectLazySignalsDataType,
+        ReflectLazySignalsObservable,
+    },
 };
 
 /// Convenience fn to clone the un-`Clone`-able.
 pub fn clone_data<T: LazySignalsData>(result: &LazySignalsResult<T>) -> LazySignalsResult<T> {
     let data = match &result.data {
-        Some(data) => { <T as FromReflect>::from_reflect(&*data.clone_value()) }
+        Some(data) => { <T as FromReflect>::from_reflect(data.as_reflect()) }
         None => None,
     };
     LazySignalsResult {
         data,
-        error: result.error,
+        error: result.error.clone(),
     }
 }
 
-/// Convenience fn to add a concrete value to a dynamic tuple proxy.
-pub fn insert_data<T: LazySignalsData>(args: &mut DynamicTuple, result: &LazySignalsResult<T>) {
+/// Convenience fn to put a concrete value in a dynamic tuple proxy at the index, overwriting the
+/// element already there (from the last run) in place, or appending it if the tuple is shorter.
+pub fn insert_data<T: LazySignalsData>(
+    args: &mut DynamicTuple,
+    index: usize,
+    result: &LazySignalsResult<T>
+) {
     // the type inserted here has to be `Option<T>`
 
     // let's look at the error and return `None` if it is `Some`, otherwise just return `Some(data)`
-    let cloned = clone_data::<T>(result);
-    let result = match cloned.error {
+    let value = match result.error {
         Some(_) => None,
-        None => cloned.data,
+        None => result.data.as_ref().and_then(|data| T::from_reflect(data.as_reflect())),
     };
-    args.insert(result);
+    if index >= args.field_len() {
+        args.insert(value);
+    } else if let Some(slot) = args.field_mut(index).and_then(|field| field.downcast_mut()) {
+        *slot = value;
+    } else {
+        // a source of another type took this place (it was retargeted), so rebuild the tuple
+        let mut fields = Box::new(std::mem::take(args)).drain();
+        fields[index] = Box::new(value);
+        for field in fields {
+            args.insert_boxed(field);
+        }
+    }
+}
+
+/// Take the args a `Computed` or `Effect` last ran with out of its `SourceArgs`, to be filled in
+/// again for this run. They are dropped if it now has fewer sources.
+pub fn take_source_args(entity: Entity, sources: usize, world: &mut World) -> DynamicTuple {
+    let args = world
+        .get_mut::<SourceArgs>(entity)
+        .map(|mut last| std::mem::take(&mut last.0))
+        .unwrap_or_default();
+    match args.field_len() > sources {
+        true => DynamicTuple::default(),
+        false => args,
+    }
+}
+
+/// Put the args back in the `SourceArgs` of the `Computed` or `Effect` for its next run.
+pub fn return_source_args(entity: Entity, args: DynamicTuple, world: &mut World) {
+    match world.get_mut::<SourceArgs>(entity) {
+        Some(mut last) => {
+            last.0 = args;
+        }
+        None => {
+            if let Some(mut entity) = world.get_entity_mut(entity) {
+                entity.insert(SourceArgs(args));
+            }
+        }
+    }
 }
 
-/// Convenience fn to convert a `DynamicTuple` into a concrete type.
-pub fn make_tuple<T: LazySignalsArgs>(tuple: &DynamicTuple) -> T {
-    <T as FromReflect>::from_reflect(tuple).unwrap()
+/// Convenience fn to convert a `DynamicTuple` into a concrete args type (tuple or struct).
+pub fn make_args<T: LazySignalsArgs>(tuple: &DynamicTuple) -> T {
+    T::from_args(tuple).unwrap()
+}
+
+/// Convenience fn to convert a `DynamicTuple` of `Option`s into an args type (tuple or struct) of
+/// the values inside them. Returns `None` unless every one of them is `Some`.
+pub fn make_unwrapped_args<T: LazySignalsArgs>(tuple: &DynamicTuple) -> Option<T> {
+    let mut unwrapped = DynamicTuple::default();
+    for field in tuple.iter_fields() {
+        let ReflectRef::Enum(option) = field.reflect_ref() else {
+            return None;
+        };
+        if option.variant_name() != "Some" {
+            return None;
+        }
+        unwrapped.insert_boxed(option.field_at(0)?.clone_value());
+    }
+    Some(make_args::<T>(&unwrapped))
 }
 
 /// Given mutable reference to a `LazySignalsState` component instance, make a `LazySignalsObservable`.
@@ -52,8 +122,9 @@ pub fn ph_nglui_mglw_nafh_cthulhu_r_lyeh_wgah_nagl_fhtagn<'a>(
     type_id: &TypeId,
     type_registry: &RwLockReadGuard<TypeRegistry>
 ) -> &'a mut dyn LazySignalsObservable {
-    // convert into a pointer
-    let ptr_mut = mut_untyped.as_mut();
+    // convert into a pointer without marking the component changed, that only happens when the
+    // value actually changes (see `mark_changed`)
+    let ptr_mut = mut_untyped.bypass_change_detection().reborrow();
 
     // the `type_registration` is used to build a strategy to dereference a pointer to the component
 
@@ -90,7 +161,7 @@ pub fn run_as_observable(
     component_id: &ComponentId,
     type_id: &TypeId,
     type_registry: &RwLockReadGuard<TypeRegistry>,
-    mut closure: Box<dyn ObservableFn>
+    mut closure: impl ObservableFn
 ) -> MaybeFlaggedEntities {
     // get the source `LazySignalsState` component as an ECS change detection handle
     if let Some(mut mut_untyped) = entity.get_mut_by_id(*component_id) {
@@ -102,59 +173,344 @@ pub fn run_as_observable(
         );
 
         // run the supplied fn
-        closure(Box::new(observable), args, target)
+        closure(observable, args, target)
     } else {
         None
     }
 }
 
-/// Convenience fn to subscribe an entity to a source.
+/// Find the `LazySignalsState` of a `Signal` or `Computed` entity and run the supplied closure on it
+/// as a `LazySignalsObservable`. Returns `None` if the entity is not a `LazySignals` primitive.
+pub fn with_observable<R>(
+    entity: Entity,
+    world: &mut World,
+    closure: impl FnOnce(&mut dyn LazySignalsObservable, &TypeRegistry) -> R
+) -> Option<R> {
+    world.resource_scope(|world, type_registry: Mut<AppTypeRegistry>| {
+        let type_registry = type_registry.read();
+        with_observable_in(entity, world, &type_registry, closure)
+    })
+}
+
+/// Same as `with_observable` but for use inside a `resource_scope` that already holds the
+/// `AppTypeRegistry`.
+pub fn with_observable_in<R>(
+    entity: Entity,
+    world: &mut World,
+    type_registry: &RwLockReadGuard<TypeRegistry>,
+    closure: impl FnOnce(&mut dyn LazySignalsObservable, &TypeRegistry) -> R
+) -> Option<R> {
+    let component_id = world.get_entity(entity)?.get::<ImmutableState>()?.component_id;
+    let type_id = world.components().get_info(component_id)?.type_id()?;
+    let mut entity = world.get_entity_mut(entity)?;
+    let mut mut_untyped = entity.get_mut_by_id(component_id)?;
+    let observable = ph_nglui_mglw_nafh_cthulhu_r_lyeh_wgah_nagl_fhtagn(
+        &mut mut_untyped,
+        &type_id,
+        type_registry
+    );
+    Some(closure(observable, type_registry))
+}
+
+/// Whether every source of a `Computed` or `Effect` has a value, so none of its args will be
+/// `None`. Anything without sources is ready.
+pub fn is_ready(entity: Entity, world: &World, type_registry: &TypeRegistry) -> bool {
+    let Some(entity) = world.get_entity(entity) else {
+        return false;
+    };
+    let sources = match (entity.get::<ComputedImmutable>(), entity.get::<LazyEffect>()) {
+        (Some(computed), _) => &computed.sources,
+        (None, Some(effect)) => &effect.sources,
+        (None, None) => {
+            return true;
+        }
+    };
+    sources.iter().all(|source| has_value(*source, world, type_registry))
+}
+
+/// The version of the current value of a `Signal` or `Computed`, if it is one.
+pub fn state_version(entity: Entity, world: &World, type_registry: &TypeRegistry) -> Option<u64> {
+    read_observable(entity, world, type_registry, |observable| observable.version())
+}
+
+// whether a `Signal` or `Computed` holds a value
+fn has_value(entity: Entity, world: &World, type_registry: &TypeRegistry) -> bool {
+    read_observable(entity, world, type_registry, |observable| observable.has_data()) == Some(true)
+}
+
+// read a `Signal` or `Computed` as an observable without a mutable borrow of the world
+fn read_observable<R>(
+    entity: Entity,
+    world: &World,
+    type_registry: &TypeRegistry,
+    closure: impl FnOnce(&dyn LazySignalsObservable) -> R
+) -> Option<R> {
+    let entity = world.get_entity(entity)?;
+    let component_id = entity.get::<ImmutableState>()?.component_id;
+    let type_id = world.components().get_info(component_id)?.type_id()?;
+    let value = type_registry.get_type_data::<ReflectComponent>(type_id)?.reflect(entity)?;
+    let observable = type_registry.get_type_data::<ReflectLazySignalsObservable>(type_id)?;
+    observable.get(value).map(closure)
+}
+
+/// The `TypeInfo` of the `T` held by the `LazySignalsState<T>` of an entity.
+pub fn data_type_info(
+    entity: Entity,
+    world: &World,
+    type_registry: &TypeRegistry
+) -> Option<&'static TypeInfo> {
+    let component_id = world.get_entity(entity)?.get::<ImmutableState>()?.component_id;
+    let type_id = world.components().get_info(component_id)?.type_id()?;
+    state_data_type(type_id, type_registry)
+}
+
+/// The `TypeInfo` of the `T` of the `LazySignalsState<T>` with the given `TypeId`, as recorded when
+/// the state type was registered.
+pub fn state_data_type(type_id: TypeId, type_registry: &TypeRegistry) -> Option<&'static TypeInfo> {
+    type_registry
+        .get_type_data::<ReflectLazySignalsDataType>(type_id)
+        .map(|types| types.data_type)
+}
+
+enum Number {
+    Int(i128),
+    Float(f64),
+}
+
+fn as_number(value: &dyn Reflect) -> Option<Number> {
+    macro_rules! int {
+        ($($type:ty),*) => {
+            $(
+                if let Some(value) = value.downcast_ref::<$type>() {
+                    return i128::try_from(*value).ok().map(Number::Int);
+                }
+            )*
+        };
+    }
+    int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+    if let Some(value) = value.downcast_ref::<f32>() {
+        return Some(Number::Float(*value as f64));
+    }
+    value.downcast_ref::<f64>().map(|value| Number::Float(*value))
+}
+
+/// Convert a number to another numeric type, e.g. the `i64` or `f64` an editor or remote protocol
+/// produced into the `u32` a signal holds. Integers only convert when they are in range for the
+/// target, and floats only convert to integers when they have no fractional part.
+pub fn coerce_number(value: &dyn Reflect, target: TypeId) -> Option<Box<dyn Reflect>> {
+    let number = as_number(value)?;
+
+    macro_rules! int {
+        ($($type:ty),*) => {
+            $(
+                if target == TypeId::of::<$type>() {
+                    let value = match number {
+                        Number::Int(value) => value,
+                        Number::Float(value) if value.is_finite() && value.fract() == 0.0 => {
+                            value as i128
+                        }
+                        Number::Float(_) => {
+                            return None;
+                        }
+                    };
+                    return <$type>::try_from(value).ok().map(|value| Box::new(value) as Box<_>);
+                }
+            )*
+        };
+    }
+    int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+    let value = match number {
+        Number::Int(value) => value as f64,
+        Number::Float(value) => value,
+    };
+    if target == TypeId::of::<f32>() {
+        Some(Box::new(value as f32))
+    } else if target == TypeId::of::<f64>() {
+        Some(Box::new(value))
+    } else {
+        None
+    }
+}
+
+/// Mark the `LazySignalsState` of a `Signal` or `Computed` as changed for Bevy's change detection,
+/// so `Changed<LazySignalsState<T>>` matches exactly when the value (or error) changed, and record
+/// the frame in `LastUpdated`.
+pub fn mark_changed(entity: &mut EntityWorldMut) {
+    let Some(component_id) = entity.get::<ImmutableState>().map(|state| state.component_id) else {
+        return;
+    };
+    if let Some(mut state) = entity.get_mut_by_id(component_id) {
+        state.set_changed();
+    }
+
+    let frame = entity.world().get_resource::<FrameCount>().map_or(0, |frame_count| frame_count.0);
+    match entity.get_mut::<LastUpdated>() {
+        Some(mut last_updated) => {
+            last_updated.0 = frame;
+        }
+        None => {
+            entity.insert(LastUpdated(frame));
+        }
+    }
+}
+
+/// Convenience fn to send a `SignalChanged` event if the entity asked for one.
+pub fn emit_changed(entity: Entity, type_id: TypeId, world: &mut World) {
+    if world.entity(entity).contains::<EmitSignalChanged>() {
+        world.send_event(SignalChanged { entity, type_id });
+    }
+}
+
+/// Convenience fn to check whether a source was made passive for a `Computed` with
+/// `LazySignals.passive_sources`.
+pub fn is_passive(subscriber: Entity, source: Entity, world: &World) -> bool {
+    world
+        .get::<PassiveSources>(subscriber)
+        .is_some_and(|passive| passive.sources.contains(&source))
+}
+
+/// Convenience fn to subscribe an entity to a source. Passive sources are skipped.
 pub fn subscribe(
     entity: &Entity,
     source: &Entity,
     type_registry: &RwLockReadGuard<TypeRegistry>,
     world: &mut World
 ) {
-    // get the `TypeId` of each source (`Signal` or `Computed`) component
-    let mut component_id: Option<ComponentId> = None;
-    let mut type_id: Option<TypeId> = None;
+    // a passive source is read without being subscribed to
+    if is_passive(*entity, *source, world) {
+        trace!("-{:?} is a passive source of {:?}", source, entity);
+        return;
+    }
+
+    trace!("Subscribing {:#?} to {:?}", entity, source);
+
+    // get the `ComponentId` of the source (`Signal` or `Computed`) component
+    let Some(component_id) = component_id_of(*source, world) else {
+        return;
+    };
+
+    // ...and the cached way to make it a `LazySignalsObservable`
+    if let Some(accessor) = observable_accessor(component_id, type_registry, world) {
+        subscribe_with(*entity, *source, component_id, &accessor, world);
+    }
+}
+
+/// Same as `subscribe` but only with the accessors already in `LazySignalsAccessors` (see
+/// `cache_accessors`), so it does not need the type registry.
+pub fn subscribe_cached(entity: Entity, source: Entity, world: &mut World) {
+    if is_passive(entity, source, world) {
+        trace!("-{:?} is a passive source of {:?}", source, entity);
+        return;
+    }
 
     trace!("Subscribing {:#?} to {:?}", entity, source);
 
-    // get a readonly reference to the source entity
-    if let Some(source) = world.get_entity(*source) {
-        trace!("-got source EntityRef");
-        // get the source `LazySignalsImmutable` component
-        if let Some(immutable_state) = source.get::<ImmutableState>() {
-            trace!("-got ImmutableState");
-            // ...as a `LazySignalsObservable`
-            component_id = Some(immutable_state.component_id);
-            if let Some(info) = world.components().get_info(component_id.unwrap()) {
-                trace!("-got TypeId");
-                type_id = info.type_id();
+    let Some((component_id, accessor)) = cached_accessor(source, world) else {
+        return;
+    };
+    subscribe_with(entity, source, component_id, &accessor, world);
+}
+
+/// Run the closure on a `Signal` or `Computed` as a `LazySignalsObservable`, using the accessor
+/// already in `LazySignalsAccessors` (see `cache_accessors`).
+pub fn with_cached_observable<R>(
+    entity: Entity,
+    world: &mut World,
+    closure: impl FnOnce(&mut dyn LazySignalsObservable) -> R
+) -> Option<R> {
+    let (component_id, accessor) = cached_accessor(entity, world)?;
+    let mut mut_untyped = world.get_entity_mut(entity)?.into_mut_by_id(component_id)?;
+    // safety: the accessor was resolved from the type of this very `ComponentId`
+    unsafe { accessor.observable(&mut mut_untyped) }.map(closure)
+}
+
+/// Make sure `LazySignalsAccessors` has the accessor of each of these `Signal`s and `Computed`s.
+/// The type registry is only locked if some of them are of a type that has not been seen yet, and
+/// only while those are looked up.
+pub fn cache_accessors(entities: impl IntoIterator<Item = Entity>, world: &mut World) {
+    world.init_resource::<LazySignalsAccessors>();
+    let accessors = world.resource::<LazySignalsAccessors>();
+    let mut missing = Vec::<ComponentId>::new();
+    for entity in entities {
+        if let Some(component_id) = component_id_of(entity, world) {
+            if accessors.get(component_id).is_none() && !missing.contains(&component_id) {
+                missing.push(component_id);
             }
         }
     }
+    if missing.is_empty() {
+        return;
+    }
 
-    // we have a component and a type, now do `mut` stuff
-    if component_id.is_some() && type_id.is_some() {
-        if let Some(mut source) = world.get_entity_mut(*source) {
-            let component_id = &component_id.unwrap();
-            let type_id = type_id.unwrap();
-
-            run_as_observable(
-                &mut source,
-                None,
-                Some(entity),
-                component_id,
-                &type_id,
-                type_registry,
-                Box::new(|observable, _args, target| {
-                    observable.subscribe(*target.unwrap());
-                    observable.merge_subscribers();
-                    None
-                })
-            );
-        }
+    trace!("-resolving accessors for {} component IDs", missing.len());
+    let resolved: Vec<(ComponentId, ObservableAccessor)> = {
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        missing
+            .into_iter()
+            .filter_map(|component_id| {
+                let type_id = world.components().get_info(component_id)?.type_id()?;
+                Some((component_id, ObservableAccessor::resolve(type_id, &type_registry)?))
+            })
+            .collect()
+    };
+    let mut accessors = world.resource_mut::<LazySignalsAccessors>();
+    for (component_id, accessor) in resolved {
+        accessors.insert(component_id, accessor);
+    }
+}
+
+/// The accessor of a `Signal` or `Computed` from `LazySignalsAccessors`, if it is there.
+pub fn cached_accessor(entity: Entity, world: &World) -> Option<(ComponentId, ObservableAccessor)> {
+    let component_id = component_id_of(entity, world)?;
+    let accessor = world.get_resource::<LazySignalsAccessors>()?.get(component_id)?;
+    Some((component_id, accessor.clone()))
+}
+
+// the `ComponentId` of the `LazySignalsState` of a `Signal` or `Computed`
+fn component_id_of(entity: Entity, world: &World) -> Option<ComponentId> {
+    Some(world.get_entity(entity)?.get::<ImmutableState>()?.component_id)
+}
+
+// add the subscriber to the state through its accessor
+fn subscribe_with(
+    entity: Entity,
+    source: Entity,
+    component_id: ComponentId,
+    accessor: &ObservableAccessor,
+    world: &mut World
+) {
+    let Some(mut source) = world.get_entity_mut(source) else {
+        return;
+    };
+    let Some(mut mut_untyped) = source.get_mut_by_id(component_id) else {
+        return;
+    };
+    // safety: the accessor was resolved from the type of this very `ComponentId`
+    if let Some(observable) = unsafe { accessor.observable(&mut mut_untyped) } {
+        observable.subscribe(entity);
+        observable.merge_subscribers();
+    }
+}
+
+// the accessor for the component from `LazySignalsAccessors`, resolved and cached if it is not
+// there yet
+fn observable_accessor(
+    component_id: ComponentId,
+    type_registry: &TypeRegistry,
+    world: &mut World
+) -> Option<ObservableAccessor> {
+    let accessors = world.get_resource::<LazySignalsAccessors>();
+    if let Some(accessor) = accessors.and_then(|accessors| accessors.get(component_id)) {
+        return Some(accessor.clone());
+    }
+
+    trace!("-resolving accessor for component ID {:?}", component_id);
+    let type_id = world.components().get_info(component_id)?.type_id()?;
+    let accessor = ObservableAccessor::resolve(type_id, type_registry)?;
+    if let Some(mut accessors) = world.get_resource_mut::<LazySignalsAccessors>() {
+        accessors.insert(component_id, accessor.clone());
     }
+    Some(accessor)
 }
@@ -0,0 +1,46 @@
+use bevy::{ asset::AssetId, prelude::* };
+
+/// ## Assets
+/// Helpers for the `&mut World` an `Effect` gets, for the common case of changing an asset (e.g. a
+/// material) when a signal changes, e.g. `world.asset_mut::<StandardMaterial>(&material)`.
+///
+/// Both go through `Assets::get_mut`, so the asset gets an `AssetEvent::Modified` and the `Assets`
+/// resource is marked changed, the same as when a system changes it through `ResMut<Assets<A>>`.
+pub trait LazySignalsAssetsExt {
+    /// The asset, if the `Assets<A>` resource exists and the asset is loaded.
+    fn asset_mut<A: Asset>(&mut self, id: impl Into<AssetId<A>>) -> Option<&mut A>;
+
+    /// Run the closure with the asset and the rest of the world, e.g. to read another resource
+    /// while changing the asset. Returns `None` without running it if the asset is not loaded.
+    fn with_asset_mut<A: Asset, R>(
+        &mut self,
+        id: impl Into<AssetId<A>>,
+        closure: impl FnOnce(&mut A, &mut World) -> R
+    ) -> Option<R>;
+}
+
+impl LazySignalsAssetsExt for World {
+    fn asset_mut<A: Asset>(&mut self, id: impl Into<AssetId<A>>) -> Option<&mut A> {
+        let id = id.into();
+        // only mark the resource changed if the asset is there to be changed
+        let assets = self.get_resource_mut::<Assets<A>>()?;
+        if !assets.contains(id) {
+            return None;
+        }
+        assets.into_inner().get_mut(id)
+    }
+
+    fn with_asset_mut<A: Asset, R>(
+        &mut self,
+        id: impl Into<AssetId<A>>,
+        closure: impl FnOnce(&mut A, &mut World) -> R
+    ) -> Option<R> {
+        let id = id.into();
+        if !self.get_resource::<Assets<A>>()?.contains(id) {
+            return None;
+        }
+        self.resource_scope(|world, mut assets: Mut<Assets<A>>| {
+            assets.get_mut(id).map(|asset| closure(asset, world))
+        })
+    }
+}
@@ -0,0 +1,144 @@
+use std::{ collections::VecDeque, time::Duration };
+
+use bevy::prelude::*;
+
+use crate::{
+    clock::now,
+    dead_letters::{ dead_letter, DeadLetterReason },
+    framework::*,
+    lazy_immutable::{ LazySignalsImmutable, LazySignalsState },
+};
+
+/// ## Backpressure
+/// How a `Signal` absorbs a burst of sends (e.g. from a channel or per-frame input) before the
+/// values propagate.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Backpressure {
+    /// Every send replaces the pending value, so only the last value of a pass propagates. This is
+    /// what a `Signal` does without a policy.
+    Latest,
+
+    /// Once a value is pending, further sends are dropped until it has propagated.
+    Drop,
+
+    /// Values propagate one per pass, in the order they were sent. Up to `capacity` of them wait
+    /// their turn, and sends beyond that are dropped until the queue drains.
+    Block(usize),
+
+    /// Sends are held for the given window, starting with the first one, and then only the last
+    /// value propagates.
+    Conflate(Duration),
+}
+
+/// A held send, ready to be merged once the policy lets it through.
+type HeldSend = Box<dyn FnOnce(Entity, &mut World) + Send + Sync>;
+
+/// A `SignalBackpressure` component applies a `Backpressure` policy to the sends of a `Signal`.
+/// Values dropped by the policy are recorded as dead letters.
+#[derive(Component)]
+pub struct SignalBackpressure {
+    pub policy: Backpressure,
+    held: VecDeque<HeldSend>,
+    window_ends: Option<Duration>,
+}
+
+impl SignalBackpressure {
+    pub fn new(policy: Backpressure) -> Self {
+        Self { policy, held: VecDeque::new(), window_ends: None }
+    }
+
+    /// How many sends are waiting to be let through.
+    pub fn held(&self) -> usize {
+        self.held.len()
+    }
+}
+
+fn hold<T: LazySignalsData>(data: T, trigger: bool) -> HeldSend {
+    Box::new(move |signal, world| {
+        if let Some(mut state) = world.get_mut::<LazySignalsState<T>>(signal) {
+            state
+                .bypass_change_detection()
+                .merge_next(LazySignalsResult { data: Some(data), error: None }, trigger);
+            world.entity_mut(signal).insert(SendSignal);
+        }
+    })
+}
+
+/// Run the `SignalBackpressure` policy of a signal, if any, on a value that passed middleware and
+/// validation. Returns the data to merge right away, or `None` if it was held or dropped.
+pub(crate) fn apply_backpressure<T: LazySignalsData>(
+    signal: Entity,
+    data: T,
+    trigger: bool,
+    world: &mut World
+) -> Option<T> {
+    let now = now(world);
+    let pending = world.get::<SendSignal>(signal).is_some();
+    let Some(mut backpressure) = world.get_mut::<SignalBackpressure>(signal) else {
+        return Some(data);
+    };
+
+    let dropped = match backpressure.policy {
+        Backpressure::Latest => {
+            return Some(data);
+        }
+        Backpressure::Drop => pending,
+        Backpressure::Block(capacity) => {
+            if !pending && backpressure.held.is_empty() {
+                return Some(data);
+            }
+            if backpressure.held.len() < capacity {
+                backpressure.held.push_back(hold(data, trigger));
+                return None;
+            }
+            true
+        }
+        Backpressure::Conflate(window) => {
+            if backpressure.window_ends.is_none() {
+                backpressure.window_ends = Some(now + window);
+            }
+            backpressure.held.clear();
+            backpressure.held.push_back(hold(data, trigger));
+            return None;
+        }
+    };
+
+    if dropped {
+        trace!("-backpressure dropped a value sent to {}", entity_label(signal, world));
+        dead_letter(signal, DeadLetterReason::Backpressure, &data, trigger, world);
+        return None;
+    }
+    Some(data)
+}
+
+// let held values through: the next queued one of each idle blocking signal, and the last one of
+// each conflating signal whose window is over
+pub fn release_backpressure(
+    world: &mut World,
+    query_backpressure: &mut QueryState<(Entity, &mut SignalBackpressure, Has<SendSignal>)>
+) {
+    let now = now(world);
+
+    let released: Vec<(Entity, HeldSend)> = query_backpressure
+        .iter_mut(world)
+        .filter_map(|(entity, mut backpressure, pending)| {
+            let release = match backpressure.policy {
+                Backpressure::Block(_) => !pending,
+                Backpressure::Conflate(_) => {
+                    backpressure.window_ends.is_some_and(|window_ends| window_ends <= now)
+                }
+                _ => false,
+            };
+            if !release {
+                return None;
+            }
+            backpressure.window_ends = None;
+            backpressure.held.pop_front().map(|held| (entity, held))
+        })
+        .collect();
+
+    for (signal, held) in released {
+        trace!("-releasing a held value of {}", entity_label(signal, world));
+        held(signal, world);
+    }
+}
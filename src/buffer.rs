@@ -0,0 +1,70 @@
+use std::{ collections::VecDeque, time::Duration };
+
+use bevy::{ prelude::*, reflect::ReflectMut };
+
+use crate::{ arcane_wizardry::with_observable, clock::now, framework::* };
+
+/// ## Buffers
+/// A `TimeWindow` component keeps the arrival time of each value in a `Vec<T>` signal made by
+/// `LazySignals.buffer_time`, so values older than `window` can be dropped even when the source
+/// has not changed.
+#[derive(Component)]
+pub struct TimeWindow {
+    pub window: Duration,
+    arrivals: VecDeque<Duration>,
+}
+
+impl TimeWindow {
+    pub fn new(window: Duration) -> Self {
+        Self { window, arrivals: VecDeque::new() }
+    }
+
+    /// Note a value that arrived at `now`.
+    pub fn push(&mut self, now: Duration) {
+        self.arrivals.push_back(now);
+    }
+
+    /// Forget the values that are too old at `now`, returning how many there were.
+    pub fn expire(&mut self, now: Duration) -> usize {
+        let window = self.window;
+        let mut expired = 0;
+        while self.arrivals.front().is_some_and(|arrival| now.saturating_sub(*arrival) > window) {
+            self.arrivals.pop_front();
+            expired += 1;
+        }
+        expired
+    }
+}
+
+// drop the values that have been in each time buffer for longer than its window
+pub fn expire_time_buffers(
+    world: &mut World,
+    query_windows: &mut QueryState<(Entity, &mut TimeWindow)>
+) {
+    let now = now(world);
+
+    let expired: Vec<(Entity, usize)> = query_windows
+        .iter_mut(world)
+        .filter_map(|(entity, mut window)| {
+            let expired = window.expire(now);
+            (expired > 0).then_some((entity, expired))
+        })
+        .collect();
+
+    // the buffer is a `Vec<T>`, so remove the oldest values through reflection
+    for (buffer, expired) in expired {
+        trace!("-expiring {} values of {}", expired, entity_label(buffer, world));
+        let merged = with_observable(buffer, world, |observable, _| {
+            let mut values = observable.data_reflect()?;
+            if let ReflectMut::List(list) = values.reflect_mut() {
+                for _ in 0..expired.min(list.len()) {
+                    list.remove(0);
+                }
+            }
+            Some(observable.merge_reflect(Some(values.as_ref()), false))
+        });
+        if merged.flatten() == Some(true) {
+            world.entity_mut(buffer).insert(SendSignal);
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::time::Duration;
+
+use bevy::{ ecs::system::SystemParam, prelude::* };
+
+/// ## Clock
+/// The time that cooldowns, time buffers, backpressure windows, and family eviction go by, in place
+/// of `Time::elapsed`. Without this resource they use `Time`. Insert one in a test and `advance` it
+/// to move time forward instantly instead of sleeping:
+///
+/// ```ignore
+/// app.insert_resource(LazySignalsClock::default());
+/// app.world_mut().resource_mut::<LazySignalsClock>().advance(Duration::from_secs(5));
+/// ```
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct LazySignalsClock {
+    elapsed: Duration,
+}
+
+impl LazySignalsClock {
+    /// Create a clock that starts at the given time.
+    pub fn new(elapsed: Duration) -> Self {
+        Self { elapsed }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    pub fn advance(&mut self, by: Duration) {
+        self.elapsed += by;
+    }
+
+    pub fn set(&mut self, elapsed: Duration) {
+        self.elapsed = elapsed;
+    }
+}
+
+/// `SystemParam` to read the current time from the `LazySignalsClock`, or from `Time` without one.
+#[derive(SystemParam)]
+pub struct LazySignalsNow<'w> {
+    clock: Option<Res<'w, LazySignalsClock>>,
+    time: Option<Res<'w, Time>>,
+}
+
+impl LazySignalsNow<'_> {
+    pub fn elapsed(&self) -> Duration {
+        match (&self.clock, &self.time) {
+            (Some(clock), _) => clock.elapsed(),
+            (None, Some(time)) => time.elapsed(),
+            (None, None) => Duration::ZERO,
+        }
+    }
+}
+
+/// The current time according to the `LazySignalsClock`, or `Time` without one.
+pub fn now(world: &World) -> Duration {
+    match world.get_resource::<LazySignalsClock>() {
+        Some(clock) => clock.elapsed(),
+        None => world.get_resource::<Time>().map(|time| time.elapsed()).unwrap_or_default(),
+    }
+}
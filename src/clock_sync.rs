@@ -0,0 +1,183 @@
+use std::time::Duration;
+
+use bevy::{ ecs::world::Command, prelude::* };
+
+use crate::{
+    api::LazySignals,
+    clock::LazySignalsNow,
+    commands::merge_signal,
+    dead_letters::{ dead_letter, DeadLetterReason },
+    framework::*,
+    LazySignalsAppExt,
+    LazySignalsPlugin,
+    LazySignalsPrePassSet,
+};
+
+/// ## Clock Sync
+/// Signals with the time on the authoritative server and the round-trip time to it, for networked
+/// games. On the server, `server_time` is just the local time. On a client, both are estimated from
+/// the `ClockSyncSample` events the application sends when the answer to one of its pings arrives.
+/// All local times are `clock::now` times, i.e. from the `LazySignalsClock` or `Time`.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct ClockSync {
+    /// `Duration` signal with the estimated current time on the server, sent every frame once
+    /// there is an estimate.
+    pub server_time: Entity,
+
+    /// `Duration` signal with the estimated round-trip time to the server.
+    pub rtt: Entity,
+
+    /// How far each new sample moves the estimates, from 0 (not at all) to 1 (all the way), to
+    /// smooth out jitter.
+    pub smoothing: f64,
+
+    // server time minus local time, in seconds
+    offset: Option<f64>,
+    rtt_seconds: Option<f64>,
+}
+
+impl ClockSync {
+    /// The estimated difference between the server clock and the local one, in seconds.
+    pub fn offset(&self) -> Option<f64> {
+        self.offset
+    }
+
+    /// The estimated round-trip time, if there have been any samples.
+    pub fn rtt(&self) -> Option<Duration> {
+        self.rtt_seconds.map(Duration::from_secs_f64)
+    }
+
+    /// The server time at the given local time, going by the current estimate.
+    pub fn to_server_time(&self, local: Duration) -> Option<Duration> {
+        self.offset.map(|offset| Duration::from_secs_f64((local.as_secs_f64() + offset).max(0.0)))
+    }
+
+    /// The local time at the given server time, going by the current estimate, e.g. to tell how
+    /// long ago a timestamped remote send was made.
+    pub fn to_local_time(&self, server: Duration) -> Option<Duration> {
+        self.offset.map(|offset| Duration::from_secs_f64((server.as_secs_f64() - offset).max(0.0)))
+    }
+
+    /// Fold a sample into the estimates. The server is assumed to have answered halfway through
+    /// the round trip.
+    pub fn record(&mut self, sample: ClockSyncSample) {
+        let rtt = sample.received.saturating_sub(sample.sent).as_secs_f64();
+        let offset = sample.server_time.as_secs_f64() + rtt / 2.0 - sample.received.as_secs_f64();
+        let smoothing = self.smoothing.clamp(0.0, 1.0);
+        let smooth = |estimate: Option<f64>, sample: f64| {
+            Some(estimate.map_or(sample, |estimate| estimate + (sample - estimate) * smoothing))
+        };
+        self.rtt_seconds = smooth(self.rtt_seconds, rtt);
+        self.offset = smooth(self.offset, offset);
+    }
+}
+
+/// Sent on a client by the application when the server answers a clock-sync ping.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct ClockSyncSample {
+    /// The local time the ping was sent.
+    pub sent: Duration,
+
+    /// The server time in the answer.
+    pub server_time: Duration,
+
+    /// The local time the answer arrived.
+    pub received: Duration,
+}
+
+/// `Plugin` to add the `ClockSync` resource and its signals, and keep them up to date. Adds
+/// `LazySignalsPlugin` if it has not been added yet.
+pub struct LazySignalsClockSyncPlugin {
+    /// Whether this app is the server, whose clock is the one everyone else estimates.
+    pub authoritative: bool,
+
+    /// See `ClockSync::smoothing`.
+    pub smoothing: f64,
+}
+
+impl LazySignalsClockSyncPlugin {
+    pub fn server() -> Self {
+        Self { authoritative: true, ..default() }
+    }
+
+    pub fn client() -> Self {
+        Self::default()
+    }
+}
+
+impl Default for LazySignalsClockSyncPlugin {
+    fn default() -> Self {
+        Self { authoritative: false, smoothing: 0.1 }
+    }
+}
+
+impl Plugin for LazySignalsClockSyncPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<LazySignalsPlugin>() {
+            app.add_plugins(LazySignalsPlugin);
+        }
+        app.register_signal_type::<Duration>();
+
+        let world = app.world_mut();
+        let mut commands = world.commands();
+        let server_time = LazySignals.state(Duration::ZERO, &mut commands);
+        let rtt = LazySignals.state(Duration::ZERO, &mut commands);
+        world.flush();
+
+        app.insert_resource(ClockSync {
+            server_time,
+            rtt,
+            smoothing: self.smoothing,
+            // the server is the clock
+            offset: self.authoritative.then_some(0.0),
+            rtt_seconds: self.authoritative.then_some(0.0),
+        })
+            .add_event::<ClockSyncSample>()
+            .add_systems(PreUpdate, update_clock_sync.in_set(LazySignalsPrePassSet));
+    }
+}
+
+// fold in the samples that arrived since the last frame and send the estimates
+pub fn update_clock_sync(
+    mut samples: EventReader<ClockSyncSample>,
+    mut sync: ResMut<ClockSync>,
+    now: LazySignalsNow,
+    mut commands: Commands
+) {
+    for sample in samples.read() {
+        sync.record(*sample);
+    }
+    if let Some(rtt) = sync.rtt() {
+        LazySignals.send(sync.rtt, rtt, &mut commands);
+    }
+    if let Some(server_time) = sync.to_server_time(now.elapsed()) {
+        LazySignals.send(sync.server_time, server_time, &mut commands);
+    }
+}
+
+/// The timestamp of the last remote send merged into a signal with `send_with_timestamp`.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RemoteTimestamp(pub Duration);
+
+/// Command to send a value made remotely at the given time (e.g. server time), unless a send with
+/// the same or a later timestamp was already merged, so sends that arrive out of order or twice
+/// cannot overwrite a newer value. Dropped sends go to the dead letters as `OutOfOrder`.
+pub struct SendTimestampedCommand<T: LazySignalsData> {
+    pub signal: Entity,
+    pub data: T,
+    pub timestamp: Duration,
+}
+
+impl<T: LazySignalsData> Command for SendTimestampedCommand<T> {
+    fn apply(self, world: &mut World) {
+        let last = world.get::<RemoteTimestamp>(self.signal).map(|last| last.0);
+        if last.is_some_and(|last| last >= self.timestamp) {
+            trace!("-dropping send to {:?} stamped {:?}", self.signal, self.timestamp);
+            dead_letter(self.signal, DeadLetterReason::OutOfOrder, &self.data, false, world);
+            return;
+        }
+        if merge_signal(self.signal, self.data, false, world) {
+            world.entity_mut(self.signal).insert((SendSignal, RemoteTimestamp(self.timestamp)));
+        }
+    }
+}
@@ -1,8 +1,18 @@
-use std::{ marker::PhantomData, sync::Mutex };
+use std::{ any::TypeId, marker::PhantomData, sync::{ Arc, Mutex } };
 
-use bevy::{ ecs::world::Command, prelude::* };
+use bevy::{ ecs::world::Command, prelude::*, reflect::TypeInfo };
 
-use crate::{ bundles::*, framework::*, lazy_immutable::{ LazySignalsState, LazySignalsImmutable } };
+use crate::{
+    arcane_wizardry::{ cache_accessors, coerce_number, data_type_info, with_observable },
+    backpressure::apply_backpressure,
+    bundles::*,
+    dead_letters::{ dead_letter, DeadLetterReason },
+    framework::*,
+    hot_reload::mark_downstream,
+    lazy_immutable::{ LazySignalsState, LazySignalsImmutable },
+    middleware::{ run_middleware, run_middleware_reflect },
+    systems::init::source_type_mismatch,
+};
 
 /// Convenience extension to use each `Command` directly from `Commands` instance.
 pub trait LazySignalsCommandsExt {
@@ -23,6 +33,9 @@ pub trait LazySignalsCommandsExt {
         sources: Vec<Entity>
     );
 
+    /// Command to create a state with no value from the given entity.
+    fn create_empty_state<T: LazySignalsData>(&mut self, state: Entity);
+
     /// Command to create a short-lived effect from the given entity.
     fn create_effect<P: LazySignalsArgs>(
         &mut self,
@@ -32,9 +45,40 @@ pub trait LazySignalsCommandsExt {
         triggers: Vec<Entity>
     );
 
+    /// Command to create a payload trigger (a state that is cleared after each batch) from the
+    /// given entity.
+    fn create_payload_trigger<T: LazySignalsData>(&mut self, trigger: Entity);
+
+    /// Command to create an effect that runs on the compute task pool from the given entity.
+    fn create_pooled_effect<P: LazySignalsArgs>(
+        &mut self,
+        effect: Entity,
+        function: Arc<dyn PooledEffectWrapper>,
+        sources: Vec<Entity>,
+        triggers: Vec<Entity>
+    );
+
     /// Command to create a state (`LazyImmutable` with no `Effect` or `Computed`) from the given entity.
     fn create_state<T: LazySignalsData>(&mut self, state: Entity, data: T);
 
+    /// Command to run the reducer of a store with the given action and send the resulting state.
+    fn dispatch_action<S: LazySignalsData, A: Send + Sync + 'static>(
+        &mut self,
+        store: Entity,
+        action: A
+    );
+
+    /// Command to replace the sources (and triggers, for an effect) of a `Computed` or `Effect`.
+    fn retarget(&mut self, entity: Entity, sources: Vec<Entity>, triggers: Vec<Entity>);
+
+    /// Command to send the initial value of a signal, and optionally of every signal among the
+    /// descendants of the given entity.
+    fn reset_signal(&mut self, signal: Entity, descendants: bool);
+
+    /// Command to send a batch of signals of the same type at once. If an entity appears more than
+    /// once, each of its values is sent in order, as if sent one at a time.
+    fn send_many<T: LazySignalsData>(&mut self, batch: impl IntoIterator<Item = (Entity, T)>);
+
     // Command to send a signal if the data value is different from the current value.
     fn send_signal<T: LazySignalsData>(&mut self, signal: Entity, data: T);
 
@@ -74,6 +118,13 @@ impl<'w, 's> LazySignalsCommandsExt for Commands<'w, 's> {
         });
     }
 
+    fn create_empty_state<T: LazySignalsData>(&mut self, state: Entity) {
+        self.add(CreateEmptyStateCommand::<T> {
+            state,
+            data_type: PhantomData,
+        });
+    }
+
     fn create_effect<P: LazySignalsArgs>(
         &mut self,
         effect: Entity,
@@ -90,6 +141,29 @@ impl<'w, 's> LazySignalsCommandsExt for Commands<'w, 's> {
         });
     }
 
+    fn create_payload_trigger<T: LazySignalsData>(&mut self, trigger: Entity) {
+        self.add(CreatePayloadTriggerCommand::<T> {
+            trigger,
+            data_type: PhantomData,
+        });
+    }
+
+    fn create_pooled_effect<P: LazySignalsArgs>(
+        &mut self,
+        effect: Entity,
+        function: Arc<dyn PooledEffectWrapper>,
+        sources: Vec<Entity>,
+        triggers: Vec<Entity>
+    ) {
+        self.add(CreatePooledEffectCommand::<P> {
+            effect,
+            function,
+            sources,
+            triggers,
+            args_type: PhantomData,
+        });
+    }
+
     fn create_state<T: LazySignalsData>(&mut self, state: Entity, data: T) {
         self.add(CreateStateCommand {
             state,
@@ -97,6 +171,39 @@ impl<'w, 's> LazySignalsCommandsExt for Commands<'w, 's> {
         });
     }
 
+    fn dispatch_action<S: LazySignalsData, A: Send + Sync + 'static>(
+        &mut self,
+        store: Entity,
+        action: A
+    ) {
+        self.add(DispatchActionCommand::<S, A> {
+            store,
+            action,
+            state_type: PhantomData,
+        });
+    }
+
+    fn retarget(&mut self, entity: Entity, sources: Vec<Entity>, triggers: Vec<Entity>) {
+        self.add(RetargetCommand {
+            entity,
+            sources,
+            triggers,
+        });
+    }
+
+    fn reset_signal(&mut self, signal: Entity, descendants: bool) {
+        self.add(ResetSignalCommand {
+            signal,
+            descendants,
+        });
+    }
+
+    fn send_many<T: LazySignalsData>(&mut self, batch: impl IntoIterator<Item = (Entity, T)>) {
+        self.add(SendManySignalsCommand {
+            batch: batch.into_iter().collect(),
+        });
+    }
+
     fn send_signal<T: LazySignalsData>(&mut self, signal: Entity, data: T) {
         self.add(SendSignalCommand {
             signal,
@@ -112,6 +219,289 @@ impl<'w, 's> LazySignalsCommandsExt for Commands<'w, 's> {
     }
 }
 
+/// Complain loudly if `LazySignalsState<T>` was never registered, since reflection will quietly fail.
+fn check_registration<T: LazySignalsData>(world: &World) {
+    let registered = world
+        .get_resource::<AppTypeRegistry>()
+        .is_some_and(|registry| registry.read().contains(TypeId::of::<LazySignalsState<T>>()));
+    if !registered {
+        let type_path = T::type_path();
+        error!(
+            "LazySignalsState<{}> is not registered, call app.register_signal_type::<{}>()",
+            type_path,
+            type_path
+        );
+    }
+}
+
+/// Check that the args have one element per source, since the closure could never be called
+/// otherwise.
+fn check_arity<P: LazySignalsArgs>(
+    entity: Entity,
+    sources: &[Entity],
+    world: &World
+) -> Result<(), LazySignalsError> {
+    if P::LEN == sources.len() {
+        return Ok(());
+    }
+    error!(
+        "{} has {} sources but its args {} have {} elements",
+        entity_label(entity, world),
+        sources.len(),
+        P::type_path(),
+        P::LEN
+    );
+    Err(LazySignalsError::ArityMismatch { sources: sources.len(), args: P::LEN })
+}
+
+/// Check that the new sources of an existing `Computed` or `Effect` fit its args: one per element,
+/// each holding the type of its element.
+fn check_params(
+    entity: Entity,
+    sources: &[Entity],
+    params_type: &'static TypeInfo,
+    world: &mut World
+) -> Result<(), LazySignalsError> {
+    let len = match params_type {
+        TypeInfo::Tuple(info) => info.field_len(),
+        TypeInfo::Struct(info) => info.field_len(),
+        _ => 0,
+    };
+    if len != sources.len() {
+        error!(
+            "{} has {} args elements but was retargeted to {} sources",
+            entity_label(entity, world),
+            len,
+            sources.len()
+        );
+        return Err(LazySignalsError::ArityMismatch { sources: sources.len(), args: len });
+    }
+
+    cache_accessors(sources.iter().copied(), world);
+    for (index, source) in sources.iter().enumerate() {
+        let mismatch = source_type_mismatch(entity, index, *source, params_type, world);
+        if let Some((expected, found)) = mismatch {
+            return Err(LazySignalsError::TypeMismatch {
+                expected: expected.to_string(),
+                found: found.to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Refuse to create a `Computed` or `Effect`: despawn the entity it was going to be added to and
+/// send `SignalNodeRejected`.
+fn reject(entity: Entity, error: LazySignalsError, world: &mut World) {
+    if let Some(entity) = world.get_entity_mut(entity) {
+        entity.despawn_recursive();
+    }
+    world.send_event(SignalNodeRejected { entity, error });
+}
+
+/// Check that no source is listed twice, since each source is passed at its own position in the
+/// args, so there is no telling which position a repeated one was meant for.
+fn check_unique(entity: Entity, sources: &[Entity], world: &World) -> Result<(), LazySignalsError> {
+    for (index, source) in sources.iter().enumerate() {
+        if sources[..index].contains(source) {
+            error!(
+                "{} lists {} more than once in its sources",
+                entity_label(entity, world),
+                entity_label(*source, world)
+            );
+            return Err(LazySignalsError::DuplicateDependency(*source));
+        }
+    }
+    Ok(())
+}
+
+/// Drop repeated triggers, keeping the first of each. Triggers are not passed as args, so only
+/// whether an entity is listed matters, and the subscriber only needs to hear from it once.
+fn dedupe_triggers(entity: Entity, triggers: Vec<Entity>, world: &World) -> Vec<Entity> {
+    let mut unique = Vec::with_capacity(triggers.len());
+    for trigger in triggers {
+        if unique.contains(&trigger) {
+            warn!(
+                "{} lists {} more than once in its triggers, ignoring the repeats",
+                entity_label(entity, world),
+                entity_label(trigger, world)
+            );
+        } else {
+            unique.push(trigger);
+        }
+    }
+    unique
+}
+
+/// Complain loudly about each source whose `LazySignalsState<T>` was never registered, since the
+/// subscriber would quietly be passed `None` for it (see `check_registration`).
+fn check_sources_registration(entity: Entity, sources: &[Entity], world: &World) {
+    let Some(registry) = world.get_resource::<AppTypeRegistry>() else {
+        return;
+    };
+    let registry = registry.read();
+    for source in sources.iter() {
+        let Some(component_id) = world.get::<ImmutableState>(*source).map(|s| s.component_id) else {
+            continue;
+        };
+        let Some(info) = world.components().get_info(component_id) else {
+            continue;
+        };
+        if !info.type_id().is_some_and(|type_id| registry.contains(type_id)) {
+            error!(
+                "{} is a source of {} but {} is not registered, call app.register_signal_type",
+                entity_label(*source, world),
+                entity_label(entity, world),
+                info.name()
+            );
+        }
+    }
+}
+
+/// Check that a computed does not list itself as a source, since it would never settle.
+fn check_not_self(
+    entity: Entity,
+    sources: &[Entity],
+    world: &World
+) -> Result<(), LazySignalsError> {
+    if sources.contains(&entity) {
+        error!(
+            "{} lists itself as a source, a computed cannot depend on its own value",
+            entity_label(entity, world)
+        );
+        return Err(LazySignalsError::SelfReference);
+    }
+    Ok(())
+}
+
+/// Consult the `WriteGuard` of a signal, if any, returning whether a value may be sent to it.
+pub fn guard_signal(signal: Entity, world: &mut World) -> bool {
+    let Some(guard) = world.get::<WriteGuard>(signal) else {
+        return true;
+    };
+    if (guard.allow)(world) {
+        return true;
+    }
+    trace!("-signal {} denied a write", entity_label(signal, world));
+    world.send_event(SignalWriteDenied { entity: signal });
+    false
+}
+
+/// Run the `SignalValidator` of a signal, if any, returning the data to send or `None` if the data
+/// was rejected.
+pub fn validate_signal<T: LazySignalsData>(signal: Entity, data: T, world: &mut World) -> Option<T> {
+    let Some(validator) = world.get::<SignalValidator<T>>(signal) else {
+        return Some(data);
+    };
+    let message = match (validator.validate)(&data) {
+        Ok(()) => {
+            return Some(data);
+        }
+        Err(message) => message,
+    };
+
+    let clamped = validator.clamp.as_ref().map(|clamp| clamp(data));
+    trace!("-signal {} rejected a value: {}", entity_label(signal, world), message);
+    world.send_event(SignalRejected { entity: signal, message, clamped: clamped.is_some() });
+    clamped
+}
+
+/// `ReflectValidateFn` for a `SignalValidator<T>`.
+pub fn validate_reflect<T: LazySignalsData>(
+    signal: Entity,
+    data: Box<dyn Reflect>,
+    world: &mut World
+) -> Option<Box<dyn Reflect>> {
+    match T::from_reflect(data.as_ref()) {
+        Some(data) => validate_signal(signal, data, world).map(|data| Box::new(data) as Box<_>),
+        None => Some(data),
+    }
+}
+
+/// Same as `merge_signal` for a value only known through reflection (e.g. from a snapshot, a
+/// server, or an editor): consult the write guard, run middleware and validation, then merge the
+/// value (or clear it, if `None`) and insert `SendSignal`. A value of another numeric type is
+/// converted when it fits. Backpressure does not apply.
+pub fn merge_signal_reflect(
+    signal: Entity,
+    data: Option<Box<dyn Reflect>>,
+    world: &mut World
+) -> Result<(), LazySignalsError> {
+    let data_type = {
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        data_type_info(signal, world, &type_registry).ok_or(LazySignalsError::NoSignalError)?
+    };
+    let mut data = data.map(|data| coerce_number(data.as_ref(), data_type.type_id()).unwrap_or(data));
+
+    if !guard_signal(signal, world) {
+        return Err(LazySignalsError::SendRefused(signal));
+    }
+    if let Some(data) = data.as_deref_mut() {
+        if !run_middleware_reflect(signal, data, world) {
+            return Err(LazySignalsError::SendRefused(signal));
+        }
+    }
+    let validate = world.get::<ReflectSignalValidator>(signal).map(|validator| validator.validate);
+    if let Some(validate) = validate {
+        if let Some(value) = data.take() {
+            data = Some(validate(signal, value, world).ok_or(LazySignalsError::SendRefused(signal))?);
+        }
+    }
+
+    let found = data.as_ref().map(|data| data.reflect_type_path().to_string());
+    let merged = with_observable(signal, world, |observable, _| {
+        observable.merge_reflect(data.as_deref(), false)
+    });
+    match merged {
+        Some(true) => {
+            trace!("-merged reflected value into {}", entity_label(signal, world));
+            world.entity_mut(signal).insert(SendSignal);
+            Ok(())
+        }
+        Some(false) =>
+            Err(LazySignalsError::TypeMismatch {
+                expected: data_type.type_path().to_string(),
+                found: found.unwrap_or_default(),
+            }),
+        None => Err(LazySignalsError::NoSignalError),
+    }
+}
+
+/// Run middleware and validation on a value and merge it into the next value of the signal, without
+/// inserting `SendSignal`. Returns whether the value was merged.
+pub(crate) fn merge_signal<T: LazySignalsData>(
+    signal: Entity,
+    data: T,
+    trigger: bool,
+    world: &mut World
+) -> bool {
+    if !guard_signal(signal, world) {
+        return false;
+    }
+    let Some(data) = run_middleware(signal, data, world)
+        .and_then(|data| validate_signal(signal, data, world))
+        .and_then(|data| apply_backpressure(signal, data, trigger, world)) else {
+        return false;
+    };
+    // we're less sure the signal actually exists, but don't panic if not
+    // (assume the caller removed it and we don't care about it anymore)
+    if let Some(mut entity) = world.get_entity_mut(signal) {
+        if let Some(mut immutable) = entity.get_mut::<LazySignalsState<T>>() {
+            immutable
+                .bypass_change_detection()
+                .merge_next(LazySignalsResult { data: Some(data), error: None }, trigger);
+            trace!("merged next");
+            return true;
+        }
+        error!("could not get State of {}", entity_label(signal, entity.world()));
+        dead_letter(signal, DeadLetterReason::TypeMismatch, &data, trigger, world);
+    } else {
+        error!("could not get Signal {:?}", signal);
+        dead_letter(signal, DeadLetterReason::MissingEntity, &data, trigger, world);
+    }
+    false
+}
+
 /// Command to create an action (non-blocking effect) from the given entity.
 pub struct CreateActionCommand<P: LazySignalsArgs> {
     pub effect: Entity,
@@ -123,6 +513,15 @@ pub struct CreateActionCommand<P: LazySignalsArgs> {
 
 impl<P: LazySignalsArgs> Command for CreateActionCommand<P> {
     fn apply(self, world: &mut World) {
+        check_sources_registration(self.effect, &self.sources, world);
+
+        let checked = check_unique(self.effect, &self.sources, world).and_then(|_|
+            check_arity::<P>(self.effect, &self.sources, world)
+        );
+        if let Err(error) = checked {
+            return reject(self.effect, error, world);
+        }
+        let triggers = dedupe_triggers(self.effect, self.triggers, world);
         world
             .get_entity_mut(self.effect)
             .unwrap()
@@ -130,7 +529,7 @@ impl<P: LazySignalsArgs> Command for CreateActionCommand<P> {
                 EffectBundle::from_function::<P>(
                     EffectContext::Long(self.function),
                     self.sources,
-                    self.triggers
+                    triggers
                 )
             );
     }
@@ -147,6 +546,16 @@ pub struct CreateComputedCommand<P: LazySignalsArgs, R: LazySignalsData> {
 
 impl<P: LazySignalsArgs, R: LazySignalsData> Command for CreateComputedCommand<P, R> {
     fn apply(self, world: &mut World) {
+        check_registration::<R>(world);
+        check_sources_registration(self.computed, &self.sources, world);
+
+        let checked = check_not_self(self.computed, &self.sources, world)
+            .and_then(|_| check_unique(self.computed, &self.sources, world))
+            .and_then(|_| check_arity::<P>(self.computed, &self.sources, world));
+        if let Err(error) = checked {
+            return reject(self.computed, error, world);
+        }
+
         // once init runs once for a concrete `R`, it just returns the existing `ComponentId` next time
         let component_id = world.init_component::<LazySignalsState<R>>();
         world
@@ -169,6 +578,15 @@ pub struct CreateEffectCommand<P: LazySignalsArgs> {
 
 impl<P: LazySignalsArgs> Command for CreateEffectCommand<P> {
     fn apply(self, world: &mut World) {
+        check_sources_registration(self.effect, &self.sources, world);
+
+        let checked = check_unique(self.effect, &self.sources, world).and_then(|_|
+            check_arity::<P>(self.effect, &self.sources, world)
+        );
+        if let Err(error) = checked {
+            return reject(self.effect, error, world);
+        }
+        let triggers = dedupe_triggers(self.effect, self.triggers, world);
         world
             .get_entity_mut(self.effect)
             .unwrap()
@@ -176,7 +594,73 @@ impl<P: LazySignalsArgs> Command for CreateEffectCommand<P> {
                 EffectBundle::from_function::<P>(
                     EffectContext::Short(self.function),
                     self.sources,
-                    self.triggers
+                    triggers
+                )
+            );
+    }
+}
+
+/// Command to create a `LazyImmutableState` with no value from the given entity.
+pub struct CreateEmptyStateCommand<T: LazySignalsData> {
+    pub state: Entity,
+    pub data_type: PhantomData<T>,
+}
+
+impl<T: LazySignalsData> Command for CreateEmptyStateCommand<T> {
+    fn apply(self, world: &mut World) {
+        check_registration::<T>(world);
+
+        let component_id = world.init_component::<LazySignalsState<T>>();
+        world.get_entity_mut(self.state).unwrap().insert(StateBundle::<T>::empty(component_id));
+    }
+}
+
+/// Command to create an empty `LazyImmutableState` marked `TransientPayload` from the given entity.
+pub struct CreatePayloadTriggerCommand<T: LazySignalsData> {
+    pub trigger: Entity,
+    pub data_type: PhantomData<T>,
+}
+
+impl<T: LazySignalsData> Command for CreatePayloadTriggerCommand<T> {
+    fn apply(self, world: &mut World) {
+        check_registration::<T>(world);
+
+        let component_id = world.init_component::<LazySignalsState<T>>();
+        world
+            .get_entity_mut(self.trigger)
+            .unwrap()
+            .insert((StateBundle::<T>::empty(component_id), TransientPayload));
+    }
+}
+
+/// Command to create a `LazyEffect` that runs on the compute task pool from the given entity.
+pub struct CreatePooledEffectCommand<P: LazySignalsArgs> {
+    pub effect: Entity,
+    pub function: Arc<dyn PooledEffectWrapper>,
+    pub sources: Vec<Entity>,
+    pub triggers: Vec<Entity>,
+    pub args_type: PhantomData<P>,
+}
+
+impl<P: LazySignalsArgs> Command for CreatePooledEffectCommand<P> {
+    fn apply(self, world: &mut World) {
+        check_sources_registration(self.effect, &self.sources, world);
+
+        let checked = check_unique(self.effect, &self.sources, world).and_then(|_|
+            check_arity::<P>(self.effect, &self.sources, world)
+        );
+        if let Err(error) = checked {
+            return reject(self.effect, error, world);
+        }
+        let triggers = dedupe_triggers(self.effect, self.triggers, world);
+        world
+            .get_entity_mut(self.effect)
+            .unwrap()
+            .insert(
+                EffectBundle::from_function::<P>(
+                    EffectContext::Pooled(self.function),
+                    self.sources,
+                    triggers
                 )
             );
     }
@@ -190,6 +674,8 @@ pub struct CreateStateCommand<T: LazySignalsData> {
 
 impl<T: LazySignalsData> Command for CreateStateCommand<T> {
     fn apply(self, world: &mut World) {
+        check_registration::<T>(world);
+
         // store the `ComponentId`` so we can reflect the `LazySignalsState` later
         let component_id = world.init_component::<LazySignalsState<T>>();
         world
@@ -199,6 +685,234 @@ impl<T: LazySignalsData> Command for CreateStateCommand<T> {
     }
 }
 
+/// Command to stop a `Computed` from subscribing to some of its sources, so a change to one of them
+/// alone does not make it recompute.
+pub struct PassiveSourcesCommand {
+    pub computed: Entity,
+    pub sources: Vec<Entity>,
+}
+
+impl Command for PassiveSourcesCommand {
+    fn apply(self, world: &mut World) {
+        let Some(entity_ref) = world.get_entity(self.computed) else {
+            error!("could not make sources passive for {:?}, it does not exist", self.computed);
+            return;
+        };
+        let label = entity_label(self.computed, world);
+        if entity_ref.contains::<TrackedSources>() {
+            error!("{} tracks its own sources and cannot have passive ones", label);
+            return;
+        }
+        let Some(computed) = entity_ref.get::<ComputedImmutable>() else {
+            error!("{} is not a computed", label);
+            return;
+        };
+        let sources: Vec<Entity> = self.sources
+            .into_iter()
+            .filter(|source| {
+                let is_source = computed.sources.contains(source);
+                if !is_source {
+                    error!("{:?} is not a source of {}, not making it passive", source, label);
+                }
+                is_source
+            })
+            .collect();
+
+        let mut entity = world.entity_mut(self.computed);
+        let mut passive = entity.take::<PassiveSources>().unwrap_or_default();
+        for source in sources.iter() {
+            if !passive.sources.contains(source) {
+                passive.sources.push(*source);
+            }
+        }
+        entity.insert(passive);
+
+        // drop the subscriptions that were already made
+        for source in sources {
+            with_observable(source, world, |observable, _| observable.unsubscribe(self.computed));
+        }
+    }
+}
+
+/// Command to send a `Signal` the value it was created with. With `descendants`, the same is done
+/// for every `Signal` in the hierarchy below the given entity, which need not be a signal itself.
+pub struct ResetSignalCommand {
+    pub signal: Entity,
+    pub descendants: bool,
+}
+
+impl Command for ResetSignalCommand {
+    fn apply(self, world: &mut World) {
+        let mut stack = vec![self.signal];
+        while let Some(entity) = stack.pop() {
+            if self.descendants {
+                if let Some(children) = world.get::<Children>(entity) {
+                    stack.extend(children.iter());
+                }
+            }
+
+            // a computed has no initial value of its own and catches up from its sources
+            if world.get::<ComputedImmutable>(entity).is_some() {
+                continue;
+            }
+
+            if with_observable(entity, world, |observable, _| observable.reset()).is_some() {
+                trace!("-reset {:?}", entity);
+                world.entity_mut(entity).insert(SendSignal);
+            } else if !self.descendants {
+                error!("could not reset {}, it is not a signal", entity_label(entity, world));
+            }
+        }
+    }
+}
+
+/// Command to dispatch an action to a store, i.e. a `Signal` with a `SignalReducer`.
+pub struct DispatchActionCommand<S: LazySignalsData, A: Send + Sync + 'static> {
+    pub store: Entity,
+    pub action: A,
+    pub state_type: PhantomData<S>,
+}
+
+impl<S: LazySignalsData, A: Send + Sync + 'static> Command for DispatchActionCommand<S, A> {
+    fn apply(self, world: &mut World) {
+        trace!("DispatchActionCommand {:?}", self.store);
+        if !guard_signal(self.store, world) {
+            return;
+        }
+        let Some(entity) = world.get_entity(self.store) else {
+            error!("could not get Store {:?}", self.store);
+            return;
+        };
+        let (Some(state), Some(reducer)) = (
+            entity.get::<LazySignalsState<S>>(),
+            entity.get::<SignalReducer<S, A>>(),
+        ) else {
+            error!("could not get State and Reducer of {}", entity_label(self.store, world));
+            return;
+        };
+        let Some(current) = state.get() else {
+            error!("store {} has no state to reduce", entity_label(self.store, world));
+            return;
+        };
+        let next = (reducer.reduce)(&current, self.action);
+
+        // merge right away so the next action dispatched this tick reduces the new state
+        let Some(next) = run_middleware(self.store, next, world).and_then(|next| {
+            validate_signal(self.store, next, world)
+        }) else {
+            return;
+        };
+        let mut entity = world.entity_mut(self.store);
+        entity
+            .get_mut::<LazySignalsState<S>>()
+            .unwrap()
+            .bypass_change_detection()
+            .merge_now(LazySignalsResult { data: Some(next), error: None }, false);
+        entity.insert(SendSignal);
+    }
+}
+
+/// Command to replace the sources and triggers of a `Computed` or `Effect`. Only the subscriptions
+/// that actually changed are updated when `init_lazy_signals` runs, and a `Computed` is recomputed.
+/// Sources that do not fit the args are refused with `SignalNodeRejected`.
+pub struct RetargetCommand {
+    pub entity: Entity,
+    pub sources: Vec<Entity>,
+    pub triggers: Vec<Entity>,
+}
+
+impl Command for RetargetCommand {
+    fn apply(self, world: &mut World) {
+        let Some(entity_ref) = world.get_entity(self.entity) else {
+            error!("could not retarget {:?}, it does not exist", self.entity);
+            return;
+        };
+        if entity_ref.contains::<TrackedSources>() {
+            error!(
+                "{} tracks its own sources and cannot be retargeted",
+                entity_label(self.entity, world)
+            );
+            return;
+        }
+        let is_computed = entity_ref.contains::<ComputedImmutable>();
+        let params_type = match entity_ref.get::<ComputedImmutable>() {
+            Some(computed) => Some(computed.params_type),
+            None => entity_ref.get::<LazyEffect>().map(|effect| effect.params_type),
+        };
+        let label = entity_label(self.entity, world);
+        let Some(params_type) = params_type else {
+            error!("{} is not a computed or an effect", label);
+            return;
+        };
+        let checked = match is_computed {
+            true => check_not_self(self.entity, &self.sources, world),
+            false => Ok(()),
+        };
+        let checked = checked
+            .and_then(|_| check_unique(self.entity, &self.sources, world))
+            .and_then(|_| check_params(self.entity, &self.sources, params_type, world));
+        if let Err(error) = checked {
+            world.send_event(SignalNodeRejected { entity: self.entity, error });
+            return;
+        }
+        let sources = self.sources;
+        let triggers = dedupe_triggers(self.entity, self.triggers, world);
+
+        let mut entity = world.entity_mut(self.entity);
+        let (old, new) = if let Some(mut computed) = entity.get_mut::<ComputedImmutable>() {
+            let old = std::mem::replace(&mut computed.sources, sources.clone());
+            (old, sources)
+        } else if let Some(mut effect) = entity.get_mut::<LazyEffect>() {
+            let mut old = std::mem::replace(&mut effect.sources, sources.clone());
+            old.extend(std::mem::replace(&mut effect.triggers, triggers.clone()));
+            let mut new = sources;
+            for trigger in triggers.iter() {
+                if !new.contains(trigger) {
+                    new.push(*trigger);
+                }
+            }
+            (old, new)
+        } else {
+            return;
+        };
+        if is_computed && !triggers.is_empty() {
+            warn!("{} is a computed, ignoring its triggers", label);
+        }
+
+        // not subscribed yet, so init will pick up the new sources anyway
+        if entity.contains::<InitDependencies>() {
+            return;
+        }
+
+        let mut changes = entity.take::<SourceChanges>().unwrap_or_default();
+        changes.record(&old, &new);
+        entity.insert(changes);
+        if is_computed {
+            entity.insert((ComputeMemo, Dirty));
+            mark_downstream(self.entity, world);
+        }
+    }
+}
+
+/// Command to send a batch of signals of the same type, merging every value and then inserting all
+/// the `SendSignal` markers in one pass.
+pub struct SendManySignalsCommand<T: LazySignalsData> {
+    pub batch: Vec<(Entity, T)>,
+}
+
+impl<T: LazySignalsData> Command for SendManySignalsCommand<T> {
+    fn apply(self, world: &mut World) {
+        trace!("SendManySignalsCommand {} values", self.batch.len());
+        // every value runs through middleware, validation, and backpressure in order, the same as
+        // sending them one at a time, so e.g. a `Block` signal queues the ones after the first
+        for (signal, data) in self.batch {
+            if merge_signal(signal, data, false, world) {
+                world.entity_mut(signal).insert(SendSignal);
+            }
+        }
+    }
+}
+
 /// Command to send a Signal (i.e. update a LazyImmutable during the next tick) to the given entity.
 pub struct SendSignalCommand<T: LazySignalsData> {
     pub signal: Entity,
@@ -208,21 +922,8 @@ pub struct SendSignalCommand<T: LazySignalsData> {
 impl<T: LazySignalsData> Command for SendSignalCommand<T> {
     fn apply(self, world: &mut World) {
         trace!("SendSignalCommand {:?}", self.signal);
-        // we're less sure the signal actually exists, but don't panic if not
-        // (assume the caller removed it and we don't care about it anymore)
-        if let Some(mut entity) = world.get_entity_mut(self.signal) {
-            if let Some(mut immutable) = entity.get_mut::<LazySignalsState<T>>() {
-                immutable.merge_next(
-                    LazySignalsResult { data: Some(self.data), error: None },
-                    false
-                );
-                entity.insert(SendSignal);
-                trace!("merged next and inserted SendSignal");
-            } else {
-                error!("could not get Immutable");
-            }
-        } else {
-            error!("could not get Signal");
+        if merge_signal(self.signal, self.data, false, world) {
+            world.entity_mut(self.signal).insert(SendSignal);
         }
     }
 }
@@ -236,21 +937,8 @@ pub struct TriggerSignalCommand<T: LazySignalsData> {
 impl<T: LazySignalsData> Command for TriggerSignalCommand<T> {
     fn apply(self, world: &mut World) {
         trace!("TriggerSignalCommand {:?}", self.signal);
-        // we're less sure the signal actually exists, but don't panic if not
-        // (assume the caller removed it and we don't care about it anymore)
-        if let Some(mut entity) = world.get_entity_mut(self.signal) {
-            if let Some(mut immutable) = entity.get_mut::<LazySignalsState<T>>() {
-                immutable.merge_next(
-                    LazySignalsResult { data: Some(self.data), error: None },
-                    true
-                );
-                entity.insert(SendSignal);
-                trace!("merged next and inserted SendSignal");
-            } else {
-                error!("could not get State");
-            }
-        } else {
-            error!("could not get Signal");
+        if merge_signal(self.signal, self.data, true, world) {
+            world.entity_mut(self.signal).insert(SendSignal);
         }
     }
 }
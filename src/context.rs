@@ -0,0 +1,121 @@
+use bevy::{
+    ecs::{ entity::EntityHashSet, schedule::{ InternedScheduleLabel, ScheduleLabel } },
+    prelude::*,
+};
+
+use crate::{
+    despawn::dependents_of,
+    framework::Paused,
+    lazy_signals_full_systems,
+    LazySignalsPlugin,
+    LazySignalsSystemSet,
+};
+
+/// ## Contexts
+/// Puts a primitive in a labeled context, so it is only processed by the chain added with
+/// `LazySignalsPlugin::labeled` for the same label. Primitives without one belong to the default
+/// context processed by `LazySignalsPlugin` itself.
+///
+/// A subscriber in another context is still marked when one of its sources changes, but it does not
+/// run until its own chain does.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct SignalContext(pub &'static str);
+
+/// The context whose chain is running, `None` for the default context.
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct ActiveSignalContext(pub Option<&'static str>);
+
+impl ActiveSignalContext {
+    pub fn contains(&self, context: Option<&SignalContext>) -> bool {
+        self.0 == context.map(|context| context.0)
+    }
+}
+
+/// While `LazySignals.process_subgraph` runs, only the primitives in the subgraph are processed.
+#[derive(Resource, Default, Debug)]
+pub struct ActiveSubgraph(pub EntityHashSet);
+
+/// Whether the entity belongs to the context whose chain is running (and to the subgraph being
+/// processed, if any) and is not `Paused`.
+pub fn in_active_context(entity: Entity, world: &World) -> bool {
+    if world.get::<Paused>(entity).is_some() {
+        return false;
+    }
+    let in_context = world
+        .get_resource::<ActiveSignalContext>()
+        .copied()
+        .unwrap_or_default()
+        .contains(world.get::<SignalContext>(entity));
+    in_context &&
+        world
+            .get_resource::<ActiveSubgraph>()
+            .map_or(true, |subgraph| subgraph.0.contains(&entity))
+}
+
+/// The root, the entities below it in the hierarchy, and every primitive downstream of any of them.
+pub fn subgraph_of(root: Entity, world: &mut World) -> EntityHashSet {
+    let mut subgraph = EntityHashSet::default();
+    let mut stack = vec![root];
+    while let Some(entity) = stack.pop() {
+        if !subgraph.insert(entity) {
+            continue;
+        }
+        if let Some(children) = world.get::<Children>(entity) {
+            stack.extend(children.iter());
+        }
+        stack.extend(dependents_of(entity, world));
+    }
+    subgraph
+}
+
+/// The schedule holding the systems of one labeled context.
+#[derive(ScheduleLabel, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct LazySignalsContextSchedule(pub &'static str);
+
+/// `Plugin` to process the primitives of one labeled context with their own chain of systems, in a
+/// schedule of their own choosing (e.g. `FixedUpdate` for a simulation). Adds `LazySignalsPlugin`
+/// if it has not been added yet.
+pub struct LazySignalsContextPlugin {
+    pub label: &'static str,
+    pub schedule: InternedScheduleLabel,
+}
+
+impl LazySignalsContextPlugin {
+    /// Run the chain in the given schedule instead of `PreUpdate`.
+    pub fn in_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = schedule.intern();
+        self
+    }
+}
+
+impl LazySignalsPlugin {
+    /// Create a `Plugin` for a labeled context, isolated from the default one and from each other.
+    pub fn labeled(label: &'static str) -> LazySignalsContextPlugin {
+        LazySignalsContextPlugin { label, schedule: PreUpdate.intern() }
+    }
+}
+
+impl Plugin for LazySignalsContextPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<LazySignalsPlugin>() {
+            app.add_plugins(LazySignalsPlugin);
+        }
+
+        // the chain runs as a whole in its own schedule so the active context can't leak into the
+        // systems of another context
+        let label = self.label;
+        app.add_systems(LazySignalsContextSchedule(label), lazy_signals_full_systems()).add_systems(
+            self.schedule,
+            (move |world: &mut World| {
+                world.insert_resource(ActiveSignalContext(Some(label)));
+                world.run_schedule(LazySignalsContextSchedule(label));
+                world.insert_resource(ActiveSignalContext(None));
+            }).in_set(LazySignalsSystemSet)
+        );
+    }
+
+    // several contexts can be added, one per label
+    fn is_unique(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,54 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{ clock::LazySignalsNow, commands::LazySignalsCommandsExt };
+
+/// ## Cooldowns
+/// A `Cooldown` component makes a `bool` signal `true` for `duration` after its trigger fires.
+/// Triggers that fire while it is already `true` are ignored.
+#[derive(Component)]
+pub struct Cooldown {
+    pub duration: Duration,
+    ends_at: Option<Duration>,
+}
+
+impl Cooldown {
+    pub fn new(duration: Duration) -> Self {
+        Self { duration, ends_at: None }
+    }
+
+    pub fn is_cooling_down(&self) -> bool {
+        self.ends_at.is_some()
+    }
+
+    /// Time left until the cooldown ends, given the current `LazySignals` time (see `clock`).
+    pub fn remaining(&self, now: Duration) -> Duration {
+        self.ends_at.map_or(Duration::ZERO, |ends_at| ends_at.saturating_sub(now))
+    }
+
+    /// Start the cooldown unless it is already running. Returns whether it started.
+    pub fn start(&mut self, now: Duration) -> bool {
+        if self.ends_at.is_some() {
+            return false;
+        }
+        self.ends_at = Some(now + self.duration);
+        true
+    }
+}
+
+// send false to each cooldown signal whose time is up
+pub fn tick_cooldowns(
+    mut query_cooldowns: Query<(Entity, &mut Cooldown)>,
+    now: LazySignalsNow,
+    mut commands: Commands
+) {
+    let now = now.elapsed();
+    for (entity, mut cooldown) in query_cooldowns.iter_mut() {
+        if cooldown.ends_at.is_some_and(|ends_at| ends_at <= now) {
+            trace!("-cooldown {:?} is over", entity);
+            cooldown.ends_at = None;
+            commands.send_signal(entity, false);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::VecDeque;
+
+use bevy::{ core::FrameCount, prelude::* };
+
+use crate::framework::*;
+
+/// Why a send was dropped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeadLetterReason {
+    /// The target entity does not exist (anymore).
+    MissingEntity,
+
+    /// The target entity has no `LazySignalsState` of the sent type.
+    TypeMismatch,
+
+    /// The `Backpressure` policy of the target dropped the value.
+    Backpressure,
+
+    /// A send with the same or a later timestamp was already merged (see `send_with_timestamp`).
+    OutOfOrder,
+}
+
+/// A send that never reached its signal.
+#[derive(Clone, Debug)]
+pub struct DeadLetter {
+    pub signal: Entity,
+    pub reason: DeadLetterReason,
+
+    /// Type path of the data that was sent.
+    pub type_path: &'static str,
+
+    /// Debug representation of the data that was sent.
+    pub value: String,
+
+    /// Whether the send was a trigger.
+    pub triggered: bool,
+
+    /// Frame the send was dropped during, if the `FrameCount` resource exists.
+    pub frame: Option<u32>,
+}
+
+/// ## Dead Letters
+/// Ring buffer of the most recent sends that were dropped because the target entity was missing or
+/// held a different type, for postmortem inspection (e.g. in a debug UI).
+///
+/// Not added by the plugin. Insert the resource to start capturing, e.g.
+/// `app.insert_resource(LazySignalsDeadLetters::new(256))`.
+#[derive(Resource)]
+pub struct LazySignalsDeadLetters {
+    capacity: usize,
+    letters: VecDeque<DeadLetter>,
+    total: u64,
+}
+
+impl Default for LazySignalsDeadLetters {
+    fn default() -> Self {
+        Self::new(64)
+    }
+}
+
+impl LazySignalsDeadLetters {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            letters: VecDeque::with_capacity(capacity.max(1)),
+            total: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn clear(&mut self) {
+        self.letters.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.letters.is_empty()
+    }
+
+    /// Iterate over the retained letters, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &DeadLetter> {
+        self.letters.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.letters.len()
+    }
+
+    /// Keep a letter, dropping the oldest one if the buffer is full.
+    pub fn push(&mut self, letter: DeadLetter) {
+        if self.letters.len() == self.capacity {
+            self.letters.pop_front();
+        }
+        self.letters.push_back(letter);
+        self.total += 1;
+    }
+
+    /// Number of letters pushed since the resource was created, including ones no longer retained.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+}
+
+/// Convenience fn to record a dropped send, if the `LazySignalsDeadLetters` resource exists.
+pub fn dead_letter<T: LazySignalsData>(
+    signal: Entity,
+    reason: DeadLetterReason,
+    data: &T,
+    triggered: bool,
+    world: &mut World
+) {
+    if !world.contains_resource::<LazySignalsDeadLetters>() {
+        return;
+    }
+    let frame = world.get_resource::<FrameCount>().map(|frame_count| frame_count.0);
+    let letter = DeadLetter {
+        signal,
+        reason,
+        type_path: T::type_path(),
+        value: format!("{:?}", data.as_reflect()),
+        triggered,
+        frame,
+    };
+    world.resource_mut::<LazySignalsDeadLetters>().push(letter);
+}
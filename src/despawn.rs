@@ -0,0 +1,158 @@
+use bevy::{ ecs::entity::EntityHashSet, prelude::* };
+
+use crate::{ arcane_wizardry::with_observable_in, framework::*, namespace::LazySignalsNamespace };
+
+/// ## Despawning
+/// Every `Computed` and `Effect` that lists `entity` as a source or trigger, or is subscribed to it.
+pub fn dependents_of(entity: Entity, world: &mut World) -> Vec<Entity> {
+    let mut dependents: Vec<Entity> = Vec::new();
+    let mut candidates: Vec<Entity> = world
+        .get_resource::<LazySignalsDependents>()
+        .map(|index| index.get(entity).collect())
+        .unwrap_or_default();
+
+    // primitives created this frame are not in the index until they subscribe
+    let mut query_pending = world.query_filtered::<Entity, With<InitDependencies>>();
+    candidates.extend(query_pending.iter(world));
+
+    for dependent in candidates {
+        // the index is not pruned when a primitive stops depending on the entity, so check
+        let Some(dependent_ref) = world.get_entity(dependent) else {
+            continue;
+        };
+        let computed = dependent_ref.get::<ComputedImmutable>();
+        let effect = dependent_ref.get::<LazyEffect>();
+        let tracked = dependent_ref.get::<TrackedSources>();
+        let depends = computed.is_some_and(|computed| computed.sources.contains(&entity)) ||
+            effect.is_some_and(|effect| {
+                effect.sources.contains(&entity) || effect.triggers.contains(&entity)
+            }) ||
+            tracked.is_some_and(|tracked| tracked.sources.contains(&entity));
+        if depends && dependent != entity && !dependents.contains(&dependent) {
+            dependents.push(dependent);
+        }
+    }
+
+    let subscribers = world.resource_scope(|world, type_registry: Mut<AppTypeRegistry>| {
+        let type_registry = type_registry.read();
+        with_observable_in(entity, world, &type_registry, |observable, _| {
+            observable.get_subscribers()
+        }).unwrap_or_default()
+    });
+    for subscriber in subscribers {
+        if world.get_entity(subscriber).is_some() && !dependents.contains(&subscriber) {
+            dependents.push(subscriber);
+        }
+    }
+    dependents
+}
+
+/// Despawn a primitive, removing it from the subscriber lists of its own sources and handling the
+/// primitives that depend on it according to `policy`. Returns the despawned entities.
+pub fn despawn_with_policy(
+    entity: Entity,
+    policy: DependentPolicy,
+    world: &mut World
+) -> Result<Vec<Entity>, LazySignalsError> {
+    if world.get_entity(entity).is_none() {
+        return Err(LazySignalsError::NoSignalError);
+    }
+
+    let dependents = dependents_of(entity, world);
+    let doomed = match policy {
+        DependentPolicy::Error if !dependents.is_empty() => {
+            return Err(LazySignalsError::HasDependents(entity));
+        }
+        DependentPolicy::Cascade => {
+            // breadth first so each entity is only visited once
+            let mut doomed = vec![entity];
+            let mut seen = EntityHashSet::from_iter([entity]);
+            let mut index = 0;
+            while index < doomed.len() {
+                for dependent in dependents_of(doomed[index], world) {
+                    if seen.insert(dependent) {
+                        doomed.push(dependent);
+                    }
+                }
+                index += 1;
+            }
+            doomed
+        }
+        _ => vec![entity],
+    };
+
+    for doomed_entity in doomed.iter() {
+        trace!("-despawning {}", entity_label(*doomed_entity, world));
+        detach(*doomed_entity, world);
+    }
+
+    if policy == DependentPolicy::Orphan {
+        orphan(entity, &dependents, world);
+    }
+
+    for doomed_entity in doomed.iter() {
+        if let Some(path) = world
+            .resource::<LazySignalsNamespace>()
+            .path_of(*doomed_entity)
+            .map(String::from)
+        {
+            world.resource_mut::<LazySignalsNamespace>().remove(&path);
+        }
+        world.despawn(*doomed_entity);
+    }
+    Ok(doomed)
+}
+
+// take the entity out of the subscriber lists of everything it depends on
+fn detach(entity: Entity, world: &mut World) {
+    let Some(entity_ref) = world.get_entity(entity) else {
+        return;
+    };
+    let mut sources: Vec<Entity> = Vec::new();
+    if let Some(computed) = entity_ref.get::<ComputedImmutable>() {
+        sources.extend(computed.sources.iter());
+    }
+    if let Some(effect) = entity_ref.get::<LazyEffect>() {
+        sources.extend(effect.sources.iter().chain(effect.triggers.iter()));
+    }
+    if let Some(tracked) = entity_ref.get::<TrackedSources>() {
+        sources.extend(tracked.sources.iter());
+    }
+
+    world.resource_scope(|world, type_registry: Mut<AppTypeRegistry>| {
+        let type_registry = type_registry.read();
+        for source in sources.iter() {
+            with_observable_in(*source, world, &type_registry, |observable, _| {
+                observable.unsubscribe(entity);
+            });
+        }
+    });
+
+    if let Some(mut dependents) = world.get_resource_mut::<LazySignalsDependents>() {
+        for source in sources {
+            dependents.remove(source, entity);
+        }
+        dependents.remove_source(entity);
+    }
+}
+
+// leave each dependent without the entity, sending an error to the computeds
+fn orphan(entity: Entity, dependents: &[Entity], world: &mut World) {
+    world.resource_scope(|world, type_registry: Mut<AppTypeRegistry>| {
+        let type_registry = type_registry.read();
+        for dependent in dependents {
+            // triggers are not positional, so an effect can just stop listening
+            if let Some(mut effect) = world.get_mut::<LazyEffect>(*dependent) {
+                effect.triggers.retain(|trigger| *trigger != entity);
+                continue;
+            }
+
+            let sent = with_observable_in(*dependent, world, &type_registry, |observable, _| {
+                observable.send_error(LazySignalsError::SourceRemoved(entity));
+            });
+            if sent.is_some() {
+                world.entity_mut(*dependent).insert(SendSignal);
+            }
+        }
+    });
+}
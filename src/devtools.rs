@@ -0,0 +1,426 @@
+use std::{ io::{ ErrorKind, Read, Write }, net::{ TcpListener, TcpStream, ToSocketAddrs } };
+
+use bevy::{
+    core::FrameCount,
+    prelude::*,
+    reflect::{ serde::{ TypedReflectDeserializer, TypedReflectSerializer }, TypeRegistry },
+};
+use serde::de::DeserializeSeed;
+use serde_json::{ json, Value };
+use tungstenite::{
+    handshake::{ server::{ NoCallback, ServerHandshake }, HandshakeError, MidHandshake },
+    protocol::WebSocketConfig,
+    Message,
+    WebSocket,
+};
+
+use crate::{
+    api::LazySignals,
+    arcane_wizardry::{ data_type_info, with_observable_in },
+    framework::*,
+    introspection::enumerate_signals,
+    namespace::LazySignalsNamespace,
+    LazySignalsSystemSet,
+};
+
+/// ## Devtools
+/// `Plugin` to run a small WebSocket server that streams signal changes to a browser panel (or any
+/// other WebSocket client) and accepts values to send, e.g. to inspect a headless server remotely.
+/// There is no authentication, so it refuses to bind anything but a loopback address unless
+/// `allow_remote` is set, and it only accepts values to send when `allow_send` is set.
+///
+/// Every message is a JSON text frame. Entities are identified by `entity` (`Entity::to_bits`) and
+/// `name` (the `Name` of the entity or its namespace path, if any). Values are plain JSON (e.g.
+/// `75` for a `u32`, `{ "x": 1.0, "y": 2.0 }` for a `Vec2`), or `null` when there is no value.
+///
+/// From the server:
+/// - `{ "type": "snapshot", "frame": 12, "signals": [{ "entity": 4294967301, "name": "health",
+///   "kind": "Signal", "type_path": "u32", "meta": null, "value": 75 }, ...] }` when a client
+///   connects or asks, where `meta` holds the `description`, `tags`, and `owner_module` of a
+///   `LazySignalsMeta`, if any
+/// - `{ "type": "changed", "frame": 13, "entity": 4294967301, "name": "health", "value": 70 }` for
+///   each `Signal` or `Computed` whose value changed
+/// - `{ "type": "error", "message": "..." }` when a message from the client could not be applied
+///
+/// From the client:
+/// - `{ "type": "send", "entity": 4294967301, "value": 100 }` (or `"name": "health"` instead of
+///   `entity`) to send a value to a signal, if `allow_send` is set. The value goes through the
+///   write guard, send middleware, and validator of the signal like `LazySignals.set_from_reflect`
+///   and is applied during the next batch.
+/// - `{ "type": "snapshot" }` to get a new snapshot
+///
+/// Binary messages are ignored. A client is disconnected if its upgrade request is longer than
+/// `MAX_REQUEST_LEN`, if it sends a message longer than `MAX_FRAME_LEN`, or if it falls more than
+/// `MAX_BACKLOG_LEN` bytes behind on reading.
+pub struct LazySignalsDevtoolsPlugin {
+    pub address: String,
+
+    /// Accept `send` messages from clients. Off by default, so the devtools only observe.
+    pub allow_send: bool,
+
+    /// Bind an address other than a loopback one, e.g. to inspect a headless server from another
+    /// machine. Anyone who can reach the address can read (and with `allow_send`, write) signals.
+    pub allow_remote: bool,
+}
+
+impl Default for LazySignalsDevtoolsPlugin {
+    fn default() -> Self {
+        Self { address: "127.0.0.1:9223".to_string(), allow_send: false, allow_remote: false }
+    }
+}
+
+impl Plugin for LazySignalsDevtoolsPlugin {
+    fn build(&self, app: &mut App) {
+        match DevtoolsServer::bind(&self.address, self.allow_remote) {
+            Ok(mut server) => {
+                server.allow_send = self.allow_send;
+                info!("devtools listening on ws://{}", self.address);
+                app.insert_resource(server).add_systems(
+                    PreUpdate,
+                    // `ValueChanged` is still there until the next batch starts
+                    run_devtools.after(LazySignalsSystemSet)
+                );
+            }
+            Err(error) => error!("could not start devtools on {}: {}", self.address, error),
+        }
+    }
+}
+
+/// Longest message a client may send. Messages from the panel are small, so anything longer is
+/// treated as a broken or hostile client.
+pub const MAX_FRAME_LEN: usize = 1 << 20;
+
+/// Longest HTTP upgrade request a client may send before the handshake is done.
+pub const MAX_REQUEST_LEN: usize = 8 << 10;
+
+/// Most bytes a client may have waiting to be written to it. A client that reads too slowly to keep
+/// up with the changes is disconnected rather than buffering without bound.
+pub const MAX_BACKLOG_LEN: usize = 16 << 20;
+
+/// The listening socket and the connected clients.
+#[derive(Resource)]
+pub struct DevtoolsServer {
+    listener: TcpListener,
+    clients: Vec<DevtoolsClient>,
+
+    /// Whether `send` messages from clients are applied.
+    pub allow_send: bool,
+}
+
+impl DevtoolsServer {
+    /// Listen on the address without accepting sends. Fails with `ErrorKind::PermissionDenied` if
+    /// the address is not a loopback one and `allow_remote` is not set.
+    pub fn bind(address: &str, allow_remote: bool) -> std::io::Result<Self> {
+        if !allow_remote && address.to_socket_addrs()?.any(|address| !address.ip().is_loopback()) {
+            return Err(
+                std::io::Error::new(
+                    ErrorKind::PermissionDenied,
+                    "not a loopback address (set allow_remote to bind it)"
+                )
+            );
+        }
+        let listener = TcpListener::bind(address)?;
+        listener.set_nonblocking(true)?;
+        Ok(Self { listener, clients: Vec::new(), allow_send: false })
+    }
+
+    pub fn local_addr(&self) -> std::io::Result<std::net::SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Number of clients that completed the WebSocket handshake.
+    pub fn connected(&self) -> usize {
+        self.clients
+            .iter()
+            .filter(|client| matches!(client, DevtoolsClient::Open(_)))
+            .count()
+    }
+}
+
+enum DevtoolsClient {
+    Accepted(DevtoolsStream),
+    Handshaking(MidHandshake<ServerHandshake<DevtoolsStream, NoCallback>>),
+    Open(WebSocket<DevtoolsStream>),
+    Closed,
+}
+
+impl DevtoolsClient {
+    // take the handshake as far as the socket allows without blocking
+    fn handshake(self) -> Self {
+        let result = match self {
+            DevtoolsClient::Accepted(stream) => {
+                let config = WebSocketConfig {
+                    max_message_size: Some(MAX_FRAME_LEN),
+                    max_frame_size: Some(MAX_FRAME_LEN),
+                    max_write_buffer_size: MAX_BACKLOG_LEN,
+                    ..default()
+                };
+                tungstenite::accept_with_config(stream, Some(config))
+            }
+            DevtoolsClient::Handshaking(handshake) => handshake.handshake(),
+            client => {
+                return client;
+            }
+        };
+        match result {
+            Ok(mut socket) => {
+                socket.get_mut().request_left = None;
+                DevtoolsClient::Open(socket)
+            }
+            Err(HandshakeError::Interrupted(handshake)) => DevtoolsClient::Handshaking(handshake),
+            Err(HandshakeError::Failure(error)) => {
+                trace!("devtools handshake failed: {}", error);
+                DevtoolsClient::Closed
+            }
+        }
+    }
+
+    fn send_json(&mut self, message: &Value) {
+        let DevtoolsClient::Open(socket) = self else {
+            return;
+        };
+        match socket.write(Message::Text(message.to_string())) {
+            Ok(()) => {}
+            // the message is queued and goes out with the next flush
+            Err(tungstenite::Error::Io(error)) if error.kind() == ErrorKind::WouldBlock => {}
+            Err(tungstenite::Error::WriteBufferFull(_)) => {
+                trace!("devtools client is over {} bytes behind, disconnecting", MAX_BACKLOG_LEN);
+                *self = DevtoolsClient::Closed;
+            }
+            Err(_) => {
+                *self = DevtoolsClient::Closed;
+            }
+        }
+    }
+
+    // write as much of the backlog as the socket takes without blocking
+    fn flush(&mut self) {
+        let DevtoolsClient::Open(socket) = self else {
+            return;
+        };
+        match socket.flush() {
+            Ok(()) => {}
+            Err(tungstenite::Error::Io(error)) if error.kind() == ErrorKind::WouldBlock => {}
+            Err(_) => {
+                *self = DevtoolsClient::Closed;
+            }
+        }
+    }
+}
+
+// the socket of a client, which refuses to read more than `MAX_REQUEST_LEN` of the upgrade request
+struct DevtoolsStream {
+    stream: TcpStream,
+    request_left: Option<usize>,
+}
+
+impl Read for DevtoolsStream {
+    fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+        let Some(left) = self.request_left else {
+            return self.stream.read(buffer);
+        };
+        if left == 0 {
+            return Err(std::io::Error::new(ErrorKind::InvalidData, "upgrade request too long"));
+        }
+        let len = buffer.len().min(left);
+        let read = self.stream.read(&mut buffer[..len])?;
+        self.request_left = Some(left - read);
+        Ok(read)
+    }
+}
+
+impl Write for DevtoolsStream {
+    fn write(&mut self, buffer: &[u8]) -> std::io::Result<usize> {
+        self.stream.write(buffer)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.stream.flush()
+    }
+}
+
+// accept clients, apply their messages, and stream the values that changed during this batch
+pub fn run_devtools(
+    world: &mut World,
+    query_changed: &mut QueryState<Entity, (With<ImmutableState>, With<ValueChanged>)>
+) {
+    let changed: Vec<Entity> = query_changed.iter(world).collect();
+    let frame = world.get_resource::<FrameCount>().map_or(0, |frame| frame.0);
+
+    world.resource_scope(|world, mut server: Mut<DevtoolsServer>| {
+        while let Ok((stream, address)) = server.listener.accept() {
+            if stream.set_nonblocking(true).is_ok() {
+                trace!("devtools client connected from {}", address);
+                let stream = DevtoolsStream { stream, request_left: Some(MAX_REQUEST_LEN) };
+                server.clients.push(DevtoolsClient::Accepted(stream));
+            }
+        }
+
+        let allow_send = server.allow_send;
+        for client in server.clients.iter_mut() {
+            if !matches!(client, DevtoolsClient::Open(_)) {
+                *client = std::mem::replace(client, DevtoolsClient::Closed).handshake();
+                if matches!(client, DevtoolsClient::Open(_)) {
+                    let snapshot = snapshot(frame, world);
+                    client.send_json(&snapshot);
+                }
+            }
+            while let DevtoolsClient::Open(socket) = client {
+                // pings and closes are answered by the socket itself, binary messages are ignored
+                let payload = match socket.read() {
+                    Ok(Message::Text(payload)) => payload,
+                    Ok(_) => {
+                        continue;
+                    }
+                    Err(tungstenite::Error::Io(error)) if error.kind() == ErrorKind::WouldBlock => {
+                        break;
+                    }
+                    Err(error) => {
+                        trace!("devtools client disconnected: {}", error);
+                        *client = DevtoolsClient::Closed;
+                        break;
+                    }
+                };
+                if let Some(reply) = handle_message(&payload, allow_send, frame, world) {
+                    client.send_json(&reply);
+                }
+            }
+        }
+
+        if !changed.is_empty() && server.connected() > 0 {
+            let messages: Vec<Value> = changed
+                .iter()
+                .map(|entity| {
+                    json!({
+                        "type": "changed",
+                        "frame": frame,
+                        "entity": entity.to_bits(),
+                        "name": name_of(*entity, world),
+                        "value": value_of(*entity, world),
+                    })
+                })
+                .collect();
+            for client in server.clients.iter_mut() {
+                for message in messages.iter() {
+                    client.send_json(message);
+                }
+            }
+        }
+
+        for client in server.clients.iter_mut() {
+            client.flush();
+        }
+        server.clients.retain(|client| !matches!(client, DevtoolsClient::Closed));
+    });
+}
+
+fn handle_message(payload: &str, allow_send: bool, frame: u32, world: &mut World) -> Option<Value> {
+    let message: Value = match serde_json::from_str(payload) {
+        Ok(message) => message,
+        Err(error) => {
+            return Some(error_message(format!("invalid message: {}", error)));
+        }
+    };
+
+    match message["type"].as_str() {
+        Some("snapshot") => Some(snapshot(frame, world)),
+        Some("send") if !allow_send => {
+            Some(error_message("sends are disabled (set allow_send to accept them)".to_string()))
+        }
+        Some("send") => {
+            let entity = match (message["entity"].as_u64(), message["name"].as_str()) {
+                (Some(bits), _) => Entity::try_from_bits(bits).ok(),
+                (None, Some(name)) => find_by_name(name, world),
+                _ => None,
+            };
+            let Some(entity) = entity.filter(|entity| world.get_entity(*entity).is_some()) else {
+                return Some(error_message(format!("no signal for {}", message)));
+            };
+            match send_json(entity, &message["value"], world) {
+                Ok(()) => None,
+                Err(error) => Some(error_message(error)),
+            }
+        }
+        _ => Some(error_message(format!("unknown message type in {}", message))),
+    }
+}
+
+fn error_message(message: String) -> Value {
+    json!({ "type": "error", "message": message })
+}
+
+fn snapshot(frame: u32, world: &mut World) -> Value {
+    let signals: Vec<Value> = enumerate_signals(world)
+        .into_iter()
+        .map(|info| {
+            json!({
+                "entity": info.entity.to_bits(),
+                "name": name_of(info.entity, world),
+                "kind": format!("{:?}", info.kind),
+                "type_path": info.type_path,
+                "meta": info.meta.map(|meta| {
+                    json!({
+                        "description": meta.description,
+                        "tags": meta.tags,
+                        "owner_module": meta.owner_module,
+                    })
+                }),
+                "value": value_of(info.entity, world),
+            })
+        })
+        .collect();
+    json!({ "type": "snapshot", "frame": frame, "signals": signals })
+}
+
+fn name_of(entity: Entity, world: &World) -> Option<String> {
+    world
+        .get::<Name>(entity)
+        .map(|name| name.to_string())
+        .or_else(|| {
+            world
+                .get_resource::<LazySignalsNamespace>()
+                .and_then(|namespace| namespace.path_of(entity))
+                .map(String::from)
+        })
+}
+
+fn find_by_name(name: &str, world: &mut World) -> Option<Entity> {
+    if let Some(entity) = world
+        .get_resource::<LazySignalsNamespace>()
+        .and_then(|namespace| namespace.lookup(name))
+    {
+        return Some(entity);
+    }
+    let mut query_named = world.query_filtered::<(Entity, &Name), With<ImmutableState>>();
+    query_named
+        .iter(world)
+        .find(|(_, entity_name)| entity_name.as_str() == name)
+        .map(|(entity, _)| entity)
+}
+
+fn value_of(entity: Entity, world: &mut World) -> Value {
+    world.resource_scope(|world, type_registry: Mut<AppTypeRegistry>| {
+        let type_registry = type_registry.read();
+        with_observable_in(entity, world, &type_registry, |observable, _| observable.data_reflect())
+            .flatten()
+            .and_then(|data| to_value(data.as_ref(), &type_registry))
+            .unwrap_or(Value::Null)
+    })
+}
+
+fn to_value(data: &dyn Reflect, type_registry: &TypeRegistry) -> Option<Value> {
+    serde_json::to_value(TypedReflectSerializer::new(data, type_registry)).ok()
+}
+
+fn send_json(entity: Entity, value: &Value, world: &mut World) -> Result<(), String> {
+    let data = world.resource_scope(|world, type_registry: Mut<AppTypeRegistry>| {
+        let type_registry = type_registry.read();
+        let registration = data_type_info(entity, world, &type_registry)
+            .and_then(|info| type_registry.get(info.type_id()))
+            .ok_or_else(|| format!("{} is not a signal", entity_label(entity, world)))?;
+        TypedReflectDeserializer::new(registration, &type_registry)
+            .deserialize(value)
+            .map_err(|error| format!("could not deserialize {}: {}", value, error))
+    })?;
+    LazySignals.set_from_reflect(entity, data.as_ref(), world).map_err(|error| error.to_string())
+}
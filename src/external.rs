@@ -0,0 +1,104 @@
+use std::{ path::PathBuf, sync::Arc };
+
+use bevy::{ input::InputSystem, prelude::*, window::FileDragAndDrop };
+
+use crate::{ api::LazySignals, LazySignalsAppExt, LazySignalsPlugin, LazySignalsSystemSet };
+
+/// ## External Input
+/// Payload triggers fired by input from outside the app, made by `LazySignalsExternalInputPlugin`.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct ExternalInputSignals {
+    /// `Vec<PathBuf>` payload trigger with the files dropped onto a window during the frame.
+    pub files_dropped: Entity,
+
+    /// `String` payload trigger with the text pasted during the frame.
+    pub text_pasted: Entity,
+}
+
+/// Reads the text on the system clipboard. Bevy has no clipboard access of its own, so this is
+/// where a clipboard crate comes in, e.g. `|| arboard::Clipboard::new().ok()?.get_text().ok()`.
+#[derive(Resource, Clone)]
+pub struct LazySignalsClipboard(pub Arc<dyn Fn() -> Option<String> + Send + Sync>);
+
+impl LazySignalsClipboard {
+    pub fn new(read: impl Fn() -> Option<String> + Send + Sync + 'static) -> Self {
+        Self(Arc::new(read))
+    }
+}
+
+/// Send this event when text is pasted some other way, e.g. from a browser `paste` event or a UI
+/// crate that already reads the clipboard, to fire `ExternalInputSignals::text_pasted`.
+#[derive(Event, Clone, Debug)]
+pub struct TextPasted(pub String);
+
+/// `Plugin` to turn file drops and pastes into payload triggers, so an editor-like app can react to
+/// them with ordinary effects. The signals are listed in the `ExternalInputSignals` resource.
+///
+/// A paste is Ctrl+V (or Cmd+V) while a `LazySignalsClipboard` is present to read the text, or a
+/// `TextPasted` event. Adds `LazySignalsPlugin` if it has not been added yet.
+pub struct LazySignalsExternalInputPlugin;
+
+impl Plugin for LazySignalsExternalInputPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<LazySignalsPlugin>() {
+            app.add_plugins(LazySignalsPlugin);
+        }
+
+        app.register_signal_type::<Vec<PathBuf>>()
+            .add_event::<FileDragAndDrop>()
+            .add_event::<TextPasted>()
+            .add_systems(
+                PreUpdate,
+                forward_external_input.after(InputSystem).before(LazySignalsSystemSet)
+            );
+
+        let world = app.world_mut();
+        let mut commands = world.commands();
+        let signals = ExternalInputSignals {
+            files_dropped: LazySignals.payload_trigger::<Vec<PathBuf>>(&mut commands),
+            text_pasted: LazySignals.payload_trigger::<String>(&mut commands),
+        };
+        world.flush();
+        world.insert_resource(signals);
+    }
+}
+
+// fire the payload triggers for the files dropped and text pasted since the last frame
+pub fn forward_external_input(
+    signals: Res<ExternalInputSignals>,
+    mut drops: EventReader<FileDragAndDrop>,
+    mut pastes: EventReader<TextPasted>,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    clipboard: Option<Res<LazySignalsClipboard>>,
+    mut commands: Commands
+) {
+    let files: Vec<PathBuf> = drops
+        .read()
+        .filter_map(|event| {
+            match event {
+                FileDragAndDrop::DroppedFile { path_buf, .. } => Some(path_buf.clone()),
+                _ => None,
+            }
+        })
+        .collect();
+    if !files.is_empty() {
+        trace!("-files dropped: {:?}", files);
+        LazySignals.trigger_with(signals.files_dropped, files, &mut commands);
+    }
+
+    let mut text = pastes.read().last().map(|pasted| pasted.0.clone());
+    if let (Some(keys), Some(clipboard)) = (keys, clipboard) {
+        let modifier = keys.any_pressed([
+            KeyCode::ControlLeft,
+            KeyCode::ControlRight,
+            KeyCode::SuperLeft,
+            KeyCode::SuperRight,
+        ]);
+        if modifier && keys.just_pressed(KeyCode::KeyV) {
+            text = (clipboard.0)().or(text);
+        }
+    }
+    if let Some(text) = text {
+        LazySignals.trigger_with(signals.text_pasted, text, &mut commands);
+    }
+}
@@ -0,0 +1,236 @@
+use std::{ any::{ Any, TypeId }, borrow::Cow, hash::Hash, time::Duration };
+
+use bevy::{
+    ecs::entity::{ Entities, EntityHashMap, EntityHashSet },
+    prelude::*,
+    utils::HashMap,
+};
+
+use crate::{ clock::now, despawn::despawn_with_policy, framework::DependentPolicy };
+
+/// ## Families
+/// A family creates one member (usually a `Computed`) per key on demand, e.g. one per map chunk,
+/// and despawns members that have not been requested for a while according to its `MemoEviction`.
+///
+/// Members are only considered used when requested through `LazySignalsFamily::member` (or `get`),
+/// so anything else holding a member's entity should ask the family for it again each time.
+pub trait LazySignalsFamilyKey: Clone + Eq + Hash + Send + Sync + 'static {}
+
+impl<K: Clone + Eq + Hash + Send + Sync + 'static> LazySignalsFamilyKey for K {}
+
+/// Rules for despawning family members. Without either limit, members live as long as the family.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MemoEviction {
+    /// Keep at most this many members, despawning the least recently used ones first.
+    pub max_members: Option<usize>,
+
+    /// Despawn members that have not been used for this long (according to the
+    /// `LazySignalsClock`, or `Time`).
+    pub ttl: Option<Duration>,
+}
+
+impl MemoEviction {
+    pub fn lru(max_members: usize) -> Self {
+        Self { max_members: Some(max_members), ttl: None }
+    }
+
+    pub fn ttl(ttl: Duration) -> Self {
+        Self { max_members: None, ttl: Some(ttl) }
+    }
+}
+
+type FamilyFactory<K> = Box<dyn Fn(&K, &mut Commands) -> Entity + Send + Sync>;
+
+// type-erased key map, so the eviction system does not need to know `K`
+trait FamilyMembers: Send + Sync {
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+    fn len(&self) -> usize;
+    fn remove(&mut self, entity: Entity);
+}
+
+struct KeyedMembers<K: LazySignalsFamilyKey> {
+    factory: FamilyFactory<K>,
+    entities: HashMap<K, Entity>,
+}
+
+impl<K: LazySignalsFamilyKey> FamilyMembers for KeyedMembers<K> {
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    fn remove(&mut self, entity: Entity) {
+        self.entities.retain(|_, member| *member != entity);
+    }
+}
+
+/// Component that holds the members of a family, keyed by `K`.
+#[derive(Component)]
+pub struct LazySignalsFamily {
+    members: Box<dyn FamilyMembers>,
+    last_used: EntityHashMap<Duration>,
+    used: EntityHashSet,
+    eviction: MemoEviction,
+}
+
+impl LazySignalsFamily {
+    pub fn new<K: LazySignalsFamilyKey>(
+        factory: impl Fn(&K, &mut Commands) -> Entity + Send + Sync + 'static,
+        eviction: MemoEviction
+    ) -> Self {
+        Self {
+            members: Box::new(KeyedMembers::<K> {
+                factory: Box::new(factory),
+                entities: HashMap::new(),
+            }),
+            last_used: EntityHashMap::default(),
+            used: EntityHashSet::default(),
+            eviction,
+        }
+    }
+
+    fn keyed<K: LazySignalsFamilyKey>(&mut self) -> &mut KeyedMembers<K> {
+        match self.members.as_any_mut().downcast_mut::<KeyedMembers<K>>() {
+            Some(members) => members,
+            None => panic!("family key is not a {}", std::any::type_name::<K>()),
+        }
+    }
+
+    /// Get the member for the key, if it exists, and mark it used.
+    pub fn get<K: LazySignalsFamilyKey>(&mut self, key: &K) -> Option<Entity> {
+        let entity = self.keyed::<K>().entities.get(key).copied();
+        if let Some(entity) = entity {
+            self.used.insert(entity);
+        }
+        entity
+    }
+
+    /// Get the member for the key, creating it with the factory if needed, and mark it used.
+    pub fn member<K: LazySignalsFamilyKey>(&mut self, key: &K, commands: &mut Commands) -> Entity {
+        if let Some(entity) = self.get(key) {
+            return entity;
+        }
+
+        let members = self.keyed::<K>();
+        let entity = (members.factory)(key, commands);
+        members.entities.insert(key.clone(), entity);
+        self.used.insert(entity);
+        entity
+    }
+
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // stamp the members used since the last run and return the ones that should be evicted
+    fn evict(&mut self, now: Duration) -> Vec<Entity> {
+        for entity in self.used.drain() {
+            self.last_used.insert(entity, now);
+        }
+
+        let mut evicted = Vec::<Entity>::new();
+        if let Some(ttl) = self.eviction.ttl {
+            self.last_used.retain(|entity, last_used| {
+                let keep = now.saturating_sub(*last_used) <= ttl;
+                if !keep {
+                    evicted.push(*entity);
+                }
+                keep
+            });
+        }
+
+        if let Some(max_members) = self.eviction.max_members {
+            if self.last_used.len() > max_members {
+                let mut by_age: Vec<(Entity, Duration)> = self.last_used
+                    .iter()
+                    .map(|(entity, last_used)| (*entity, *last_used))
+                    .collect();
+                by_age.sort_by_key(|(_, last_used)| *last_used);
+                for (entity, _) in by_age.into_iter().take(self.last_used.len() - max_members) {
+                    self.last_used.remove(&entity);
+                    evicted.push(entity);
+                }
+            }
+        }
+
+        for entity in evicted.iter() {
+            self.members.remove(*entity);
+        }
+        evicted
+    }
+}
+
+// despawn family members that are past their TTL or over the size cap, leaving anything outside
+// the family that still depends on one with a `SourceRemoved` error
+pub fn evict_family_members(
+    world: &mut World,
+    query_families: &mut QueryState<&mut LazySignalsFamily>
+) {
+    let now = now(world);
+    let evicted: Vec<Entity> = query_families
+        .iter_mut(world)
+        .flat_map(|mut family| family.evict(now))
+        .collect();
+    for entity in evicted {
+        trace!("-evicting family member {:?}", entity);
+        // the member may already be gone, e.g. despawned along with the rest of a cascade
+        let _ = despawn_with_policy(entity, DependentPolicy::Orphan, world);
+    }
+}
+
+/// Identifies a `Computed` made by `LazySignals.computed_keyed`: the stable function key, the types
+/// of its params and result, and its sources in order.
+type MemoKey = (Cow<'static, str>, TypeId, TypeId, Vec<Entity>);
+
+/// The memos made by `LazySignals.computed_keyed`, so a definition with the same function key,
+/// types, and sources as an existing one gets that memo instead of a duplicate node. Entries are
+/// dropped once their memo is despawned.
+///
+/// A shared memo is shared by everything that asked for it, so despawning it (e.g. along with one
+/// widget) affects the others too. Despawn the sources instead and let the memo go with them.
+#[derive(Resource, Default)]
+pub struct LazySignalsMemoKeys {
+    memos: HashMap<MemoKey, Entity>,
+}
+
+impl LazySignalsMemoKeys {
+    /// Get the memo for the key, types, and sources, creating it with the factory if needed.
+    pub fn get_or_create<P: 'static, R: 'static>(
+        &mut self,
+        key: Cow<'static, str>,
+        sources: Vec<Entity>,
+        create: impl FnOnce(Vec<Entity>) -> Entity
+    ) -> Entity {
+        let memo_key = (key, TypeId::of::<P>(), TypeId::of::<R>(), sources);
+        if let Some(memo) = self.memos.get(&memo_key) {
+            trace!("-sharing memo {:?} for {}", memo, memo_key.0);
+            return *memo;
+        }
+        let memo = create(memo_key.3.clone());
+        self.memos.insert(memo_key, memo);
+        memo
+    }
+
+    pub fn len(&self) -> usize {
+        self.memos.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.memos.is_empty()
+    }
+}
+
+// forget the keyed memos that have been despawned
+pub fn prune_memo_keys(mut keys: ResMut<LazySignalsMemoKeys>, entities: &Entities) {
+    if keys.memos.values().all(|memo| entities.contains(*memo)) {
+        return;
+    }
+    keys.memos.retain(|_, memo| entities.contains(*memo));
+}
@@ -0,0 +1,186 @@
+use std::{
+    ops::ControlFlow,
+    sync::{ atomic::{ AtomicBool, Ordering }, Arc, Mutex },
+};
+
+use bevy::prelude::*;
+use ehttp::{ streaming::Part, Request };
+
+use crate::{
+    api::LazySignals,
+    commands::LazySignalsCommandsExt,
+    framework::*,
+    LazySignalsAppExt,
+    LazySignalsPlugin,
+    LazySignalsSystemSet,
+};
+
+/// ## Fetch
+/// What a fetch made by `LazySignals.fetch` is doing.
+#[derive(Reflect, Clone, PartialEq, Eq, Default, Debug)]
+pub enum FetchStatus {
+    /// There is no URL to fetch.
+    #[default]
+    Idle,
+
+    /// A request is in flight.
+    Loading,
+
+    /// The last request got a response with this HTTP status code.
+    Done(u16),
+
+    /// The last request did not get a response.
+    Failed(String),
+}
+
+/// A response to a GET request.
+#[derive(Clone, Default, Debug)]
+pub struct FetchResponse {
+    pub status: u16,
+    pub body: Vec<u8>,
+}
+
+/// The response to a request, or why there is none.
+pub type FetchResult = Result<FetchResponse, String>;
+
+/// The signals of a fetch made by `LazySignals.fetch`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Fetch {
+    /// `FetchStatus` signal.
+    pub status: Entity,
+
+    /// `String` signal with the body of the last response, which is kept while the next request is
+    /// loading or if it fails.
+    pub body: Entity,
+}
+
+/// A `FetchInFlight` component on the status signal of a fetch holds the request being made.
+/// Replacing or removing it cancels the request: the download stops at the next chunk of the body
+/// and closes the connection, and the response is never sent.
+#[derive(Component)]
+pub struct FetchInFlight {
+    body: Entity,
+    response: Arc<Mutex<Option<FetchResult>>>,
+    canceled: Arc<AtomicBool>,
+}
+
+impl FetchInFlight {
+    /// Start a GET request of the URL with `ehttp` (on a thread of its own natively, or with the
+    /// `fetch` API of the browser on the web).
+    pub fn start(url: String, body: Entity) -> Self {
+        let response = Arc::new(Mutex::new(None));
+        let canceled = Arc::new(AtomicBool::new(false));
+        let partial = Mutex::new(FetchResponse::default());
+
+        let on_data = {
+            let response = response.clone();
+            let canceled = canceled.clone();
+            move |part: ehttp::Result<Part>| {
+                if canceled.load(Ordering::Relaxed) {
+                    return ControlFlow::Break(());
+                }
+                let mut partial = partial.lock().unwrap();
+                let done = match part {
+                    Ok(Part::Response(head)) => {
+                        partial.status = head.status;
+                        return ControlFlow::Continue(());
+                    }
+                    Ok(Part::Chunk(chunk)) if !chunk.is_empty() => {
+                        partial.body.extend_from_slice(&chunk);
+                        return ControlFlow::Continue(());
+                    }
+                    // an empty chunk is the end of the body
+                    Ok(Part::Chunk(_)) => Ok(std::mem::take(&mut *partial)),
+                    Err(error) => Err(error),
+                };
+                *response.lock().unwrap() = Some(done);
+                ControlFlow::Break(())
+            }
+        };
+        ehttp::streaming::fetch(Request::get(url), on_data);
+
+        Self { body, response, canceled }
+    }
+}
+
+impl Drop for FetchInFlight {
+    fn drop(&mut self) {
+        self.canceled.store(true, Ordering::Relaxed);
+    }
+}
+
+/// `Plugin` for `LazySignals.fetch`. Adds `LazySignalsPlugin` if it has not been added yet.
+pub struct LazySignalsFetchPlugin;
+
+impl Plugin for LazySignalsFetchPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<LazySignalsPlugin>() {
+            app.add_plugins(LazySignalsPlugin);
+        }
+
+        app.register_signal_type::<FetchStatus>()
+            .add_systems(PreUpdate, poll_fetches.before(LazySignalsSystemSet));
+    }
+}
+
+/// Create the signals of a fetch and an `Effect` that starts a GET request of the `String` URL
+/// signal right away and whenever it changes, canceling the request in flight, if any.
+pub fn fetch(url: Entity, commands: &mut Commands) -> Fetch {
+    let status = LazySignals.state(FetchStatus::Idle, commands);
+    let body = commands.spawn_empty().id();
+    commands.create_empty_state::<String>(body);
+
+    let effect = LazySignals.effect::<(Option<String>,)>(
+        move |(url,), world| {
+            let Some(url) = url.filter(|url| !url.is_empty()) else {
+                if let Some(mut entity) = world.get_entity_mut(status) {
+                    entity.remove::<FetchInFlight>();
+                }
+                LazySignals.send_now(status, FetchStatus::Idle, world);
+                return None;
+            };
+
+            trace!("-fetching {}", url);
+            if let Some(mut entity) = world.get_entity_mut(status) {
+                entity.insert(FetchInFlight::start(url, body));
+            }
+            LazySignals.send_now(status, FetchStatus::Loading, world);
+            None
+        },
+        vec![url],
+        vec![],
+        commands
+    );
+    commands.entity(effect).insert(InitialEvaluation(true));
+
+    Fetch { status, body }
+}
+
+// send the status and body of each request that finished
+pub fn poll_fetches(
+    world: &mut World,
+    query_fetches: &mut QueryState<(Entity, &mut FetchInFlight)>
+) {
+    let finished: Vec<(Entity, Entity, FetchResult)> = query_fetches
+        .iter_mut(world)
+        .filter_map(|(status, in_flight)| {
+            let result = in_flight.response.lock().unwrap().take()?;
+            Some((status, in_flight.body, result))
+        })
+        .collect();
+
+    for (status, body, result) in finished {
+        world.entity_mut(status).remove::<FetchInFlight>();
+        match result {
+            Ok(response) => {
+                let text = String::from_utf8_lossy(&response.body).into_owned();
+                LazySignals.send_now(body, text, world);
+                LazySignals.send_now(status, FetchStatus::Done(response.status), world);
+            }
+            Err(error) => {
+                warn!("fetch for {} failed: {}", entity_label(status, world), error);
+                LazySignals.send_now(status, FetchStatus::Failed(error), world);
+            }
+        }
+    }
+}
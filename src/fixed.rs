@@ -0,0 +1,152 @@
+use std::{ fmt, ops::{ Add, Div, Mul, Neg, Sub } };
+
+use bevy::prelude::*;
+
+macro_rules! fixed {
+    ($(#[$meta:meta])* $name:ident, $raw:ty, $wide:ty, $frac:literal) => {
+        $(#[$meta])*
+        #[derive(Reflect, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        #[reflect(PartialEq, Hash, Debug, Default)]
+        pub struct $name($raw);
+
+        impl $name {
+            /// Number of fractional bits.
+            pub const FRAC_BITS: u32 = $frac;
+            pub const ZERO: Self = Self(0);
+            pub const ONE: Self = Self(1 << $frac);
+            pub const MIN: Self = Self(<$raw>::MIN);
+            pub const MAX: Self = Self(<$raw>::MAX);
+
+            /// Make a value from its underlying integer, i.e. the value times `2^FRAC_BITS`.
+            pub const fn from_raw(raw: $raw) -> Self {
+                Self(raw)
+            }
+
+            pub const fn raw(self) -> $raw {
+                self.0
+            }
+
+            pub const fn from_int(value: $raw) -> Self {
+                Self(value.wrapping_shl($frac))
+            }
+
+            /// Make a value from the ratio of two integers, e.g. `from_ratio(1, 3)`, without going
+            /// through floating point. Rounds toward negative infinity like division, and panics if
+            /// `denominator` is zero.
+            pub const fn from_ratio(numerator: $raw, denominator: $raw) -> Self {
+                Self(Self::div_raw(numerator, denominator))
+            }
+
+            // the raw quotient of two raw values, rounding toward negative infinity where `/`
+            // truncates toward zero
+            const fn div_raw(numerator: $raw, denominator: $raw) -> $raw {
+                let numerator = (numerator as $wide) << $frac;
+                let denominator = denominator as $wide;
+                let quotient = numerator / denominator;
+                if numerator % denominator != 0 && (numerator < 0) != (denominator < 0) {
+                    (quotient - 1) as $raw
+                } else {
+                    quotient as $raw
+                }
+            }
+
+            /// Convert from a float, rounding to the nearest value. Floats are only deterministic
+            /// across machines for exactly representable values, so keep this to setup code.
+            pub fn from_f64(value: f64) -> Self {
+                Self((value * (1u64 << $frac) as f64).round() as $raw)
+            }
+
+            pub fn to_f32(self) -> f32 {
+                self.to_f64() as f32
+            }
+
+            pub fn to_f64(self) -> f64 {
+                self.0 as f64 / (1u64 << $frac) as f64
+            }
+
+            /// The integer part, rounded toward negative infinity.
+            pub const fn floor(self) -> $raw {
+                self.0 >> $frac
+            }
+
+            pub const fn abs(self) -> Self {
+                Self(self.0.wrapping_abs())
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self(self.0.wrapping_add(other.0))
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self(self.0.wrapping_sub(other.0))
+            }
+        }
+
+        impl Mul for $name {
+            type Output = Self;
+
+            fn mul(self, other: Self) -> Self {
+                Self((((self.0 as $wide) * (other.0 as $wide)) >> $frac) as $raw)
+            }
+        }
+
+        impl Div for $name {
+            type Output = Self;
+
+            /// Rounds toward negative infinity like `mul`. Panics if `other` is zero, like integer
+            /// division.
+            fn div(self, other: Self) -> Self {
+                Self(Self::div_raw(self.0, other.0))
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self(self.0.wrapping_neg())
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.to_f64(), f)
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}({})", stringify!($name), self.to_f64())
+            }
+        }
+    };
+}
+
+fixed!(
+    /// ## Fixed Point
+    /// A signed 16.16 fixed-point number for signals in deterministic (e.g. lockstep) simulations,
+    /// where the same inputs must give bit-identical results on every machine. Arithmetic is
+    /// integer arithmetic that wraps on overflow (in debug builds too), rounding toward negative
+    /// infinity. It works with the numeric operators (`add`, `mul`, `clamp`, ...) and comparisons
+    /// (`greater_than`, `hysteresis`, ...) like any other number.
+    Fixed32,
+    i32,
+    i64,
+    16
+);
+
+fixed!(
+    /// A signed 32.32 fixed-point number, for more range or precision than `Fixed32`.
+    Fixed64,
+    i64,
+    i128,
+    32
+);
@@ -26,6 +26,7 @@ impl<R: LazySignalsData> ComputedBundle<R> {
                 function,
                 sources,
                 args_type: TypeId::of::<P>(),
+                params_type: P::type_info(),
                 result_type: TypeId::of::<LazySignalsState<R>>(),
             },
             init: InitDependencies,
@@ -51,6 +52,7 @@ impl EffectBundle {
                 sources,
                 triggers,
                 args_type: TypeId::of::<P>(),
+                params_type: P::type_info(),
             },
             init: InitDependencies,
         }
@@ -73,4 +75,14 @@ impl<T: LazySignalsData> StateBundle<T> {
             meta: ImmutableState { component_id },
         }
     }
+
+    pub fn empty(component_id: ComponentId) -> StateBundle<T> {
+        StateBundle {
+            state: LazySignalsState::<T>::new(LazySignalsResult {
+                data: None,
+                error: None,
+            }),
+            meta: ImmutableState { component_id },
+        }
+    }
 }
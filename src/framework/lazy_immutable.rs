@@ -1,4 +1,10 @@
-use bevy::{ prelude::*, reflect::{ reflect_trait, DynamicTuple, Reflect } };
+use std::sync::atomic::{ AtomicU64, Ordering };
+
+use bevy::{
+    ecs::entity::EntityHashSet,
+    prelude::*,
+    reflect::{ reflect_trait, DynamicTuple, FromType, Reflect },
+};
 
 use crate::arcane_wizardry::{ clone_data, insert_data };
 
@@ -16,6 +22,10 @@ pub trait LazySignalsImmutable: Send + Sync + 'static {
     /// Provide a new value for the lazy update system to merge.
     fn merge_next(&mut self, next: LazySignalsResult<Self::DataType>, trigger: bool);
 
+    /// Merge a new value right away so it can be read immediately. Subscribers are still notified
+    /// by the lazy update system, so `SendSignal` must be added as usual.
+    fn merge_now(&mut self, next: LazySignalsResult<Self::DataType>, trigger: bool);
+
     /// Immediately update a new value without triggering any subscribers (mostly used internally).
     fn update(&mut self, next: LazySignalsResult<Self::DataType>) -> bool;
 
@@ -35,20 +45,60 @@ pub trait LazySignalsObservable {
     /// Add `None` to the args.
     fn append_none(&mut self, args: &mut DynamicTuple);
 
-    /// Copy the data into a dynamic tuple of args for the `Effect` or `Computed` to consume.
-    fn copy_data(&mut self, caller: Entity, args: &mut DynamicTuple);
+    /// Replace the current error with a `ComputeFailed` that records the upstream error `caused_by`
+    /// held by the source `at`.
+    fn chain_error(&mut self, at: Entity, caused_by: LazySignalsError);
+
+    /// Clear the current value without notifying subscribers.
+    fn clear(&mut self);
+
+    /// Copy the data into a dynamic tuple of args for the `Effect` or `Computed` to consume, as the
+    /// element at the index.
+    fn copy_data(&mut self, caller: Entity, args: &mut DynamicTuple, index: usize);
+
+    /// Get a reflected copy of the current data, if any.
+    fn data_reflect(&self) -> Option<Box<dyn Reflect>>;
+
+    /// Get a copy of the current error, if any.
+    fn error_reflect(&self) -> Option<LazySignalsError>;
 
     /// Get the list of subscribers that may need notification.
     fn get_subscribers(&self) -> Vec<Entity>;
 
+    /// Same as `get_subscribers` but appends them to a list the caller reuses.
+    fn append_subscribers(&self, subs: &mut Vec<Entity>);
+
+    /// The version of the current value or error. It goes up every time they change and is never
+    /// shared by two states, so the same version means the same value.
+    fn version(&self) -> u64;
+
+    /// Whether there is a current value (as opposed to an error or nothing at all).
+    fn has_data(&self) -> bool;
+
     /// This method merges the `next_value` and returns `get_subscribers()`.
     fn merge(&mut self) -> MaybeFlaggedEntities;
 
+    /// Same as `merge` but appends the subscribers to a list the caller reuses, returning whether
+    /// the value changed and whether it was triggered.
+    fn merge_into(&mut self, subs: &mut Vec<Entity>) -> (bool, bool);
+
+    /// Same as `merge_next` but for reflected data. Returns `false` if the data is the wrong type.
+    fn merge_reflect(&mut self, data: Option<&dyn Reflect>, trigger: bool) -> bool;
+
+    /// Send the value the state was created with as the next value.
+    fn reset(&mut self);
+
+    /// Send an error as the next value.
+    fn send_error(&mut self, error: LazySignalsError);
+
     /// Called by a lazy update system to refresh the subscribers.
     fn merge_subscribers(&mut self);
 
     /// Called by an `Effect` or `Memo` indirectly by reading the current value.
     fn subscribe(&mut self, entity: Entity);
+
+    /// Remove a subscriber that is going away.
+    fn unsubscribe(&mut self, entity: Entity);
 }
 
 /// A `LazySignalsState` is known as a cell in a propagator network. It may also be referred to as
@@ -60,34 +110,144 @@ pub trait LazySignalsObservable {
 /// `LazyImmutableBool`, `LazyImmutableInt`, `LazyImmutableFloat`, `LazyImmutableStr`, `LazyImmutableUnit`.
 ///
 /// The subscriber set is built from the `sources`/`triggers` of computed memos and effects, so it does
-/// not have to be serialized, which is good because the `SparseSet` doesn't seem to do `Reflect`.
+/// not have to be serialized. It is an `EntityHashSet` rather than a `SparseSet`, so each state
+/// only pays for the subscribers it has (not for the highest entity index among them), and checking
+/// or adding an edge stays O(1) with tens of thousands of primitives.
 ///
 /// This `LazySignalsState` component is lazy. Other forms are left as an exercise for the reader.
+///
+/// Change detection is guaranteed to follow the value: the component is marked changed exactly when
+/// a `Signal` or `Computed` gets a new value or error (when `ValueChanged` is added), so systems
+/// can use `Query<&LazySignalsState<T>, Changed<LazySignalsState<T>>>` instead of subscribing.
+/// Staging a value with `send`, subscribing, and other bookkeeping do not mark it changed.
+///
+/// Each new value or error is also stamped with a version, which lets a `Computed` that was marked
+/// to recompute skip it when its sources hold the same versions as the last time it ran.
 #[derive(Component, Reflect)]
-#[reflect(Component, LazySignalsObservable)]
+#[reflect(Component, LazySignalsObservable, LazySignalsDataType)]
 pub struct LazySignalsState<T: LazySignalsData> {
     result: LazySignalsResult<T>,
+    initial: Option<T>,
     next_value: LazySignalsResult<T>,
     triggered: bool,
+    merged_early: bool,
+    #[reflect(ignore)]
+    version: u64,
     #[reflect(ignore)]
-    subscribers: EntitySet,
+    subscribers: EntityHashSet,
     #[reflect(ignore)]
-    next_subscribers: EntitySet,
+    next_subscribers: EntityHashSet,
+}
+
+/// Type data with the types behind a `LazySignalsState<T>`, recorded when the state type is
+/// registered so they can be looked up from the `TypeId` of the component alone.
+#[derive(Clone)]
+pub struct ReflectLazySignalsDataType {
+    /// The `TypeId` and path of the `Option<T>` a subscriber gets from the state.
+    pub param_type: (TypeId, &'static str),
+
+    /// The `TypeInfo` of `T`.
+    pub data_type: &'static TypeInfo,
+}
+
+impl<T: LazySignalsData> FromType<LazySignalsState<T>> for ReflectLazySignalsDataType {
+    fn from_type() -> Self {
+        Self {
+            param_type: (TypeId::of::<Option<T>>(), Option::<T>::type_path()),
+            data_type: T::type_info(),
+        }
+    }
 }
 
 impl<T: LazySignalsData> LazySignalsState<T> {
     pub fn new(result: LazySignalsResult<T>) -> Self {
         Self {
+            initial: clone_data(&result).data,
             result,
             next_value: LazySignalsResult {
                 data: None,
                 error: Some(LazySignalsError::NoNextValue),
             },
             triggered: false,
-            subscribers: empty_set(),
-            next_subscribers: empty_set(),
+            merged_early: false,
+            version: next_version(),
+            subscribers: EntityHashSet::default(),
+            next_subscribers: EntityHashSet::default(),
         }
     }
+
+    /// Overwrite the value with `next_value` if needed, returning whether it was overwritten and
+    /// whether the data actually changed.
+    fn apply_next(&mut self) -> (bool, bool) {
+        let mut changed = false;
+
+        // whether or not to overwrite the existing info
+        let doo_eet = match &self.next_value.error {
+            Some(err) =>
+                match err {
+                    // not a real error, nothing to merge...
+                    LazySignalsError::NoNextValue => false,
+
+                    // always merge errors, but only notify subscribers about a new one
+                    _ => {
+                        changed = self.result.error.as_ref() != Some(err);
+                        true
+                    }
+                }
+            None =>
+                // if there is no error, then compare the data values
+                match &self.next_value.data {
+                    Some(next) => {
+                        trace!("next exists");
+                        match &self.result.data {
+                            Some(data) => {
+                                trace!("data exists");
+                                if data != next {
+                                    // the new data is different
+                                    trace!("data != next");
+                                    changed = true;
+                                    true
+                                } else {
+                                    // the new data is the same as current
+                                    false
+                                }
+                            }
+                            None => {
+                                // currently no data but there's data to merge which means changed
+                                changed = true;
+                                true
+                            }
+                        }
+                    }
+                    // there is no next value, so clear out the current value
+                    None => {
+                        changed = self.result.data.is_some();
+                        changed
+                    }
+                }
+        };
+
+        // overwrite the value
+        if doo_eet {
+            if changed {
+                self.version = next_version();
+            }
+            // move rather than clone, cloning goes through reflection and allocates
+            self.result = std::mem::replace(&mut self.next_value, LazySignalsResult {
+                data: None,
+                error: Some(LazySignalsError::NoNextValue),
+            });
+        }
+
+        (doo_eet, changed)
+    }
+}
+
+// versions are handed out from one counter, so a state that replaces another never repeats one
+static NEXT_VERSION: AtomicU64 = AtomicU64::new(1);
+
+fn next_version() -> u64 {
+    NEXT_VERSION.fetch_add(1, Ordering::Relaxed)
 }
 
 impl<T: LazySignalsData> LazySignalsImmutable for LazySignalsState<T> {
@@ -106,8 +266,17 @@ impl<T: LazySignalsData> LazySignalsImmutable for LazySignalsState<T> {
         self.triggered = triggered;
     }
 
+    fn merge_now(&mut self, next_value: LazySignalsResult<T>, triggered: bool) {
+        self.merge_next(next_value, triggered);
+        let (_, changed) = self.apply_next();
+        self.merged_early |= changed;
+    }
+
     fn update(&mut self, next: LazySignalsResult<Self::DataType>) -> bool {
         let changed = self.result != next;
+        if changed {
+            self.version = next_version();
+        }
         self.result = next;
         changed
     }
@@ -118,83 +287,77 @@ impl<T: LazySignalsData> LazySignalsObservable for LazySignalsState<T> {
         args.insert(Option::<T>::None);
     }
 
-    fn copy_data(&mut self, caller: Entity, args: &mut DynamicTuple) {
-        insert_data(args, &self.result);
+    fn chain_error(&mut self, at: Entity, caused_by: LazySignalsError) {
+        let error = Some(LazySignalsError::ComputeFailed {
+            at,
+            caused_by: Box::new(caused_by),
+        });
+        if self.result.error != error {
+            self.version = next_version();
+        }
+        self.result.error = error;
+    }
+
+    fn clear(&mut self) {
+        if self.result.data.is_some() || self.result.error.is_some() {
+            self.version = next_version();
+        }
+        self.result = LazySignalsResult { data: None, error: None };
+    }
+
+    fn copy_data(&mut self, caller: Entity, args: &mut DynamicTuple, index: usize) {
+        insert_data(args, index, &self.result);
 
         self.subscribe(caller);
     }
 
+    fn data_reflect(&self) -> Option<Box<dyn Reflect>> {
+        self.result.data.as_ref().map(|data| data.clone_value())
+    }
+
+    fn error_reflect(&self) -> Option<LazySignalsError> {
+        self.result.error.clone()
+    }
+
     fn get_subscribers(&self) -> Vec<Entity> {
         let mut subs = Vec::<Entity>::new();
+        self.append_subscribers(&mut subs);
+        subs
+    }
 
+    fn append_subscribers(&self, subs: &mut Vec<Entity>) {
         // copy the subscribers into the output vector
-        subs.extend(self.subscribers.indices());
+        subs.extend(self.subscribers.iter());
         trace!("-found subs {:?}", self.subscribers);
-        subs
     }
 
-    fn merge(&mut self) -> MaybeFlaggedEntities {
-        let mut changed = false;
-        let triggered = self.triggered;
+    fn has_data(&self) -> bool {
+        self.result.data.is_some()
+    }
+
+    fn version(&self) -> u64 {
+        self.version
+    }
 
+    fn merge(&mut self) -> MaybeFlaggedEntities {
         // output vector for downstream subscribers to process next
         let mut subs = Vec::<Entity>::new();
+        let (changed, triggered) = self.merge_into(&mut subs);
+        Some((subs, changed, triggered))
+    }
 
-        // whether or not to overwrite the existing info
-        let doo_eet = match &self.next_value.error {
-            Some(err) =>
-                // always merge errors
-                match err {
-                    LazySignalsError::NoSignalError => true,
-
-                    // not a real error, nothing to merge...
-                    LazySignalsError::NoNextValue => false,
-
-                    LazySignalsError::ReadError(_) => true,
-                }
-            None =>
-                // if there is no error, then compare the data values
-                match &self.next_value.data {
-                    Some(next) => {
-                        trace!("next exists");
-                        match &self.result.data {
-                            Some(data) => {
-                                trace!("data exists");
-                                if data != next {
-                                    // the new data is different
-                                    trace!("data != next");
-                                    changed = true;
-                                    true
-                                } else {
-                                    // the new data is the same as current
-                                    false
-                                }
-                            }
-                            None => {
-                                // currently no data but there's data to merge which means changed
-                                changed = true;
-                                true
-                            }
-                        }
-                    }
-                    // there is no next value, so clear out the current value
-                    None => self.result.data.is_some(),
-                }
-        };
+    fn merge_into(&mut self, subs: &mut Vec<Entity>) -> (bool, bool) {
+        let (doo_eet, changed) = self.apply_next();
+        let triggered = self.triggered;
 
-        // overwrite the value
-        if doo_eet {
-            self.result = clone_data(&self.next_value);
-            self.next_value = LazySignalsResult {
-                data: None,
-                error: Some(LazySignalsError::NoNextValue),
-            };
-        }
+        // a value merged early still needs to notify its subscribers
+        let changed = changed || self.merged_early;
+        self.merged_early = false;
 
         // return a list of subscribers
-        if doo_eet || triggered {
+        if doo_eet || changed || triggered {
             // copy the subscribers into the output vector
-            subs = self.get_subscribers();
+            self.append_subscribers(subs);
 
             // clear the local subscriber set which will be replenished by each subscriber if
             // it calls the value method later
@@ -203,17 +366,43 @@ impl<T: LazySignalsData> LazySignalsObservable for LazySignalsState<T> {
             // trigger is processed, so reset the flag
             self.triggered = false;
         }
-        Some((subs, changed, triggered))
+        (changed, triggered)
+    }
+
+    fn merge_reflect(&mut self, data: Option<&dyn Reflect>, trigger: bool) -> bool {
+        let data = match data {
+            Some(data) =>
+                match T::from_reflect(data) {
+                    Some(data) => Some(data),
+                    None => {
+                        return false;
+                    }
+                }
+            None => None,
+        };
+        self.merge_next(LazySignalsResult { data, error: None }, trigger);
+        true
+    }
+
+    fn reset(&mut self) {
+        let data = self.initial.as_ref().and_then(|data| T::from_reflect(data));
+        self.merge_next(LazySignalsResult { data, error: None }, false);
+    }
+
+    fn send_error(&mut self, error: LazySignalsError) {
+        self.merge_next(LazySignalsResult { data: None, error: Some(error) }, false);
     }
 
     fn merge_subscribers(&mut self) {
-        for subscriber in self.next_subscribers.indices() {
-            self.subscribers.insert(subscriber, ());
-        }
-        self.next_subscribers.clear();
+        self.subscribers.extend(self.next_subscribers.drain());
     }
 
     fn subscribe(&mut self, entity: Entity) {
-        self.next_subscribers.insert(entity, ());
+        self.next_subscribers.insert(entity);
+    }
+
+    fn unsubscribe(&mut self, entity: Entity) {
+        self.subscribers.remove(&entity);
+        self.next_subscribers.remove(&entity);
     }
 }
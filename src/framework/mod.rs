@@ -1,20 +1,38 @@
-use std::{ any::TypeId, fmt::Debug, sync::Mutex };
+use std::{
+    any::TypeId,
+    fmt::Debug,
+    ops::{ Deref, Range },
+    sync::{ Arc, Mutex },
+    time::Duration,
+};
 
 use bevy::{
     ecs::{
-        component::{ ComponentId, ComponentInfo },
-        storage::SparseSet,
+        change_detection::MutUntyped,
+        component::{ ComponentId, ComponentInfo, Components },
+        entity::{ EntityHashMap, EntityHashSet },
+        storage::{ SparseSet, SparseSetIndex },
         system::BoxedSystem,
         world::CommandQueue,
     },
     prelude::*,
-    reflect::{ DynamicTuple, GetTypeRegistration, Tuple },
+    reflect::{ GetTypeRegistration, ReflectFromPtr, Tuple, TypeInfo, TypeRegistry, Typed },
     tasks::Task,
+    utils::all_tuples,
 };
 
 use thiserror::Error;
 
-use crate::LazySignalsObservable;
+use crate::{
+    lazy_immutable::ReflectLazySignalsDataType,
+    LazySignalsImmutable,
+    LazySignalsObservable,
+    LazySignalsState,
+    ReflectLazySignalsObservable,
+};
+
+pub use bevy::reflect::DynamicTuple;
+pub use bevy_lazy_signals_macros::LazySignalsParams;
 
 pub mod bundles;
 pub mod lazy_immutable;
@@ -28,12 +46,70 @@ pub struct LazySignalsResult<R: LazySignalsData> {
     pub error: Option<LazySignalsError>,
 }
 
+/// Wraps large or non-`Clone` data in an `Arc` so it can be sent as a signal.
+///
+/// Reflection treats it as an opaque value, so every copy handed to a subscriber only clones the
+/// `Arc`, and equality is `Arc::ptr_eq`: a signal changes when a new allocation is sent, not when
+/// the pointed-to data compares differently.
+#[derive(Reflect)]
+#[reflect_value(PartialEq)]
+pub struct LazySignalsShared<T: Send + Sync + TypePath>(Arc<T>);
+
+impl<T: Send + Sync + TypePath> LazySignalsShared<T> {
+    pub fn new(data: T) -> Self {
+        Self(Arc::new(data))
+    }
+
+    /// Get the underlying `Arc` (e.g. to hold onto the data outside a closure).
+    pub fn arc(&self) -> &Arc<T> {
+        &self.0
+    }
+}
+
+impl<T: Send + Sync + TypePath> Clone for LazySignalsShared<T> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<T: Send + Sync + TypePath> PartialEq for LazySignalsShared<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<T: Send + Sync + TypePath> Deref for LazySignalsShared<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Send + Sync + TypePath> From<Arc<T>> for LazySignalsShared<T> {
+    fn from(arc: Arc<T>) -> Self {
+        Self(arc)
+    }
+}
+
+/// Value of a signal made by `LazySignals.merge`: which of the merged sources was sent, and what.
+#[derive(PartialEq, Reflect)]
+pub struct MergedValue<T: LazySignalsData> {
+    /// Position of the source in the list passed to `merge`.
+    pub index: usize,
+    pub source: Entity,
+    pub value: T,
+}
+
 /// Return type for an optional list of entities and some flags (changed, triggered).
 pub type MaybeFlaggedEntities = Option<(Vec<Entity>, bool, bool)>;
 
 /// ## Enums
 /// Read error.
-#[derive(Error, Clone, Copy, PartialEq, Reflect, Debug)]
+///
+/// Reflected as an opaque value, since `ComputeFailed` nests another error.
+#[derive(Error, Clone, PartialEq, Reflect, Debug)]
+#[reflect_value(PartialEq, Debug)]
 pub enum LazySignalsError {
     /// An attempt was made to reference a `LazySignals` entity that does not exist.
     #[error["Signal does not exist"]]
@@ -46,6 +122,109 @@ pub enum LazySignalsError {
     /// An attempt was made to read a signal and something weird went wrong.
     #[error("Error reading signal {0:?}")]
     ReadError(Entity),
+
+    /// The entity held by an `Entity` signal was despawned.
+    #[error("Entity {0:?} was despawned")]
+    EntityDespawned(Entity),
+
+    /// A primitive that this one depends on was despawned with `DependentPolicy::Orphan`.
+    #[error("Source {0:?} was removed")]
+    SourceRemoved(Entity),
+
+    /// A primitive could not be despawned with `DependentPolicy::Error` because others depend on it.
+    #[error("Entity {0:?} still has dependents")]
+    HasDependents(Entity),
+
+    /// A `SignalHandle` no longer refers to the signal it was made for, because the entity was
+    /// despawned or made into a different signal.
+    #[error("Handle to {0:?} is stale")]
+    StaleHandle(Entity),
+
+    /// A value of type `found` could not be used (or coerced) as the `expected` type of a signal.
+    #[error("Expected a value of type {expected} but found {found}")]
+    TypeMismatch {
+        expected: String,
+        found: String,
+    },
+
+    /// A `Computed` failed while its source `at` held the error `caused_by`.
+    #[error("Computed failed because source {at:?} failed: {caused_by}")]
+    ComputeFailed {
+        at: Entity,
+        caused_by: Box<LazySignalsError>,
+    },
+
+    /// A value read with `LazySignals.read_fresh` is older than allowed. `age` is the number of
+    /// frames since it was last updated, or `None` if it never was.
+    #[error("Signal {entity:?} is stale (last updated {age:?} frames ago)")]
+    Stale {
+        entity: Entity,
+        age: Option<u32>,
+    },
+
+    /// A `Computed` or `Effect` was given a different number of sources than its args have
+    /// elements, so its closure could never be called.
+    #[error("{sources} sources were given for args with {args} elements")]
+    ArityMismatch {
+        sources: usize,
+        args: usize,
+    },
+
+    /// A `Computed` or `Effect` listed the same source more than once. (Repeated triggers are just
+    /// dropped, since they are not passed as args.)
+    #[error("Entity {0:?} is listed more than once")]
+    DuplicateDependency(Entity),
+
+    /// A `Computed` listed itself as a source, so it could never settle.
+    #[error("A computed cannot depend on its own value")]
+    SelfReference,
+
+    /// A value sent to the signal was refused by its `WriteGuard`, the middleware, or its
+    /// `SignalValidator`.
+    #[error("Signal {0:?} refused the value")]
+    SendRefused(Entity),
+}
+
+impl LazySignalsError {
+    /// Follow a chain of `ComputeFailed` errors upstream to the original error.
+    pub fn root_cause(&self) -> &LazySignalsError {
+        match self {
+            LazySignalsError::ComputeFailed { caused_by, .. } => caused_by.root_cause(),
+            _ => self,
+        }
+    }
+
+    /// The most upstream entity in a chain of `ComputeFailed` errors, i.e. the one that originated
+    /// the failure. `None` if this error was not chained.
+    pub fn origin(&self) -> Option<Entity> {
+        match self {
+            LazySignalsError::ComputeFailed { at, caused_by } => caused_by.origin().or(Some(*at)),
+            _ => None,
+        }
+    }
+}
+
+/// What `LazySignals.despawn` does with the primitives that depend on the one being despawned.
+#[derive(Clone, Copy, PartialEq, Eq, Reflect, Debug)]
+pub enum DependentPolicy {
+    /// Despawn the dependents too, and their dependents, and so on.
+    Cascade,
+
+    /// Leave the dependents, giving each `Computed` a `LazySignalsError::SourceRemoved` error.
+    Orphan,
+
+    /// Do not despawn anything if there are dependents.
+    Error,
+}
+
+/// What to do with an `Entity` signal when the entity it holds is despawned.
+#[derive(Clone, Copy, PartialEq, Reflect, Debug)]
+pub enum LivenessPolicy {
+    /// Send `None` to the signal.
+    Clear,
+
+    /// Send `LazySignalsError::EntityDespawned` to the signal.
+    Error,
 }
 
 // ## Traits
@@ -57,6 +236,7 @@ pub trait LazySignalsData: FromReflect +
     Send +
     Sync +
     TypePath +
+    Typed +
     'static {}
 impl<T> LazySignalsData
     for T
@@ -68,11 +248,54 @@ impl<T> LazySignalsData
             Send +
             Sync +
             TypePath +
+            Typed +
             'static {}
 
-/// A tuple containing parameters for a computed memo or effect.
-pub trait LazySignalsArgs: LazySignalsData + Tuple {}
-impl<T> LazySignalsArgs for T where T: LazySignalsData + Tuple {}
+/// A tuple or struct containing parameters for a computed memo or effect.
+///
+/// Each element is the `Option<T>` of the source at the same index in the `sources` vec, i.e.
+/// `sources[0]` is passed as `args.0`, `sources[1]` as `args.1`, and so on. Triggers are not passed.
+/// Tuples of up to 12 elements are implemented here, so up to 12 sources can be used.
+///
+/// A struct with `#[derive(Reflect, LazySignalsParams)]` may be used instead of a tuple so the
+/// closure can use field names. Its fields are filled in declaration order, so the first field gets
+/// `sources[0]`, etc. Other types, such as a bare `u32`, are not `LazySignalsArgs` and do not
+/// compile:
+///
+/// ```compile_fail
+/// # use bevy::prelude::*;
+/// # use bevy_lazy_signals::api::LazySignals;
+/// # fn build(commands: &mut Commands, a: Entity) {
+/// LazySignals.computed::<u32, u32>(LazySignals::result, vec![a], commands);
+/// # }
+/// ```
+pub trait LazySignalsArgs: LazySignalsData {
+    /// Number of elements, checked against the number of sources.
+    const LEN: usize;
+
+    /// Build the args from the `DynamicTuple` with one element per source. Returns `None` if an
+    /// element is not of the type the args expect.
+    fn from_args(args: &DynamicTuple) -> Option<Self>;
+}
+
+/// Convert the element of the `DynamicTuple` at the index, for `#[derive(LazySignalsParams)]`.
+pub fn arg_at<T: FromReflect>(args: &DynamicTuple, index: usize) -> Option<T> {
+    T::from_reflect(args.field(index)?)
+}
+
+macro_rules! impl_args_tuple {
+    ($($param:ident),*) => {
+        impl<$($param: LazySignalsData),*> LazySignalsArgs for ($($param,)*) {
+            const LEN: usize = <[&str]>::len(&[$(stringify!($param)),*]);
+
+            fn from_args(args: &DynamicTuple) -> Option<Self> {
+                <Self as FromReflect>::from_reflect(args)
+            }
+        }
+    };
+}
+
+all_tuples!(impl_args_tuple, 0, 12, P);
 
 /// A propagator function aggregates (merges) data from multiple cells to store in a bound cell.
 /// Compared to the MIT model, the `Computed` pulls data into a cell they are bound to.
@@ -100,6 +323,73 @@ impl<
     T: Send + Sync + 'static + Fn(P) -> LazySignalsResult<R>
 > Computed<P, R> for T {}
 
+/// Let the developer pass in a closure that reads its sources through a `TrackingContext` instead
+/// of receiving them as args.
+pub trait TrackedComputed<R: LazySignalsData>: Send +
+    Sync +
+    'static +
+    Fn(&mut TrackingContext) -> LazySignalsResult<R> {}
+impl<
+    R: LazySignalsData,
+    T: Send + Sync + 'static + Fn(&mut TrackingContext) -> LazySignalsResult<R>
+> TrackedComputed<R> for T {}
+
+/// Read access for a `TrackedComputed` that records each `Signal` or `Computed` read during a run.
+/// The `Computed` then subscribes only to those, so a branch that is not taken does not cause it to
+/// recompute.
+pub struct TrackingContext<'a> {
+    world: &'a World,
+    sources: Vec<Entity>,
+}
+
+impl<'a> TrackingContext<'a> {
+    pub fn new(world: &'a World) -> Self {
+        Self { world, sources: Vec::new() }
+    }
+
+    /// Get the current value of a source and record the dependency.
+    pub fn get<T: LazySignalsData>(&mut self, source: Entity) -> Option<T> {
+        if !self.sources.contains(&source) {
+            self.sources.push(source);
+        }
+        self.world.get_entity(source)?.get::<LazySignalsState<T>>()?.get()
+    }
+
+    /// The sources read so far, in the order they were first read.
+    pub fn into_sources(self) -> Vec<Entity> {
+        self.sources
+    }
+}
+
+/// A tuple of `Signal` or `Computed` entities that can be read at once with
+/// `LazySignals.read_many`, returning a tuple of `Option`s of the same length. The value types
+/// are inferred from how the result is used, e.g. `let (hp, name): (Option<u32>, Option<String>) = ...`.
+pub trait ReadMany<R> {
+    fn read_many(self, world: &World) -> R;
+}
+
+macro_rules! impl_read_many {
+    ($(($entity:ident, $type:ident)),*) => {
+        impl<$($type: LazySignalsData),*> ReadMany<($(Option<$type>,)*)>
+        for ($(impl_read_many!(@entity $entity),)*) {
+            fn read_many(self, world: &World) -> ($(Option<$type>,)*) {
+                let ($($entity,)*) = self;
+                ($(crate::api::LazySignals.value::<$type>($entity, world),)*)
+            }
+        }
+    };
+    (@entity $entity:ident) => { Entity };
+}
+
+impl_read_many!((a, A));
+impl_read_many!((a, A), (b, B));
+impl_read_many!((a, A), (b, B), (c, C));
+impl_read_many!((a, A), (b, B), (c, C), (d, D));
+impl_read_many!((a, A), (b, B), (c, C), (d, D), (e, E));
+impl_read_many!((a, A), (b, B), (c, C), (d, D), (e, E), (f, F));
+impl_read_many!((a, A), (b, B), (c, C), (d, D), (e, E), (f, F), (g, G));
+impl_read_many!((a, A), (b, B), (c, C), (d, D), (e, E), (f, F), (g, G), (h, H));
+
 /// This is the same basic thing but this fn just runs side-effects so no value is returned.
 pub trait EffectWrapper: Send + Sync + FnMut(&DynamicTuple, &mut World) -> Option<BoxedSystem> {}
 impl<T: Send + Sync + FnMut(&DynamicTuple, &mut World) -> Option<BoxedSystem>> EffectWrapper
@@ -121,16 +411,25 @@ impl<T: Send + Sync + Fn(&DynamicTuple) -> Task<CommandQueue>> ActionWrapper for
 pub trait Action<P: LazySignalsArgs>: Send + Sync + 'static + Fn(P) -> Task<CommandQueue> {}
 impl<P: LazySignalsArgs, T: Send + Sync + 'static + Fn(P) -> Task<CommandQueue>> Action<P> for T {}
 
+/// A pooled effect gets no world, so it can run on the `ComputeTaskPool` alongside the others.
+/// The commands it queues are applied once every pooled effect of the pass has finished.
+pub trait PooledEffectWrapper: Send + Sync + Fn(&DynamicTuple) -> CommandQueue {}
+impl<T: Send + Sync + Fn(&DynamicTuple) -> CommandQueue> PooledEffectWrapper for T {}
+
+pub trait PooledEffect<P: LazySignalsArgs>: Send + Sync + 'static + Fn(P) -> CommandQueue {}
+impl<P: LazySignalsArgs, T: Send + Sync + 'static + Fn(P) -> CommandQueue> PooledEffect<P> for T {}
+
 pub enum EffectContext {
     Short(Mutex<Box<dyn EffectWrapper>>),
     Long(Mutex<Box<dyn ActionWrapper>>),
+    Pooled(Arc<dyn PooledEffectWrapper>),
 }
 
 /// Catch-all fn signature for `LazySignalsObservable` operations.
 pub trait ObservableFn: Send +
     Sync +
     FnMut(
-        Box<&mut dyn LazySignalsObservable>,
+        &mut dyn LazySignalsObservable,
         Option<&mut DynamicTuple>,
         Option<&Entity>
     ) -> MaybeFlaggedEntities {}
@@ -138,7 +437,7 @@ impl<
     T: Send +
         Sync +
         FnMut(
-            Box<&mut dyn LazySignalsObservable>,
+            &mut dyn LazySignalsObservable,
             Option<&mut DynamicTuple>,
             Option<&Entity>
         ) -> MaybeFlaggedEntities
@@ -163,6 +462,7 @@ pub struct ComputedImmutable {
     pub function: Mutex<Box<dyn ComputedContext>>,
     pub sources: Vec<Entity>,
     pub args_type: TypeId,
+    pub params_type: &'static TypeInfo,
     pub result_type: TypeId,
 }
 
@@ -178,6 +478,7 @@ pub struct LazyEffect {
     pub sources: Vec<Entity>,
     pub triggers: Vec<Entity>,
     pub args_type: TypeId,
+    pub params_type: &'static TypeInfo,
 }
 
 /// A DeferredEffect component marks an Effect function that needs to run.
@@ -190,12 +491,81 @@ pub struct DeferredEffect;
 #[component(storage = "SparseSet")]
 pub struct Dirty;
 
+/// Overrides `LazySignalsConfig::initial_evaluation` for a single `Computed` or `Effect`.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct InitialEvaluation(pub bool);
+
 /// Marks a `ComputedImmutable` or `LazyEffect` as needing to subscribe to its dependencies.
 /// This normally only happens within the framework internals on create.
 #[derive(Component)]
 #[component(storage = "SparseSet")]
 pub struct InitDependencies;
 
+/// A `SourceVersions` component records the version of each source a `Computed` last ran with.
+/// While they all still match, it is not run again even if it was marked to recompute, since its
+/// value would be the same.
+#[derive(Component, Default, Debug)]
+pub struct SourceVersions {
+    pub versions: Vec<(Entity, u64)>,
+}
+
+/// A `SourceArgs` component keeps the args a `Computed` or `Effect` last ran with, so the next run
+/// copies the values of its sources into them in place instead of building a new tuple.
+#[derive(Component, Default)]
+pub struct SourceArgs(pub DynamicTuple);
+
+/// A `SourceChanges` component lists the sources and triggers a `Computed` or `Effect` gained and
+/// lost when it was retargeted, so only those subscriptions are touched instead of all of them.
+#[derive(Component, Default, Debug)]
+#[component(storage = "SparseSet")]
+pub struct SourceChanges {
+    pub added: Vec<Entity>,
+    pub removed: Vec<Entity>,
+}
+
+impl SourceChanges {
+    /// Record the difference between the old and new dependencies, on top of any earlier change.
+    pub fn record(&mut self, old: &[Entity], new: &[Entity]) {
+        for source in old.iter().filter(|source| !new.contains(source)) {
+            if let Some(index) = self.added.iter().position(|added| added == source) {
+                self.added.swap_remove(index);
+            } else if !self.removed.contains(source) {
+                self.removed.push(*source);
+            }
+        }
+        for source in new.iter().filter(|source| !old.contains(source)) {
+            if let Some(index) = self.removed.iter().position(|removed| removed == source) {
+                self.removed.swap_remove(index);
+            } else if !self.added.contains(source) {
+                self.added.push(*source);
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// A `MaxDuration` component sets a time budget for each run of a `Computed` or `Effect` closure.
+/// A run that takes longer is counted in `LazySignalsStats` and sends `BudgetExceeded`.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct MaxDuration(pub Duration);
+
+/// A `Paused` component keeps a primitive from being processed, as if it were in a context whose
+/// chain never runs. Sends and subscriber marks pile up as usual and are processed once it is
+/// removed.
+#[derive(Component, Default, Debug)]
+pub struct Paused;
+
+/// A `PassiveSources` component lists sources of a `Computed` that it reads but does not subscribe
+/// to. A change to a passive source is picked up the next time another source makes the `Computed`
+/// recompute, but does not make it recompute by itself.
+#[derive(Component, Default, Debug)]
+pub struct PassiveSources {
+    pub sources: Vec<Entity>,
+}
+
 /// A `RunningTask` component marks an `Effect` function that may still be running.
 #[derive(Component)]
 #[component(storage = "SparseSet")]
@@ -203,17 +573,392 @@ pub struct RunningTask {
     pub task: Task<CommandQueue>,
 }
 
+/// An `EmitSignalChanged` component makes a `Signal` or `Computed` send a `SignalChanged` event
+/// whenever its value actually changes.
+#[derive(Component)]
+pub struct EmitSignalChanged;
+
+/// A `SignalValidator` checks each value sent to a `Signal` before it is merged. An invalid value
+/// is dropped, or replaced with the result of `clamp` if there is one, and `SignalRejected` is sent.
+#[derive(Component)]
+pub struct SignalValidator<T: LazySignalsData> {
+    pub validate: ValidateFn<T>,
+    pub clamp: Option<ClampFn<T>>,
+}
+
+/// The `SignalValidator<T>` of a signal for values only known through reflection (e.g. restored from
+/// a snapshot or received from a server), added along with it. The value is converted to `T` and
+/// validated the same way.
+#[derive(Component)]
+pub struct ReflectSignalValidator {
+    pub validate: ReflectValidateFn,
+}
+
+/// Returns the value to send or `None` if it was rejected. A value that is not a `T` is returned
+/// as is, for the merge to report.
+pub type ReflectValidateFn = fn(Entity, Box<dyn Reflect>, &mut World) -> Option<Box<dyn Reflect>>;
+
+/// Returns a message describing why the value is invalid.
+pub type ValidateFn<T> = Box<dyn Fn(&T) -> Result<(), String> + Send + Sync>;
+
+/// Turns an invalid value into the nearest valid one.
+pub type ClampFn<T> = Box<dyn Fn(T) -> T + Send + Sync>;
+
+/// A `WriteGuard` is consulted before each value sent to a `Signal`, e.g. so only the system that
+/// syncs authoritative state from a server can write it. A denied value is dropped before
+/// middleware runs and `SignalWriteDenied` is sent.
+#[derive(Component)]
+pub struct WriteGuard {
+    pub allow: WriteGuardFn,
+}
+
+/// Returns whether the signal may be written right now.
+pub type WriteGuardFn = Box<dyn Fn(&World) -> bool + Send + Sync>;
+
+/// A `SignalReducer` turns the current state of a store and a dispatched action of type `A` into the
+/// next state.
+#[derive(Component)]
+pub struct SignalReducer<S: LazySignalsData, A: Send + Sync + 'static> {
+    pub reduce: ReduceFn<S, A>,
+}
+
+pub type ReduceFn<S, A> = Box<dyn Fn(&S, A) -> S + Send + Sync>;
+
+/// A `TrackLiveness` component makes a `LazySignalsState<Entity>` watch for its entity to despawn.
+#[derive(Component)]
+pub struct TrackLiveness {
+    pub policy: LivenessPolicy,
+}
+
+/// A `TrackedSources` component marks a `Computed` whose sources are whatever its closure read
+/// through the `TrackingContext` on its last run.
+#[derive(Component, Default)]
+pub struct TrackedSources {
+    pub sources: Vec<Entity>,
+}
+
+/// A `TransientPayload` component marks a `Signal` whose value only lasts for the batch it was sent
+/// in, so each trigger delivers its payload to subscribers once and nothing is retained.
+#[derive(Component)]
+pub struct TransientPayload;
+
 /// A `Triggered` component marks a `Computed` triggers any effect anywhere down its subscriber tree.
 /// It also marks any `Effect` that has been triggered this way.
 #[derive(Component)]
 #[component(storage = "SparseSet")]
 pub struct Triggered;
 
+/// An `UnwrappedArgs` component marks a `Computed` or `Effect` whose args are the values of its
+/// sources rather than `Option`s of them, made by `LazySignals.computed_unwrapped` or
+/// `LazySignals.effect_unwrapped`.
+#[derive(Component, Default, Debug)]
+pub struct UnwrappedArgs;
+
+/// A `WaitForSources` component keeps an `Effect` from running until every one of its sources has a
+/// value, so its closure never sees a `None` arg. A change before then is skipped, not deferred.
+#[derive(Component, Default, Debug)]
+pub struct WaitForSources;
+
+/// A `WriteThrough` component makes a `Computed` write its value into a component of another entity
+/// whenever it changes, so it does not need an `Effect` to do it.
+#[derive(Component)]
+pub struct WriteThrough {
+    pub write: WriteThroughFn,
+}
+
+/// Writes the value of the `Computed` (the given entity) into the target component.
+pub type WriteThroughFn = Arc<dyn Fn(Entity, &mut World) + Send + Sync>;
+
 /// A `ValueChanged` component marks a `Signal` or `Component` that actually changed.
 #[derive(Component)]
 #[component(storage = "SparseSet")]
 pub struct ValueChanged;
 
+/// The last frame a `Signal` or `Computed` got a new value or error, for `LazySignals.read_fresh`.
+/// Added the first time it changes.
+#[derive(Component)]
+pub struct LastUpdated(pub(crate) u32);
+
+impl LastUpdated {
+    pub fn frame(&self) -> u32 {
+        self.0
+    }
+}
+
+/// ## Resources
+/// Options for the reference implementation, initialized with defaults by `LazySignalsPlugin`.
+/// Insert it before or after adding the plugin to change them.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct LazySignalsConfig {
+    /// Compute each new `Computed` and run each new `Effect` once, right after it subscribes to its
+    /// sources, instead of waiting for one of them to be sent.
+    pub initial_evaluation: bool,
+}
+
+/// Reverse index of the sources and triggers each `Computed` and `Effect` was created with (plus
+/// the sources a tracked `Computed` has read), so the primitives that depend on an entity can be
+/// found without scanning the world. Kept up to date by the systems as primitives subscribe.
+#[derive(Resource, Default)]
+pub struct LazySignalsDependents {
+    dependents: EntityHashMap<EntityHashSet>,
+}
+
+impl LazySignalsDependents {
+    /// The primitives that depend (or did depend) on the given source or trigger.
+    pub fn get(&self, source: Entity) -> impl Iterator<Item = Entity> + '_ {
+        self.dependents.get(&source).into_iter().flat_map(|dependents| dependents.iter().copied())
+    }
+
+    pub fn insert(&mut self, source: Entity, dependent: Entity) {
+        self.dependents.entry(source).or_default().insert(dependent);
+    }
+
+    pub fn remove(&mut self, source: Entity, dependent: Entity) {
+        if let Some(dependents) = self.dependents.get_mut(&source) {
+            dependents.remove(&dependent);
+            if dependents.is_empty() {
+                self.dependents.remove(&source);
+            }
+        }
+    }
+
+    /// Forget the entity as a source.
+    pub fn remove_source(&mut self, source: Entity) {
+        self.dependents.remove(&source);
+    }
+}
+
+/// The reflection accessors and type metadata of each `LazySignalsState<T>` component, looked up in
+/// the type registry the first time a state of that type is subscribed to, so subscribing thousands
+/// of primitives (e.g. on scene load) does not look them up again for every edge, and the init
+/// system can do its pass without holding the registry lock.
+#[derive(Resource, Default)]
+pub struct LazySignalsAccessors {
+    accessors: SparseSet<ComponentId, ObservableAccessor>,
+}
+
+impl LazySignalsAccessors {
+    pub fn get(&self, component_id: ComponentId) -> Option<&ObservableAccessor> {
+        self.accessors.get(component_id)
+    }
+
+    pub fn insert(&mut self, component_id: ComponentId, accessor: ObservableAccessor) {
+        self.accessors.insert(component_id, accessor);
+    }
+
+    pub fn len(&self) -> usize {
+        self.accessors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.accessors.is_empty()
+    }
+}
+
+/// What it takes to turn a pointer to a `LazySignalsState<T>` component into a
+/// `LazySignalsObservable`, plus the types a subscriber has to expect from it.
+#[derive(Clone)]
+pub struct ObservableAccessor {
+    from_ptr: ReflectFromPtr,
+    observable: ReflectLazySignalsObservable,
+    param_type: Option<(TypeId, &'static str)>,
+    data_type: Option<&'static TypeInfo>,
+}
+
+impl ObservableAccessor {
+    /// Look up the accessor for the `LazySignalsState<T>` with the given `TypeId`.
+    pub fn resolve(type_id: TypeId, type_registry: &TypeRegistry) -> Option<Self> {
+        let from_ptr = type_registry.get_type_data::<ReflectFromPtr>(type_id)?;
+        let observable = type_registry.get_type_data::<ReflectLazySignalsObservable>(type_id)?;
+        let types = type_registry.get_type_data::<ReflectLazySignalsDataType>(type_id);
+        Some(Self {
+            from_ptr: from_ptr.clone(),
+            observable: observable.clone(),
+            param_type: types.map(|types| types.param_type),
+            data_type: types.map(|types| types.data_type),
+        })
+    }
+
+    /// The `TypeId` and path of the `Option<T>` a subscriber gets from the state.
+    pub fn param_type(&self) -> Option<(TypeId, &'static str)> {
+        self.param_type
+    }
+
+    /// The `TypeInfo` of the `T` the state holds.
+    pub fn data_type(&self) -> Option<&'static TypeInfo> {
+        self.data_type
+    }
+
+    /// The component as a `LazySignalsObservable`, without marking it changed.
+    ///
+    /// # Safety
+    /// The component must be of the type the accessor was resolved for.
+    pub unsafe fn observable<'a>(
+        &self,
+        mut_untyped: &'a mut MutUntyped
+    ) -> Option<&'a mut dyn LazySignalsObservable> {
+        let ptr_mut = mut_untyped.bypass_change_detection().reborrow();
+        let value = self.from_ptr.as_reflect_mut(ptr_mut);
+        self.observable.get_mut(value)
+    }
+}
+
+/// Collections the propagation systems reuse from one pass to the next, so they only allocate when
+/// the graph grows. `LazySignalsStrictModePlugin` sizes them up front.
+#[derive(Resource, Default)]
+pub struct LazySignalsBuffers {
+    pub changed: EntitySet,
+    pub component_ids: ComponentIdSet,
+    pub component_infos: ComponentInfoSet,
+    pub next_running: EntitySet,
+    pub processed: EntitySet,
+    pub running: EntitySet,
+    pub triggered: EntitySet,
+    pub dirty: Vec<Entity>,
+    pub stack: Vec<Entity>,
+    pub subscribers: Vec<Entity>,
+    /// The sources of each node being processed, as a range of `related`.
+    pub relationships: EntityRangeSet,
+    pub related: Vec<Entity>,
+    reserved: (usize, usize),
+}
+
+impl LazySignalsBuffers {
+    /// Make room for entities with indices below `entities` and every component registered in
+    /// `components`, if there is not room already.
+    pub fn reserve(&mut self, entities: usize, components: &Components) {
+        if entities > self.reserved.0 {
+            for set in [
+                &mut self.changed,
+                &mut self.next_running,
+                &mut self.processed,
+                &mut self.running,
+                &mut self.triggered,
+            ] {
+                *set = presized(entities, Entity::from_raw, ());
+            }
+            self.component_ids = presized(entities, Entity::from_raw, ComponentId::new(0));
+            self.relationships = presized(entities, Entity::from_raw, 0..0);
+            self.dirty.reserve(entities);
+            self.stack.reserve(entities);
+            self.subscribers.reserve(entities);
+            self.reserved.0 = entities;
+        }
+        if components.len() > self.reserved.1 {
+            if let Some(last) = components.iter().last() {
+                let key = |index| ComponentId::new(index as usize);
+                self.component_infos = presized(components.len(), key, last.clone());
+            }
+            self.reserved.1 = components.len();
+        }
+    }
+
+    /// The number of entities and components there is room for.
+    pub fn reserved(&self) -> (usize, usize) {
+        self.reserved
+    }
+
+    fn clear(&mut self) {
+        self.changed.clear();
+        self.component_ids.clear();
+        self.component_infos.clear();
+        self.next_running.clear();
+        self.processed.clear();
+        self.running.clear();
+        self.triggered.clear();
+        self.dirty.clear();
+        self.stack.clear();
+        self.subscribers.clear();
+        self.relationships.clear();
+        self.related.clear();
+    }
+
+    /// Record the sources of a node being processed, as the next range of `related`.
+    pub fn relate(&mut self, entity: Entity, sources: impl IntoIterator<Item = Entity>) {
+        let start = self.related.len();
+        self.related.extend(sources);
+        self.relationships.insert(entity, start..self.related.len());
+    }
+}
+
+// a sparse set only grows its index when a key with a higher index is inserted, and clearing it
+// keeps the room, so insert the highest key once
+fn presized<K: SparseSetIndex, V>(len: usize, key: fn(u32) -> K, value: V) -> SparseSet<K, V> {
+    let mut set = SparseSet::with_capacity(len);
+    if let Some(last) = len.checked_sub(1) {
+        set.insert(key(last as u32), value);
+        set.clear();
+    }
+    set
+}
+
+/// Take the `LazySignalsBuffers` out of the world for a pass, leaving empty ones behind.
+pub fn take_buffers(world: &mut World) -> LazySignalsBuffers {
+    world
+        .get_resource_mut::<LazySignalsBuffers>()
+        .map(|mut buffers| std::mem::take(&mut *buffers))
+        .unwrap_or_default()
+}
+
+/// Put the `LazySignalsBuffers` back, cleared, once a pass is done with them.
+pub fn return_buffers(mut buffers: LazySignalsBuffers, world: &mut World) {
+    buffers.clear();
+    if let Some(mut slot) = world.get_resource_mut::<LazySignalsBuffers>() {
+        *slot = buffers;
+    }
+}
+
+/// ## Events
+/// Sent when the value of a `Signal` or `Computed` marked with `EmitSignalChanged` changes.
+/// The `type_id` is that of its `LazySignalsState<T>` component.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct SignalChanged {
+    pub entity: Entity,
+    pub type_id: TypeId,
+}
+
+/// Sent when a `Computed` or `Effect` closure takes longer than its `MaxDuration`.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct BudgetExceeded {
+    pub entity: Entity,
+    pub duration: Duration,
+    pub max_duration: Duration,
+}
+
+/// Sent when a `SignalValidator` finds an invalid value. If `clamped` is true, the clamped value
+/// was sent instead; otherwise the signal was left unchanged.
+#[derive(Event, Clone, Debug)]
+pub struct SignalRejected {
+    pub entity: Entity,
+    pub message: String,
+    pub clamped: bool,
+}
+
+/// Sent when the `WriteGuard` of a signal denies a value sent to it. The signal is left unchanged.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct SignalWriteDenied {
+    pub entity: Entity,
+}
+
+/// Sent when a command refuses to create (or retarget) a `Computed` or `Effect` because of how it
+/// was put together. A node that was being created is despawned.
+#[derive(Event, Clone, Debug)]
+pub struct SignalNodeRejected {
+    pub entity: Entity,
+    pub error: LazySignalsError,
+}
+
+/// Sent when a source of a `Computed` or `Effect` holds a different type than the matching element
+/// of its args, which would otherwise just pass `None` to the closure forever.
+#[derive(Event, Clone, Debug)]
+pub struct SourceTypeMismatch {
+    pub subscriber: Entity,
+    pub source: Entity,
+    pub index: usize,
+    pub expected: &'static str,
+    pub found: &'static str,
+}
+
 /// ## Utilities
 /// Set of `Entity` to `ComponentId`.
 pub type ComponentIdSet = SparseSet<Entity, ComponentId>;
@@ -224,12 +969,23 @@ pub type ComponentInfoSet = SparseSet<ComponentId, ComponentInfo>;
 /// Set of `Entity` to child `Entity`.
 pub type EntityRelationshipSet = SparseSet<Entity, Vec<Entity>>;
 
+/// Set of `Entity` to a range of a list of child `Entity` (see `LazySignalsBuffers::relate`).
+pub type EntityRangeSet = SparseSet<Entity, Range<usize>>;
+
 /// Unique `Entity` set.
 pub type EntitySet = SparseSet<Entity, ()>;
 
 /// Set of internal errors when running computed and effect functions.
 pub type ErrorSet = SparseSet<Entity, LazySignalsError>;
 
+/// Describe an entity for logging, using its `Name` if it has one.
+pub fn entity_label(entity: Entity, world: &World) -> String {
+    match world.get_entity(entity).and_then(|entity| entity.get::<Name>()) {
+        Some(name) => format!("{} ({:?})", name, entity),
+        None => format!("{:?}", entity),
+    }
+}
+
 /// Create an empty sparse set for storing `Entity` by ID.
 pub fn empty_set() -> EntitySet {
     EntitySet::new()
@@ -0,0 +1,184 @@
+use bevy::prelude::*;
+
+use crate::{
+    api::LazySignals,
+    framework::*,
+    lazy_immutable::{ LazySignalsImmutable, LazySignalsState },
+};
+
+/// Upper bound on the transitions taken in one pass, in case the guards form a cycle.
+const MAX_TRANSITIONS_PER_PASS: usize = 16;
+
+/// A transition from `from` (or any substate of it) to `to`, taken when the `bool` signal or
+/// computed `guard` is `true`.
+pub struct FsmTransition<S: LazySignalsData> {
+    pub from: S,
+    pub to: S,
+    pub guard: Entity,
+}
+
+/// ## Finite State Machines
+/// Declares a hierarchical state machine to be created with `LazySignals.fsm`. The current state is
+/// a `Signal` of `S`, and the machine is driven by an `Effect` triggered by the transition guards.
+///
+/// When a transition is taken, the exit effects of the current state and its ancestors up to (but not
+/// including) the closest state it shares with the target are triggered, along with the enter
+/// effects from there down to the target. They run during the next pass. Enter effects of the
+/// initial state do not run.
+pub struct FsmBuilder<S: LazySignalsData> {
+    initial: S,
+    parents: Vec<(S, S)>,
+    transitions: Vec<FsmTransition<S>>,
+    on_enter: Vec<(S, Entity)>,
+    on_exit: Vec<(S, Entity)>,
+}
+
+impl<S: LazySignalsData> FsmBuilder<S> {
+    pub fn new(initial: S) -> Self {
+        Self {
+            initial,
+            parents: Vec::new(),
+            transitions: Vec::new(),
+            on_enter: Vec::new(),
+            on_exit: Vec::new(),
+        }
+    }
+
+    /// Run an `Effect` or `Action` each time the state is entered.
+    pub fn on_enter(mut self, state: S, effect: Entity) -> Self {
+        self.on_enter.push((state, effect));
+        self
+    }
+
+    /// Run an `Effect` or `Action` each time the state is exited.
+    pub fn on_exit(mut self, state: S, effect: Entity) -> Self {
+        self.on_exit.push((state, effect));
+        self
+    }
+
+    /// Nest a state inside a parent, so the transitions of the parent also apply to it.
+    pub fn substate(mut self, state: S, parent: S) -> Self {
+        self.parents.push((state, parent));
+        self
+    }
+
+    /// Go from `from` to `to` when the `bool` guard is `true`. Transitions of a substate are
+    /// checked before those of its parent, otherwise in the order they were added.
+    pub fn transition(mut self, from: S, to: S, guard: Entity) -> Self {
+        self.transitions.push(FsmTransition { from, to, guard });
+        self
+    }
+
+    pub(crate) fn build(self, commands: &mut Commands) -> Entity {
+        let guards: Vec<Entity> = self.transitions
+            .iter()
+            .map(|transition| transition.guard)
+            .collect();
+
+        let current = LazySignals.state(self.initial, commands);
+        commands.entity(current).insert(LazySignalsFsm::<S> {
+            parents: self.parents,
+            transitions: self.transitions,
+            on_enter: self.on_enter,
+            on_exit: self.on_exit,
+        });
+
+        // guards are triggers so they do not have to line up with the args
+        LazySignals.effect::<()>(
+            move |_, world| {
+                step_fsm::<S>(current, world);
+                None
+            },
+            vec![],
+            guards,
+            commands
+        );
+        current
+    }
+}
+
+/// The declaration of a state machine, on the entity that holds its current state.
+#[derive(Component)]
+pub struct LazySignalsFsm<S: LazySignalsData> {
+    parents: Vec<(S, S)>,
+    transitions: Vec<FsmTransition<S>>,
+    on_enter: Vec<(S, Entity)>,
+    on_exit: Vec<(S, Entity)>,
+}
+
+impl<S: LazySignalsData> LazySignalsFsm<S> {
+    /// The state followed by each of its ancestors, innermost first.
+    pub fn path<'a>(&'a self, state: &'a S) -> Vec<&'a S> {
+        let mut state = state;
+        let mut path = vec![state];
+        while let Some((_, parent)) = self.parents.iter().find(|(child, _)| child == state) {
+            if path.contains(&parent) {
+                break;
+            }
+            path.push(parent);
+            state = parent;
+        }
+        path
+    }
+
+    /// Whether `state` is `ancestor` or nested somewhere inside it.
+    pub fn is_in(&self, state: &S, ancestor: &S) -> bool {
+        self.path(state).contains(&ancestor)
+    }
+
+    // first transition whose guard is true, checking the innermost state first
+    fn next_transition(&self, current: &S, world: &World) -> Option<&FsmTransition<S>> {
+        self.path(current)
+            .into_iter()
+            .flat_map(|state| self.transitions.iter().filter(move |t| t.from == *state))
+            .find(|transition| {
+                transition.to != *current &&
+                    world
+                        .get::<LazySignalsState<bool>>(transition.guard)
+                        .and_then(|guard| guard.get())
+                        .unwrap_or(false)
+            })
+    }
+
+    // exit effects from the current state up, then enter effects down to the target
+    fn transition_effects(&self, from: &S, to: &S) -> Vec<Entity> {
+        let exits = self.path(from);
+        let enters = self.path(to);
+        let mut effects = Vec::new();
+        for state in exits.iter().filter(|state| !enters.contains(state)) {
+            effects.extend(self.on_exit.iter().filter(|(s, _)| s == *state).map(|(_, e)| *e));
+        }
+        for state in enters.iter().rev().filter(|state| !exits.contains(state)) {
+            effects.extend(self.on_enter.iter().filter(|(s, _)| s == *state).map(|(_, e)| *e));
+        }
+        effects
+    }
+}
+
+// take transitions until no guard of the current state is true
+fn step_fsm<S: LazySignalsData>(fsm: Entity, world: &mut World) {
+    for _ in 0..MAX_TRANSITIONS_PER_PASS {
+        let Some(current) = LazySignals.value::<S>(fsm, world) else {
+            return;
+        };
+        let Some(machine) = world.get::<LazySignalsFsm<S>>(fsm) else {
+            return;
+        };
+        let Some(transition) = machine.next_transition(&current, world) else {
+            return;
+        };
+        let effects = machine.transition_effects(&current, &transition.to);
+        let Some(next) = S::from_reflect(&transition.to) else {
+            return;
+        };
+
+        trace!("fsm {} transition to {:?}", entity_label(fsm, world), next.as_reflect());
+        LazySignals.send_now(fsm, next, world);
+        for effect in effects {
+            if let Some(mut effect) = world.get_entity_mut(effect) {
+                effect.insert((DeferredEffect, Triggered));
+            }
+        }
+    }
+    warn!("fsm {} did not settle, check its guards for a cycle", entity_label(fsm, world));
+}
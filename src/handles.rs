@@ -0,0 +1,303 @@
+use std::{ marker::PhantomData, sync::atomic::{ AtomicU64, Ordering } };
+
+use bevy::{ ecs::world::Command, prelude::* };
+
+use crate::{ api::LazySignals, commands::SendSignalCommand, framework::* };
+
+/// ## Typed handles
+/// Lightweight typed handles to a `LazySignals` entity, so the type only has to be given once, when
+/// the primitive is created. Each handle is `Copy` and can be turned back into the `Entity` to use as
+/// a source or trigger.
+///
+/// Read and write capability for a `Signal`, which can be narrowed to either one.
+pub struct RwSignal<T: LazySignalsData> {
+    entity: Entity,
+    data_type: PhantomData<fn() -> T>,
+}
+
+/// Read capability for a `Signal` (or `Computed`).
+pub struct ReadSignal<T: LazySignalsData> {
+    entity: Entity,
+    data_type: PhantomData<fn() -> T>,
+}
+
+/// Write capability for a `Signal`.
+pub struct WriteSignal<T: LazySignalsData> {
+    entity: Entity,
+    data_type: PhantomData<fn() -> T>,
+}
+
+impl<T: LazySignalsData> RwSignal<T> {
+    /// Wrap an existing entity. The caller is responsible for getting `T` right.
+    pub fn new(entity: Entity) -> Self {
+        Self { entity, data_type: PhantomData }
+    }
+
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    /// Get the current value.
+    pub fn read(&self, world: &World) -> Option<T> {
+        self.read_only().read(world)
+    }
+
+    /// Send a signal to be applied during the next batch.
+    pub fn send(&self, data: T, commands: &mut Commands) {
+        self.write_only().send(data, commands);
+    }
+
+    /// Send a signal to be applied during the next batch regardless of whether the data changed.
+    pub fn send_and_trigger(&self, data: T, commands: &mut Commands) {
+        self.write_only().send_and_trigger(data, commands);
+    }
+
+    /// Send a signal from an exclusive system and merge it right away.
+    pub fn send_now(&self, data: T, world: &mut World) {
+        self.write_only().send_now(data, world);
+    }
+
+    /// Give up write capability.
+    pub fn read_only(&self) -> ReadSignal<T> {
+        ReadSignal::new(self.entity)
+    }
+
+    /// Give up read capability.
+    pub fn write_only(&self) -> WriteSignal<T> {
+        WriteSignal::new(self.entity)
+    }
+
+    /// Split into separate read and write handles.
+    pub fn split(&self) -> (ReadSignal<T>, WriteSignal<T>) {
+        (self.read_only(), self.write_only())
+    }
+}
+
+impl<T: LazySignalsData> ReadSignal<T> {
+    /// Wrap an existing entity. The caller is responsible for getting `T` right.
+    pub fn new(entity: Entity) -> Self {
+        Self { entity, data_type: PhantomData }
+    }
+
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    /// Get the current error, if any.
+    pub fn error(&self, world: &World) -> Option<LazySignalsError> {
+        LazySignals.get_error::<T>(self.entity, world)
+    }
+
+    /// Make a `Stream` that yields each new value.
+    #[cfg(feature = "async")]
+    pub fn into_stream(
+        &self,
+        commands: &mut Commands
+    ) -> impl bevy::tasks::futures_lite::Stream<Item = T> {
+        LazySignals.into_stream::<T>(self.entity, commands)
+    }
+
+    /// Get the current value.
+    pub fn read(&self, world: &World) -> Option<T> {
+        LazySignals.read::<T>(self.entity, world)
+    }
+
+    /// Create an `Effect` that runs the closure with the new value whenever it changes.
+    pub fn subscribe(
+        &self,
+        mut closure: impl FnMut(Option<T>, &mut World) + Send + Sync + 'static,
+        commands: &mut Commands
+    ) -> Entity {
+        LazySignals.effect::<(Option<T>,)>(
+            move |args, world| {
+                closure(args.0, world);
+                None
+            },
+            vec![self.entity],
+            Vec::<Entity>::new(),
+            commands
+        )
+    }
+}
+
+impl<T: LazySignalsData> WriteSignal<T> {
+    /// Wrap an existing entity. The caller is responsible for getting `T` right.
+    pub fn new(entity: Entity) -> Self {
+        Self { entity, data_type: PhantomData }
+    }
+
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    /// Send a signal to be applied during the next batch.
+    pub fn send(&self, data: T, commands: &mut Commands) {
+        LazySignals.send::<T>(self.entity, data, commands);
+    }
+
+    /// Send a signal to be applied during the next batch regardless of whether the data changed.
+    pub fn send_and_trigger(&self, data: T, commands: &mut Commands) {
+        LazySignals.send_and_trigger::<T>(self.entity, data, commands);
+    }
+
+    /// Send a signal from an exclusive system and merge it right away.
+    pub fn send_now(&self, data: T, world: &mut World) {
+        LazySignals.send_now::<T>(self.entity, data, world);
+    }
+}
+
+// derive would require `T: Clone`, which the handles don't need
+impl<T: LazySignalsData> Clone for RwSignal<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: LazySignalsData> Copy for RwSignal<T> {}
+
+impl<T: LazySignalsData> Clone for ReadSignal<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: LazySignalsData> Copy for ReadSignal<T> {}
+
+impl<T: LazySignalsData> Clone for WriteSignal<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: LazySignalsData> Copy for WriteSignal<T> {}
+
+impl<T: LazySignalsData> From<RwSignal<T>> for ReadSignal<T> {
+    fn from(signal: RwSignal<T>) -> Self {
+        signal.read_only()
+    }
+}
+
+impl<T: LazySignalsData> From<RwSignal<T>> for WriteSignal<T> {
+    fn from(signal: RwSignal<T>) -> Self {
+        signal.write_only()
+    }
+}
+
+impl<T: LazySignalsData> From<RwSignal<T>> for Entity {
+    fn from(signal: RwSignal<T>) -> Self {
+        signal.entity
+    }
+}
+
+impl<T: LazySignalsData> From<ReadSignal<T>> for Entity {
+    fn from(signal: ReadSignal<T>) -> Self {
+        signal.entity
+    }
+}
+
+impl<T: LazySignalsData> From<WriteSignal<T>> for Entity {
+    fn from(signal: WriteSignal<T>) -> Self {
+        signal.entity
+    }
+}
+
+static NEXT_GENERATION: AtomicU64 = AtomicU64::new(1);
+
+/// A `LazySignals` generation, unique for the life of the app, given to a signal the first time a
+/// `SignalHandle` is made for it. Unlike the generation of an `Entity`, it also changes when the
+/// entity is reused for a different signal.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct SignalGeneration(u64);
+
+impl SignalGeneration {
+    fn next() -> Self {
+        Self(NEXT_GENERATION.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// Read and write handle to a `Signal` that carries its `SignalGeneration`, which is checked on
+/// every operation, so a handle kept after the signal was despawned returns
+/// `LazySignalsError::StaleHandle` (and logs an error) instead of using whatever now has the
+/// entity.
+pub struct SignalHandle<T: LazySignalsData> {
+    entity: Entity,
+    generation: SignalGeneration,
+    data_type: PhantomData<fn() -> T>,
+}
+
+impl<T: LazySignalsData> SignalHandle<T> {
+    /// Create a `Signal` state and a handle to it.
+    pub fn create(data: T, commands: &mut Commands) -> Self {
+        let entity = LazySignals.state(data, commands);
+        let generation = SignalGeneration::next();
+        commands.entity(entity).insert(generation);
+        Self { entity, generation, data_type: PhantomData }
+    }
+
+    /// Make a handle to an existing signal, giving it a generation if it does not have one yet.
+    pub fn from_entity(entity: Entity, world: &mut World) -> Result<Self, LazySignalsError> {
+        let mut entity_mut = world
+            .get_entity_mut(entity)
+            .filter(|entity| entity.contains::<ImmutableState>())
+            .ok_or(LazySignalsError::NoSignalError)?;
+        let generation = match entity_mut.get::<SignalGeneration>() {
+            Some(generation) => *generation,
+            None => {
+                let generation = SignalGeneration::next();
+                entity_mut.insert(generation);
+                generation
+            }
+        };
+        Ok(Self { entity, generation, data_type: PhantomData })
+    }
+
+    /// The entity, if the handle is still valid.
+    pub fn entity(&self, world: &World) -> Result<Entity, LazySignalsError> {
+        if world.get::<SignalGeneration>(self.entity) == Some(&self.generation) {
+            return Ok(self.entity);
+        }
+        error!("stale handle to {:?} ({:?})", self.entity, self.generation);
+        Err(LazySignalsError::StaleHandle(self.entity))
+    }
+
+    /// The entity without checking the generation, e.g. to use as a source or trigger.
+    pub fn entity_unchecked(&self) -> Entity {
+        self.entity
+    }
+
+    /// Get the current error, if any.
+    pub fn error(&self, world: &World) -> Result<Option<LazySignalsError>, LazySignalsError> {
+        self.entity(world).map(|entity| LazySignals.get_error::<T>(entity, world))
+    }
+
+    /// Get the current value.
+    pub fn read(&self, world: &World) -> Result<Option<T>, LazySignalsError> {
+        self.entity(world).map(|entity| LazySignals.read::<T>(entity, world))
+    }
+
+    /// Send a signal to be applied during the next batch. The generation is checked when the
+    /// command is applied, and a stale send is logged and dropped.
+    pub fn send(&self, data: T, commands: &mut Commands) {
+        let handle = *self;
+        commands.add(move |world: &mut World| {
+            if let Ok(signal) = handle.entity(world) {
+                SendSignalCommand { signal, data }.apply(world);
+            }
+        });
+    }
+
+    /// Send a signal from an exclusive system and merge it right away.
+    pub fn send_now(&self, data: T, world: &mut World) -> Result<(), LazySignalsError> {
+        let entity = self.entity(world)?;
+        LazySignals.send_now::<T>(entity, data, world);
+        Ok(())
+    }
+}
+
+impl<T: LazySignalsData> Clone for SignalHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: LazySignalsData> Copy for SignalHandle<T> {}
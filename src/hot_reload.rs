@@ -0,0 +1,239 @@
+use std::{ any::TypeId, borrow::Cow, marker::PhantomData, sync::{ Arc, Mutex } };
+
+use bevy::{ ecs::world::Command, prelude::*, utils::HashMap };
+
+use crate::{
+    api::{ make_computed_with, make_effect_with },
+    commands::{ CreateComputedCommand, CreateEffectCommand },
+    despawn::dependents_of,
+    framework::*,
+    lazy_immutable::LazySignalsState,
+};
+
+/// ## Hot Reload
+/// A `PropagatorName` component names the registered fn a `Computed` or `Effect` was made from, so
+/// registering that name again (e.g. after a hot reload of the code that defines it) swaps the new
+/// fn into the existing entity.
+#[derive(Component, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct PropagatorName(pub Cow<'static, str>);
+
+type MakeComputed = Arc<dyn Fn() -> Mutex<Box<dyn ComputedContext>> + Send + Sync>;
+type MakeEffect = Arc<dyn Fn() -> Mutex<Box<dyn EffectWrapper>> + Send + Sync>;
+
+enum Propagator {
+    Computed {
+        make: MakeComputed,
+        args_type: TypeId,
+        result_type: TypeId,
+    },
+    Effect {
+        make: MakeEffect,
+        args_type: TypeId,
+    },
+}
+
+/// The fns registered by stable name with `LazySignals.register_computed` and
+/// `LazySignals.register_effect`.
+#[derive(Resource, Default)]
+pub struct LazySignalsPropagators {
+    functions: HashMap<Cow<'static, str>, Propagator>,
+}
+
+impl LazySignalsPropagators {
+    pub fn contains(&self, name: &str) -> bool {
+        self.functions.contains_key(name)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.functions.keys().map(|name| name.as_ref())
+    }
+
+    fn get(&self, name: &str) -> Option<&Propagator> {
+        self.functions.get(name)
+    }
+}
+
+/// Command to register (or replace) a named `Computed` fn and swap it into every entity made from
+/// that name. The entities keep their sources, subscribers, and value, and recompute right away.
+pub struct RegisterComputedCommand<P: LazySignalsArgs, R: LazySignalsData> {
+    pub name: Cow<'static, str>,
+    pub function: Arc<dyn Fn(P) -> LazySignalsResult<R> + Send + Sync>,
+}
+
+impl<P: LazySignalsArgs, R: LazySignalsData> Command for RegisterComputedCommand<P, R> {
+    fn apply(self, world: &mut World) {
+        let function = self.function;
+        let make: MakeComputed = Arc::new(move || {
+            let function = Arc::clone(&function);
+            make_computed_with::<P, R>(move |args| function(args))
+        });
+        let args_type = TypeId::of::<P>();
+        let result_type = TypeId::of::<LazySignalsState<R>>();
+
+        let mut reattached = 0;
+        for entity in named(&self.name, world) {
+            let mut entity = world.entity_mut(entity);
+            let Some(mut computed) = entity.get_mut::<ComputedImmutable>() else {
+                continue;
+            };
+            if computed.args_type != args_type || computed.result_type != result_type {
+                error!("{} changed its params or result type, not reattaching it", self.name);
+                continue;
+            }
+            computed.function = make();
+            entity.remove::<SourceVersions>();
+            entity.insert((ComputeMemo, Dirty));
+            mark_downstream(entity.id(), world);
+            reattached += 1;
+        }
+        if reattached > 0 {
+            info!("reattached {} to {} computeds", self.name, reattached);
+        }
+
+        world
+            .get_resource_or_insert_with(LazySignalsPropagators::default)
+            .functions.insert(self.name, Propagator::Computed { make, args_type, result_type });
+    }
+}
+
+/// Command to register (or replace) a named `Effect` fn and swap it into every entity made from
+/// that name. The entities keep their sources and triggers, and run the new fn the next time one
+/// of them changes.
+pub struct RegisterEffectCommand<P: LazySignalsArgs, F: Effect<P> + Clone> {
+    pub name: Cow<'static, str>,
+    pub function: F,
+    pub args_type: PhantomData<P>,
+}
+
+impl<P: LazySignalsArgs, F: Effect<P> + Clone> Command for RegisterEffectCommand<P, F> {
+    fn apply(self, world: &mut World) {
+        let function = self.function;
+        let make: MakeEffect = Arc::new(move || make_effect_with::<P>(function.clone()));
+        let args_type = TypeId::of::<P>();
+
+        let mut reattached = 0;
+        for entity in named(&self.name, world) {
+            let Some(mut effect) = world.get_mut::<LazyEffect>(entity) else {
+                continue;
+            };
+            if effect.args_type != args_type {
+                error!("{} changed its params type, not reattaching it", self.name);
+                continue;
+            }
+            effect.function = EffectContext::Short(make());
+            reattached += 1;
+        }
+        if reattached > 0 {
+            info!("reattached {} to {} effects", self.name, reattached);
+        }
+
+        world
+            .get_resource_or_insert_with(LazySignalsPropagators::default)
+            .functions.insert(self.name, Propagator::Effect { make, args_type });
+    }
+}
+
+/// Command to create a `Computed` from a fn registered by name.
+pub struct CreateNamedComputedCommand<P: LazySignalsArgs, R: LazySignalsData> {
+    pub computed: Entity,
+    pub name: Cow<'static, str>,
+    pub sources: Vec<Entity>,
+    pub args_type: PhantomData<P>,
+    pub result_type: PhantomData<R>,
+}
+
+impl<P: LazySignalsArgs, R: LazySignalsData> Command for CreateNamedComputedCommand<P, R> {
+    fn apply(self, world: &mut World) {
+        let propagators = world.get_resource::<LazySignalsPropagators>();
+        let result = TypeId::of::<LazySignalsState<R>>();
+        let function = match propagators.and_then(|propagators| propagators.get(&self.name)) {
+            Some(Propagator::Computed { make, args_type, result_type }) => {
+                if *args_type != TypeId::of::<P>() || *result_type != result {
+                    error!("{} was registered with other params or result types", self.name);
+                    return;
+                }
+                make()
+            }
+            _ => {
+                error!("no computed is registered as {}", self.name);
+                return;
+            }
+        };
+        CreateComputedCommand::<P, R> {
+            computed: self.computed,
+            function,
+            sources: self.sources,
+            args_type: PhantomData,
+            result_type: PhantomData,
+        }.apply(world);
+        world.entity_mut(self.computed).insert(PropagatorName(self.name));
+    }
+}
+
+/// Command to create an `Effect` from a fn registered by name.
+pub struct CreateNamedEffectCommand<P: LazySignalsArgs> {
+    pub effect: Entity,
+    pub name: Cow<'static, str>,
+    pub sources: Vec<Entity>,
+    pub triggers: Vec<Entity>,
+    pub args_type: PhantomData<P>,
+}
+
+impl<P: LazySignalsArgs> Command for CreateNamedEffectCommand<P> {
+    fn apply(self, world: &mut World) {
+        let propagators = world.get_resource::<LazySignalsPropagators>();
+        let function = match propagators.and_then(|propagators| propagators.get(&self.name)) {
+            Some(Propagator::Effect { make, args_type }) => {
+                if *args_type != TypeId::of::<P>() {
+                    error!("{} was registered with other params types", self.name);
+                    return;
+                }
+                make()
+            }
+            _ => {
+                error!("no effect is registered as {}", self.name);
+                return;
+            }
+        };
+        CreateEffectCommand::<P> {
+            effect: self.effect,
+            function,
+            sources: self.sources,
+            triggers: self.triggers,
+            args_type: PhantomData,
+        }.apply(world);
+        world.entity_mut(self.effect).insert(PropagatorName(self.name));
+    }
+}
+
+// mark the computeds downstream of a reattached or retargeted computed to recompute, and the
+// effects to run if the value changes
+pub(crate) fn mark_downstream(computed: Entity, world: &mut World) {
+    let mut stack = vec![computed];
+    let mut visited = vec![computed];
+    while let Some(source) = stack.pop() {
+        for dependent in dependents_of(source, world) {
+            if visited.contains(&dependent) {
+                continue;
+            }
+            visited.push(dependent);
+            let mut entity = world.entity_mut(dependent);
+            if entity.contains::<ComputedImmutable>() {
+                entity.insert((ComputeMemo, Dirty));
+                stack.push(dependent);
+            } else {
+                entity.insert(DeferredEffect);
+            }
+        }
+    }
+}
+
+// every entity made from the named fn
+fn named(name: &str, world: &mut World) -> Vec<Entity> {
+    let mut query = world.query::<(Entity, &PropagatorName)>();
+    query
+        .iter(world)
+        .filter(|(_, propagator)| propagator.0 == name)
+        .map(|(entity, _)| entity)
+        .collect()
+}
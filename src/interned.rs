@@ -0,0 +1,89 @@
+use std::{ cell::RefCell, fmt::{ self, Write }, sync::{ OnceLock, RwLock } };
+
+use bevy::{ prelude::*, utils::HashMap };
+
+/// ## Interned Strings
+/// A string for high-churn text signals, such as an FPS counter or a timer. Each distinct string is
+/// stored once for the life of the app and a `SignalStr` is just its id, so it is `Copy`, compares
+/// by id, and sending one allocates nothing once its text has been seen.
+///
+/// Interned text is never freed, so this suits signals that cycle through a bounded set of values
+/// (`"0"` to `"999"`, `"12:59"`), not ones that hold unbounded user input. The id is only
+/// meaningful within the running app, so save the text rather than the `SignalStr`.
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[reflect(PartialEq, Hash, Debug, Default)]
+pub struct SignalStr(u32);
+
+#[derive(Default)]
+struct Interner {
+    ids: HashMap<&'static str, u32>,
+    strings: Vec<&'static str>,
+}
+
+fn interner() -> &'static RwLock<Interner> {
+    static INTERNER: OnceLock<RwLock<Interner>> = OnceLock::new();
+    INTERNER.get_or_init(|| {
+        // id 0 is the empty string, so the default value needs no lookup
+        RwLock::new(Interner { ids: HashMap::from([("", 0)]), strings: vec![""] })
+    })
+}
+
+thread_local! {
+    // reused by `SignalStr::format` so formatting text that was seen before allocates nothing
+    static FORMAT_BUFFER: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+impl SignalStr {
+    /// Intern the text, copying it only the first time it is seen.
+    pub fn new(text: &str) -> Self {
+        if let Some(id) = interner().read().unwrap().ids.get(text) {
+            return Self(*id);
+        }
+        let mut interner = interner().write().unwrap();
+        if let Some(id) = interner.ids.get(text) {
+            return Self(*id);
+        }
+        let text: &'static str = Box::leak(text.into());
+        let id = interner.strings.len() as u32;
+        interner.strings.push(text);
+        interner.ids.insert(text, id);
+        Self(id)
+    }
+
+    /// Format the text into a reused buffer and intern it, e.g.
+    /// `SignalStr::format(format_args!("{} FPS", fps))`.
+    pub fn format(args: fmt::Arguments) -> Self {
+        FORMAT_BUFFER.with_borrow_mut(|buffer| {
+            buffer.clear();
+            let _ = buffer.write_fmt(args);
+            Self::new(buffer)
+        })
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        interner().read().unwrap().strings.get(self.0 as usize).copied().unwrap_or_default()
+    }
+
+    /// The number of distinct strings interned so far, to keep an eye on growth.
+    pub fn interned_count() -> usize {
+        interner().read().unwrap().strings.len()
+    }
+}
+
+impl From<&str> for SignalStr {
+    fn from(text: &str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl fmt::Display for SignalStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for SignalStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
@@ -0,0 +1,94 @@
+use bevy::prelude::*;
+
+use crate::{ arcane_wizardry::with_observable, fixed::{ Fixed32, Fixed64 }, framework::* };
+
+/// ## Interpolation
+/// The value a `Signal` or `Computed` marked with `LazySignals.interpolated` had at the end of the
+/// last fixed timestep, so a render-rate reader can blend it with the current one.
+#[derive(Component, Default)]
+pub struct InterpolatedSignal {
+    previous: Option<Box<dyn Reflect>>,
+}
+
+impl InterpolatedSignal {
+    /// The value as of the last fixed timestep, if there was one, as the given type.
+    pub fn previous<T: LazySignalsData>(&self) -> Option<T> {
+        self.previous.as_deref().and_then(T::from_reflect)
+    }
+}
+
+/// A data type that can be blended for `LazySignals.read_interpolated`.
+pub trait Interpolate: Sized {
+    /// Blend from `self` (at `t = 0`) to `other` (at `t = 1`).
+    fn interpolate(&self, other: &Self, t: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Interpolate for f64 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * (t as f64)
+    }
+}
+
+impl Interpolate for Vec2 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self.lerp(*other, t)
+    }
+}
+
+impl Interpolate for Vec3 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self.lerp(*other, t)
+    }
+}
+
+impl Interpolate for Vec4 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self.lerp(*other, t)
+    }
+}
+
+impl Interpolate for Quat {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self.slerp(*other, t)
+    }
+}
+
+impl Interpolate for Fixed32 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        *self + (*other - *self) * Fixed32::from_f64(t as f64)
+    }
+}
+
+impl Interpolate for Fixed64 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        *self + (*other - *self) * Fixed64::from_f64(t as f64)
+    }
+}
+
+#[cfg(feature = "common_types")]
+impl Interpolate for Color {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        use bevy::color::Mix;
+        self.mix(other, t)
+    }
+}
+
+// remember the values of interpolated signals before the next fixed timestep changes them
+pub fn snapshot_interpolated(
+    world: &mut World,
+    query_interpolated: &mut QueryState<Entity, With<InterpolatedSignal>>
+) {
+    let signals: Vec<Entity> = query_interpolated.iter(world).collect();
+    for signal in signals {
+        let previous = with_observable(signal, world, |observable, _| observable.data_reflect());
+        if let Some(mut interpolated) = world.get_mut::<InterpolatedSignal>(signal) {
+            interpolated.previous = previous.flatten();
+        }
+    }
+}
@@ -0,0 +1,320 @@
+use bevy::{ ecs::entity::{ EntityHashMap, EntityHashSet }, prelude::* };
+
+use crate::{ arcane_wizardry::data_type_info, framework::* };
+
+/// What a `LazySignals` entity is.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Reflect, Debug)]
+pub enum SignalKind {
+    Signal,
+    Computed,
+    Effect,
+    Action,
+}
+
+/// ## Introspection
+/// One live `LazySignals` entity, as listed by `LazySignals.enumerate`.
+///
+/// For a `Signal` or `Computed`, `type_path` is the type of its value. For an `Effect` or `Action`,
+/// it is the type of its params tuple.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SignalInfo {
+    pub entity: Entity,
+    pub name: Option<String>,
+    pub type_path: &'static str,
+    pub kind: SignalKind,
+    pub meta: Option<LazySignalsMeta>,
+}
+
+/// List every `Signal`, `Computed`, `Effect`, and `Action` in the world, sorted by type path so the
+/// entities of each type are next to each other, then by kind and entity.
+pub fn enumerate_signals(world: &World) -> Vec<SignalInfo> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+
+    let mut signals: Vec<SignalInfo> = world
+        .iter_entities()
+        .filter_map(|entity| {
+            let (kind, type_path) = if let Some(effect) = entity.get::<LazyEffect>() {
+                let kind = match effect.function {
+                    EffectContext::Short(_) | EffectContext::Pooled(_) => SignalKind::Effect,
+                    EffectContext::Long(_) => SignalKind::Action,
+                };
+                (kind, effect.params_type.type_path())
+            } else if entity.contains::<ImmutableState>() {
+                let kind = match entity.contains::<ComputedImmutable>() {
+                    true => SignalKind::Computed,
+                    false => SignalKind::Signal,
+                };
+                let type_path = data_type_info(entity.id(), world, &type_registry)
+                    .map_or("unknown", |info| info.type_path());
+                (kind, type_path)
+            } else {
+                return None;
+            };
+
+            Some(SignalInfo {
+                entity: entity.id(),
+                name: entity.get::<Name>().map(|name| name.to_string()),
+                type_path,
+                kind,
+                meta: entity.get::<LazySignalsMeta>().cloned(),
+            })
+        })
+        .collect();
+
+    signals.sort_by(|a, b| {
+        (a.type_path, a.kind, a.entity).cmp(&(b.type_path, b.kind, b.entity))
+    });
+    signals
+}
+
+/// A `LazySignalsMeta` component describes a `LazySignals` entity for tooling, so the nodes of a
+/// large graph can be attributed to the feature and module that own them. It is listed by
+/// `LazySignals.enumerate` and sent by the devtools, and its `tags` select the entities of a
+/// feature for `LazySignals.pause_tagged` and the other `_tagged` operations.
+#[derive(Component, Reflect, Clone, PartialEq, Eq, Default, Debug)]
+#[reflect(Component)]
+pub struct LazySignalsMeta {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+    pub owner_module: Option<&'static str>,
+}
+
+impl LazySignalsMeta {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: Some(name.into()), ..default() }
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Set the module that owns the entity, usually `module_path!()`.
+    pub fn owned_by(mut self, owner_module: &'static str) -> Self {
+        self.owner_module = Some(owner_module);
+        self
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+}
+
+/// Every entity with a `LazySignalsMeta` that has the tag.
+pub fn tagged(tag: &str, world: &World) -> Vec<Entity> {
+    world
+        .iter_entities()
+        .filter(|entity| entity.get::<LazySignalsMeta>().is_some_and(|meta| meta.has_tag(tag)))
+        .map(|entity| entity.id())
+        .collect()
+}
+
+/// Shape of the dependency graph as found by `LazySignals.analyze`, e.g. to enforce architectural
+/// limits in integration tests (`assert!(report.max_depth <= 6)`).
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct GraphReport {
+    /// Number of `Signal`, `Computed`, `Effect`, and `Action` entities.
+    pub nodes: usize,
+
+    /// Number of source and trigger edges between them.
+    pub edges: usize,
+
+    /// Length of the longest chain of subscribers, counting a `Signal` as depth 0.
+    pub max_depth: usize,
+
+    /// The entity at the end of the longest chain.
+    pub deepest: Option<Entity>,
+
+    /// Largest number of computeds and effects that depend directly on the same entity.
+    pub max_fan_out: usize,
+
+    /// The entity with the largest fan-out.
+    pub widest: Option<Entity>,
+
+    /// Each group of entities that depend on each other (strongly-connected components).
+    pub cycles: Vec<Vec<Entity>>,
+
+    /// Computeds and effects that no `Signal` can reach, so they will never run again.
+    pub unreachable: Vec<Entity>,
+}
+
+impl GraphReport {
+    pub fn is_acyclic(&self) -> bool {
+        self.cycles.is_empty()
+    }
+}
+
+/// Analyze the graph formed by the sources and triggers of every `Computed` and `Effect`.
+pub fn analyze_graph(world: &World) -> GraphReport {
+    let mut nodes = Vec::<Entity>::new();
+    let mut roots = Vec::<Entity>::new();
+    let mut sources = EntityHashMap::<Vec<Entity>>::default();
+    for entity in world.iter_entities() {
+        let computed = entity.get::<ComputedImmutable>();
+        let effect = entity.get::<LazyEffect>();
+        if effect.is_none() && !entity.contains::<ImmutableState>() {
+            continue;
+        }
+        nodes.push(entity.id());
+        if computed.is_none() && effect.is_none() {
+            roots.push(entity.id());
+        }
+
+        let mut entity_sources = Vec::new();
+        if let Some(tracked) = entity.get::<TrackedSources>() {
+            entity_sources.extend(tracked.sources.iter());
+        } else if let Some(computed) = computed {
+            entity_sources.extend(computed.sources.iter());
+        }
+        if let Some(effect) = effect {
+            entity_sources.extend(effect.sources.iter().chain(effect.triggers.iter()));
+        }
+        sources.insert(entity.id(), entity_sources);
+    }
+    nodes.sort();
+
+    // turn the edges around to follow changes downstream
+    let mut dependents = EntityHashMap::<Vec<Entity>>::default();
+    let mut edges = 0;
+    for entity in nodes.iter() {
+        for source in sources[entity].iter().filter(|source| sources.contains_key(*source)) {
+            dependents.entry(*source).or_default().push(*entity);
+            edges += 1;
+        }
+    }
+
+    let mut report = GraphReport { nodes: nodes.len(), edges, ..default() };
+
+    for entity in nodes.iter() {
+        let fan_out = dependents.get(entity).map_or(0, |dependents| dependents.len());
+        if fan_out > report.max_fan_out {
+            report.max_fan_out = fan_out;
+            report.widest = Some(*entity);
+        }
+    }
+
+    let mut depths = EntityHashMap::<usize>::default();
+    for entity in nodes.iter() {
+        let depth = depth_of(*entity, &sources, &mut depths, &mut EntityHashSet::default());
+        if depth > report.max_depth || report.deepest.is_none() {
+            report.max_depth = depth;
+            report.deepest = Some(*entity);
+        }
+    }
+
+    report.cycles = strongly_connected(&nodes, &dependents)
+        .into_iter()
+        .filter(|component| {
+            component.len() > 1 ||
+                dependents.get(&component[0]).is_some_and(|deps| deps.contains(&component[0]))
+        })
+        .collect();
+
+    // everything a signal can reach may still run
+    let mut reached = EntityHashSet::from_iter(roots.iter().copied());
+    let mut queue = roots;
+    while let Some(entity) = queue.pop() {
+        for dependent in dependents.get(&entity).into_iter().flatten() {
+            if reached.insert(*dependent) {
+                queue.push(*dependent);
+            }
+        }
+    }
+    report.unreachable = nodes
+        .into_iter()
+        .filter(|entity| !reached.contains(entity))
+        .collect();
+
+    report
+}
+
+// longest chain of sources above the entity, not following a cycle back around
+fn depth_of(
+    entity: Entity,
+    sources: &EntityHashMap<Vec<Entity>>,
+    depths: &mut EntityHashMap<usize>,
+    visiting: &mut EntityHashSet
+) -> usize {
+    if let Some(depth) = depths.get(&entity) {
+        return *depth;
+    }
+    if !visiting.insert(entity) {
+        return 0;
+    }
+    let depth = sources[&entity]
+        .iter()
+        .filter(|source| sources.contains_key(*source))
+        .map(|source| depth_of(*source, sources, depths, visiting) + 1)
+        .max()
+        .unwrap_or_default();
+    visiting.remove(&entity);
+    depths.insert(entity, depth);
+    depth
+}
+
+// Tarjan's algorithm, with an explicit stack so a long chain can't overflow the real one
+fn strongly_connected(
+    nodes: &[Entity],
+    dependents: &EntityHashMap<Vec<Entity>>
+) -> Vec<Vec<Entity>> {
+    let mut index = EntityHashMap::<usize>::default();
+    let mut low = EntityHashMap::<usize>::default();
+    let mut on_stack = EntityHashSet::default();
+    let mut stack = Vec::<Entity>::new();
+    let mut components = Vec::<Vec<Entity>>::new();
+
+    for root in nodes {
+        if index.contains_key(root) {
+            continue;
+        }
+
+        // each entry is a node and the position of the next dependent to visit
+        let mut work = vec![(*root, 0)];
+        while let Some((node, child)) = work.pop() {
+            if child == 0 {
+                let next = index.len();
+                index.insert(node, next);
+                low.insert(node, next);
+                stack.push(node);
+                on_stack.insert(node);
+            }
+
+            let children = dependents.get(&node).map_or(&[][..], |children| children.as_slice());
+            if let Some(next_node) = children.get(child) {
+                work.push((node, child + 1));
+                if !index.contains_key(next_node) {
+                    work.push((*next_node, 0));
+                } else if on_stack.contains(next_node) {
+                    let lowest = low[&node].min(index[next_node]);
+                    low.insert(node, lowest);
+                }
+                continue;
+            }
+
+            // every dependent has been visited, so the node may be the root of a component
+            if low[&node] == index[&node] {
+                let mut component = Vec::new();
+                while let Some(member) = stack.pop() {
+                    on_stack.remove(&member);
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                component.sort();
+                components.push(component);
+            }
+            if let Some((parent, _)) = work.last() {
+                let lowest = low[parent].min(low[&node]);
+                low.insert(*parent, lowest);
+            }
+        }
+    }
+    components
+}
@@ -0,0 +1,173 @@
+use std::sync::atomic::{ AtomicU32, Ordering };
+
+use bevy::{ core::FrameCount, ecs::entity::EntityHashSet, prelude::* };
+
+use crate::{ arcane_wizardry::with_observable_in, framework::*, stats::LazySignalsStats };
+
+/// Why an entity was reported by leak detection.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LeakKind {
+    /// Every source and trigger of a `Computed` or `Effect` has been despawned, so it can never
+    /// run again.
+    Orphaned,
+
+    /// A `Signal` has no live subscribers and has not been read for this many frames.
+    Unread(u32),
+}
+
+/// ## Leak Detection
+/// Options for `LazySignalsLeakDetectionPlugin`, which periodically looks for primitives that can
+/// no longer do anything useful and reports them as warnings and in `LazySignalsStats::leaks`.
+/// Nothing is despawned automatically.
+#[derive(Resource, Clone, Debug)]
+pub struct LazySignalsLeakDetection {
+    /// Frames between checks.
+    pub interval: u32,
+
+    /// A `Signal` with no subscribers is reported after it has not been read for this many frames.
+    pub idle_frames: u32,
+
+    reported: EntityHashSet,
+}
+
+impl Default for LazySignalsLeakDetection {
+    fn default() -> Self {
+        Self::new(300, 3600)
+    }
+}
+
+impl LazySignalsLeakDetection {
+    pub fn new(interval: u32, idle_frames: u32) -> Self {
+        Self { interval, idle_frames, reported: EntityHashSet::default() }
+    }
+}
+
+/// The last frame a `Signal` was read with `LazySignals.value` (or `get`/`read`), added to signals
+/// by leak detection. Atomic since reads only borrow the `World`.
+#[derive(Component)]
+pub struct LastRead(AtomicU32);
+
+impl LastRead {
+    pub fn frame(&self) -> u32 {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn touch(&self, world: &World) {
+        if let Some(frame_count) = world.get_resource::<FrameCount>() {
+            self.0.store(frame_count.0, Ordering::Relaxed);
+        }
+    }
+}
+
+/// `Plugin` to add leak detection. Meant for development builds, since it makes each read of a
+/// `Signal` a little more expensive.
+pub struct LazySignalsLeakDetectionPlugin;
+
+impl Plugin for LazySignalsLeakDetectionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LazySignalsLeakDetection>().add_systems(
+            PreUpdate,
+            detect_leaks.after(crate::LazySignalsSystemSet)
+        );
+    }
+}
+
+type SignalFilter = (With<ImmutableState>, Without<ComputedImmutable>);
+
+// every so often, report orphaned computeds and effects and signals nobody reads anymore
+pub fn detect_leaks(
+    world: &mut World,
+    query_computeds: &mut QueryState<(Entity, &ComputedImmutable)>,
+    query_effects: &mut QueryState<(Entity, &LazyEffect)>,
+    query_signals: &mut QueryState<(Entity, Option<&LastRead>), SignalFilter>
+) {
+    let frame = world.get_resource::<FrameCount>().map_or(0, |frame_count| frame_count.0);
+    let Some(config) = world.get_resource::<LazySignalsLeakDetection>() else {
+        return;
+    };
+    if config.interval == 0 || frame % config.interval != 0 {
+        return;
+    }
+    let idle_frames = config.idle_frames;
+
+    let is_orphaned = |dependencies: &mut dyn Iterator<Item = &Entity>, world: &World| {
+        let mut dependencies = dependencies.peekable();
+        dependencies.peek().is_some() &&
+            dependencies.all(|entity| world.get_entity(*entity).is_none())
+    };
+
+    let mut leaks: Vec<(Entity, LeakKind)> = Vec::new();
+    for (entity, computed) in query_computeds.iter(world) {
+        if is_orphaned(&mut computed.sources.iter(), world) {
+            leaks.push((entity, LeakKind::Orphaned));
+        }
+    }
+    for (entity, effect) in query_effects.iter(world) {
+        if is_orphaned(&mut effect.sources.iter().chain(effect.triggers.iter()), world) {
+            leaks.push((entity, LeakKind::Orphaned));
+        }
+    }
+
+    // start tracking reads of signals seen for the first time
+    let mut untracked = Vec::new();
+    let mut idle = Vec::new();
+    for (entity, last_read) in query_signals.iter(world) {
+        match last_read {
+            Some(last_read) => {
+                let frames = frame.wrapping_sub(last_read.frame());
+                if frames >= idle_frames {
+                    idle.push((entity, frames));
+                }
+            }
+            None => untracked.push(entity),
+        }
+    }
+    for entity in untracked {
+        world.entity_mut(entity).insert(LastRead(AtomicU32::new(frame)));
+    }
+
+    world.resource_scope(|world, type_registry: Mut<AppTypeRegistry>| {
+        let type_registry = type_registry.read();
+        for (entity, frames) in idle {
+            let subscribers = with_observable_in(entity, world, &type_registry, |observable, _| {
+                observable.get_subscribers()
+            }).unwrap_or_default();
+            if subscribers.iter().all(|subscriber| world.get_entity(*subscriber).is_none()) {
+                leaks.push((entity, LeakKind::Unread(frames)));
+            }
+        }
+    });
+
+    // only warn about each entity once, but keep the full list in the stats
+    let mut config = world.resource_mut::<LazySignalsLeakDetection>();
+    let reported = std::mem::take(&mut config.reported);
+    let new_leaks: Vec<(Entity, LeakKind)> = leaks
+        .iter()
+        .filter(|(entity, _)| !reported.contains(entity))
+        .copied()
+        .collect();
+    config.reported = leaks
+        .iter()
+        .map(|(entity, _)| *entity)
+        .collect();
+
+    for (entity, kind) in new_leaks {
+        match kind {
+            LeakKind::Orphaned =>
+                warn!(
+                    "{} can never run again, all of its sources are gone",
+                    entity_label(entity, world)
+                ),
+            LeakKind::Unread(frames) =>
+                warn!(
+                    "{} has no subscribers and has not been read for {} frames",
+                    entity_label(entity, world),
+                    frames
+                ),
+        }
+    }
+
+    if let Some(mut stats) = world.get_resource_mut::<LazySignalsStats>() {
+        stats.set_leaks(leaks);
+    }
+}
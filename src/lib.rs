@@ -1,25 +1,150 @@
-use bevy::{ ecs::schedule::SystemConfigs, prelude::* };
+use std::marker::PhantomData;
+
+use bevy::{ ecs::schedule::{ ScheduleLabel, SystemConfigs }, prelude::* };
 
 mod arcane_wizardry;
 
+mod macros;
+
 pub mod api;
 
+pub mod backpressure;
+use backpressure::release_backpressure;
+
+pub mod buffer;
+use buffer::expire_time_buffers;
+
+pub mod clock;
+
+pub mod clock_sync;
+
 pub mod commands;
 
+pub mod context;
+use context::ActiveSignalContext;
+
+pub mod cooldown;
+use cooldown::tick_cooldowns;
+
+pub mod dead_letters;
+
+pub mod despawn;
+
+pub mod external;
+
+pub mod family;
+use family::{ evict_family_members, prune_memo_keys, LazySignalsMemoKeys };
+
+pub mod fixed;
+
 pub mod framework;
 use framework::*;
 use lazy_immutable::*;
 
+pub mod fsm;
+
+pub mod handles;
+
+pub mod hot_reload;
+
+pub mod interned;
+use interned::SignalStr;
+
+pub mod interpolation;
+use interpolation::snapshot_interpolated;
+
+pub mod introspection;
+use introspection::LazySignalsMeta;
+
+pub mod leaks;
+
+pub mod middleware;
+use middleware::{ LazySignalsMiddleware, MiddlewareDecision };
+
+pub mod mirror;
+use mirror::{ collect_mirrored, MirroredSignals };
+
+pub mod namespace;
+use namespace::LazySignalsNamespace;
+
+pub mod optimistic;
+use optimistic::settle_optimistic_updates;
+
+pub mod processing;
+
+pub mod retry;
+use retry::{ poll_resolves, ResolveStatus };
+
+pub mod sequence;
+use sequence::advance_sequences;
+
+pub mod spatial;
+use spatial::update_proximity;
+
+pub mod stats;
+use stats::{ reset_stats, LazySignalsStats };
+
+pub mod strict;
+
 pub mod systems;
 use systems::{
     computed::compute_memos,
     init::init_lazy_signals,
+    liveness::check_liveness,
+    payload::clear_payloads,
     signal::send_signals,
     effect::{ apply_deferred_effects, check_tasks },
 };
 
+#[cfg(feature = "assets")]
+pub mod assets;
+
+#[cfg(feature = "devtools")]
+pub mod devtools;
+
+#[cfg(feature = "net")]
+pub mod fetch;
+
+#[cfg(feature = "localization")]
+pub mod localization;
+
+#[cfg(feature = "overlay")]
+pub mod overlay;
+
+#[cfg(feature = "replication")]
+pub mod replication;
+
+#[cfg(feature = "replicon")]
+pub mod replicon;
+
+#[cfg(feature = "savegame")]
+pub mod savegame;
+
+#[cfg(feature = "async")]
+pub mod stream;
+
+pub mod testing;
+
+pub mod validation;
+
+pub mod writer;
+use writer::{ drain_send_queue, LazySignalsSendQueue };
+
 pub mod prelude {
-    pub use crate::{ api::*, framework::*, systems::*, LazySignalsPlugin };
+    pub use crate::{
+        api::*,
+        family::*,
+        framework::*,
+        handles::*,
+        systems::*,
+        writer::SignalWriter,
+        LazySignalsAppExt,
+        LazySignalsPlugin,
+        SignalTypes,
+    };
+
+    #[cfg(feature = "assets")]
+    pub use crate::assets::LazySignalsAssetsExt;
 }
 
 /// Convenience typedefs.
@@ -38,14 +163,52 @@ pub type LazySignalsUnit = LazySignalsState<()>; // for triggers, mostly
 #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct LazySignalsSystemSet;
 
+/// System set of the timers and queues that send signals (cooldowns, backpressure, resolves, and
+/// so on), run just before `LazySignalsSystemSet` in `PreUpdate` and in each `LazySignalsFlush` pass.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LazySignalsPrePassSet;
+
+/// Schedule that `LazySignals.flush` runs repeatedly until nothing is left to process.
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LazySignalsFlush;
+
 /// Convenience functions to make it easy to run the `LazySignals` systems when needed.
 pub fn lazy_signals_full_systems() -> SystemConfigs {
-    (check_tasks, init_lazy_signals, send_signals, compute_memos, apply_deferred_effects).chain()
+    (
+        check_tasks,
+        check_liveness,
+        init_lazy_signals,
+        send_signals,
+        compute_memos,
+        apply_deferred_effects,
+        advance_sequences,
+        clear_payloads,
+    ).chain()
+}
+
+/// The systems that decide what gets sent before the `LazySignals` systems process it, in order.
+pub fn lazy_signals_pre_pass_systems() -> SystemConfigs {
+    (
+        // despawn family members that are no longer being used before the next batch
+        evict_family_members,
+        // forget keyed memos that were despawned, so their keys make new ones
+        prune_memo_keys,
+        // end cooldowns whose time is up so the signal is sent during this batch
+        tick_cooldowns,
+        // let held values of signals with a backpressure policy through
+        release_backpressure,
+        // keep confirmed optimistic values and roll back the ones that timed out
+        settle_optimistic_updates,
+        // send resolved values and start the retries that are due
+        poll_resolves,
+        // drop values that have aged out of time buffers
+        expire_time_buffers,
+    ).chain()
 }
 
 /// This chain omits the effects sending system to allow the developer to trigger it a lot if needed.
 pub fn lazy_signals_flush_systems() -> SystemConfigs {
-    (check_tasks, init_lazy_signals, send_signals, compute_memos).chain()
+    (check_tasks, check_liveness, init_lazy_signals, send_signals, compute_memos).chain()
 }
 
 /// `Plugin` to initialize the resource and system schedule.
@@ -53,7 +216,7 @@ pub struct LazySignalsPlugin;
 
 impl Plugin for LazySignalsPlugin {
     fn build(&self, app: &mut App) {
-        // NOTE: the user application will need to register each custom `LazyImmutable<T>` for reflection
+        // NOTE: the user application will need to register each custom `T` with `register_signal_type`
 
         // add the systems to process signals, memos, and effects
         app.add_systems(
@@ -69,11 +232,191 @@ impl Plugin for LazySignalsPlugin {
             // Last, call `apply_deferred_effects()` at the end so they only fire once per tick
             lazy_signals_full_systems().in_set(LazySignalsSystemSet)
         )
+            // the same chain, run on demand by `LazySignals.flush`
+            .add_systems(LazySignalsFlush, lazy_signals_full_systems().in_set(LazySignalsSystemSet))
+            // timers and queues send what is due first, in both schedules, so they also advance
+            // while the graph is being flushed
+            .configure_sets(PreUpdate, LazySignalsPrePassSet.before(LazySignalsSystemSet))
+            .configure_sets(LazySignalsFlush, LazySignalsPrePassSet.before(LazySignalsSystemSet))
+            .add_systems(PreUpdate, lazy_signals_pre_pass_systems().in_set(LazySignalsPrePassSet))
+            .add_systems(
+                LazySignalsFlush,
+                lazy_signals_pre_pass_systems().in_set(LazySignalsPrePassSet)
+            )
+            .init_resource::<LazySignalsMemoKeys>()
+            // per-frame counters start over once per frame (not per flush) before the next batch
+            .init_resource::<LazySignalsStats>()
+            .add_systems(PreUpdate, reset_stats.before(LazySignalsPrePassSet))
+            // remember the values of interpolated signals before each fixed timestep
+            .add_systems(FixedFirst, snapshot_interpolated)
+            // transforms are propagated in `PostUpdate`, so changes are sent during the next batch
+            .add_systems(
+                PostUpdate,
+                update_proximity.after(TransformSystem::TransformPropagate)
+            )
+            // copy the values of `Mirrored` signals once they are settled for this tick
+            .init_resource::<MirroredSignals>()
+            .add_systems(PreUpdate, collect_mirrored.after(LazySignalsSystemSet))
+            .init_resource::<ActiveSignalContext>()
+            .init_resource::<LazySignalsAccessors>()
+            .init_resource::<LazySignalsBuffers>()
+            .init_resource::<LazySignalsConfig>()
+            .init_resource::<LazySignalsDependents>()
+            .init_resource::<LazySignalsMiddleware>()
+            .init_resource::<LazySignalsNamespace>()
+            .register_type::<LazySignalsMeta>()
+            .add_event::<BudgetExceeded>()
+            .add_event::<SignalChanged>()
+            .add_event::<SignalNodeRejected>()
+            .add_event::<SignalRejected>()
+            .add_event::<SignalWriteDenied>()
+            .add_event::<SourceTypeMismatch>()
             // custom Immutable types must be manually registered
-            .register_type::<LazySignalsBool>()
-            .register_type::<LazySignalsInt>()
-            .register_type::<LazySignalsFloat>()
-            .register_type::<LazySignalsStr>()
-            .register_type::<LazySignalsUnit>();
+            .register_signal_type::<bool>()
+            .register_signal_type::<u32>()
+            .register_signal_type::<f64>()
+            .register_signal_type::<StaticStrRef>()
+            .register_signal_type::<String>()
+            .register_signal_type::<SignalStr>()
+            .register_signal_type::<()>()
+            .register_signal_type::<Entity>()
+            .register_signal_type::<ResolveStatus>();
+
+        #[cfg(feature = "common_types")]
+        register_common_types(app);
+
+        // send the items that arrived from streams since the last frame
+        #[cfg(feature = "async")]
+        app.add_systems(PreUpdate, stream::drain_streams.in_set(LazySignalsPrePassSet)).add_systems(
+            LazySignalsFlush,
+            stream::drain_streams.in_set(LazySignalsPrePassSet)
+        );
+
+        #[cfg(feature = "camera")]
+        app.register_signal_type::<Option<Vec2>>().add_systems(
+            PostUpdate,
+            spatial::update_screen_positions.after(TransformSystem::TransformPropagate)
+        );
+    }
+}
+
+impl LazySignalsPlugin {
+    /// `LazySignalsPlugin` that also registers each type in the tuple as a signal type, e.g.
+    /// `LazySignalsPlugin::with_types::<(MyEnum, MyStruct, Vec2)>()` (use `(MyEnum,)` for one).
+    pub fn with_types<T: SignalTypes>() -> LazySignalsPluginWithTypes<T> {
+        LazySignalsPluginWithTypes(PhantomData)
+    }
+}
+
+/// `Plugin` made by `LazySignalsPlugin::with_types`. Add it instead of `LazySignalsPlugin`.
+pub struct LazySignalsPluginWithTypes<T: SignalTypes>(PhantomData<fn() -> T>);
+
+impl<T: SignalTypes> Plugin for LazySignalsPluginWithTypes<T> {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(LazySignalsPlugin).register_signal_types::<T>();
+    }
+}
+
+/// A tuple of signal data types to register at once, see `LazySignalsPlugin::with_types`.
+pub trait SignalTypes: 'static {
+    fn register(app: &mut App);
+}
+
+macro_rules! impl_signal_types {
+    ($($type:ident),*) => {
+        impl<$($type: LazySignalsData),*> SignalTypes for ($($type,)*) {
+            fn register(app: &mut App) {
+                $(app.register_signal_type::<$type>();)*
+            }
+        }
+    };
+}
+
+impl_signal_types!(A);
+impl_signal_types!(A, B);
+impl_signal_types!(A, B, C);
+impl_signal_types!(A, B, C, D);
+impl_signal_types!(A, B, C, D, E);
+impl_signal_types!(A, B, C, D, E, F);
+impl_signal_types!(A, B, C, D, E, F, G);
+impl_signal_types!(A, B, C, D, E, F, G, H);
+impl_signal_types!(A, B, C, D, E, F, G, H, I);
+impl_signal_types!(A, B, C, D, E, F, G, H, I, J);
+impl_signal_types!(A, B, C, D, E, F, G, H, I, J, K);
+impl_signal_types!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+/// Register the `LazySignalsState<T>` for the types most projects end up sending as signals.
+#[cfg(feature = "common_types")]
+fn register_common_types(app: &mut App) {
+    use std::time::Duration;
+
+    use fixed::{ Fixed32, Fixed64 };
+
+    app.register_signal_type::<f32>()
+        .register_signal_type::<u64>()
+        .register_signal_type::<Vec2>()
+        .register_signal_type::<Vec3>()
+        .register_signal_type::<Quat>()
+        .register_signal_type::<Color>()
+        .register_signal_type::<Duration>()
+        .register_signal_type::<Fixed32>()
+        .register_signal_type::<Fixed64>();
+}
+
+/// Convenience extension to register everything `LazySignals` needs to reflect a data type.
+pub trait LazySignalsAppExt {
+    /// Register `LazySignalsState<T>` plus the `Option<T>` and `(Option<T>,)` param types for `T`.
+    fn register_signal_type<T: LazySignalsData>(&mut self) -> &mut Self;
+
+    /// Call `register_signal_type` for each type in a tuple, e.g. `(MyEnum, MyStruct, Vec2)`.
+    fn register_signal_types<T: SignalTypes>(&mut self) -> &mut Self;
+
+    /// Register a `Copy` signal type along with the queue behind `SignalWriter<T>`.
+    fn register_signal_writer<T: LazySignalsData + Copy>(&mut self) -> &mut Self;
+
+    /// Add a closure to the end of the `LazySignalsMiddleware` pipeline that every send runs through.
+    fn add_send_middleware(
+        &mut self,
+        middleware: impl Fn(Entity, &mut dyn Reflect, &mut World) -> MiddlewareDecision +
+            Send +
+            Sync +
+            'static
+    ) -> &mut Self;
+}
+
+impl LazySignalsAppExt for App {
+    fn add_send_middleware(
+        &mut self,
+        middleware: impl Fn(Entity, &mut dyn Reflect, &mut World) -> MiddlewareDecision +
+            Send +
+            Sync +
+            'static
+    ) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_insert_with(LazySignalsMiddleware::default)
+            .push(middleware);
+        self
+    }
+
+    fn register_signal_type<T: LazySignalsData>(&mut self) -> &mut Self {
+        self.register_type::<LazySignalsState<T>>()
+            .register_type::<LazySignalsResult<T>>()
+            .register_type::<Option<T>>()
+            .register_type::<(Option<T>,)>()
+    }
+
+    fn register_signal_types<T: SignalTypes>(&mut self) -> &mut Self {
+        T::register(self);
+        self
+    }
+
+    fn register_signal_writer<T: LazySignalsData + Copy>(&mut self) -> &mut Self {
+        if self.world().contains_resource::<LazySignalsSendQueue<T>>() {
+            return self;
+        }
+        self.register_signal_type::<T>()
+            .init_resource::<LazySignalsSendQueue<T>>()
+            .add_systems(PreUpdate, drain_send_queue::<T>.in_set(LazySignalsPrePassSet))
+            .add_systems(LazySignalsFlush, drain_send_queue::<T>.in_set(LazySignalsPrePassSet))
     }
 }
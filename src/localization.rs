@@ -0,0 +1,136 @@
+use bevy::{
+    asset::{ io::Reader, AssetLoader, AsyncReadExt, LoadContext },
+    prelude::*,
+    utils::HashMap,
+};
+use thiserror::Error;
+
+use crate::{ framework::*, LazySignalsSystemSet };
+
+/// ## Localization
+/// A table of translated strings loaded from a `.l10n.csv` file. The header row is `key` followed
+/// by one column per locale (e.g. `key,en-US,fr-FR`), and each following row holds the text of one
+/// key in each locale.
+#[derive(Asset, TypePath, Default, Debug)]
+pub struct Localization {
+    locales: Vec<String>,
+    entries: HashMap<String, Vec<String>>,
+}
+
+impl Localization {
+    /// Parse a table in the same format as a `.l10n.csv` file.
+    pub fn from_csv(csv: &[u8]) -> Result<Self, LocalizationLoaderError> {
+        let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(csv);
+        let locales: Vec<String> = reader
+            .headers()?
+            .iter()
+            .skip(1)
+            .map(|locale| locale.trim().to_string())
+            .collect();
+
+        let mut entries = HashMap::new();
+        for record in reader.records() {
+            let record = record?;
+            let Some(key) = record.get(0) else {
+                continue;
+            };
+            entries.insert(key.trim().to_string(), record.iter().skip(1).map(String::from).collect());
+        }
+        Ok(Self { locales, entries })
+    }
+
+    /// The locales in the order of the columns, so the first one is the fallback.
+    pub fn locales(&self) -> &[String] {
+        &self.locales
+    }
+
+    /// The text of `key` in `locale`, if there is a non-empty translation.
+    pub fn get(&self, key: &str, locale: &str) -> Option<&str> {
+        let column = self.locales.iter().position(|l| l == locale)?;
+        self.entries
+            .get(key)
+            .and_then(|texts| texts.get(column))
+            .map(String::as_str)
+            .filter(|text| !text.is_empty())
+    }
+
+    /// The text of `key` in `locale`, falling back to the first locale and then to the key itself.
+    pub fn localize(&self, key: &str, locale: &str) -> String {
+        self.get(key, locale)
+            .or_else(|| self.locales.first().and_then(|fallback| self.get(key, fallback)))
+            .unwrap_or(key)
+            .to_string()
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum LocalizationLoaderError {
+    #[error("Could not read localization table: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Could not parse localization table: {0}")]
+    Csv(#[from] csv::Error),
+}
+
+#[derive(Default)]
+pub struct LocalizationLoader;
+
+impl AssetLoader for LocalizationLoader {
+    type Asset = Localization;
+    type Settings = ();
+    type Error = LocalizationLoaderError;
+
+    async fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader<'_>,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext<'_>
+    ) -> Result<Localization, LocalizationLoaderError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Localization::from_csv(&bytes)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["l10n.csv"]
+    }
+}
+
+/// On the `Effect` made by `LazySignals.localize`, so it runs again when its table is reloaded.
+#[derive(Component)]
+pub struct LocalizedBy(pub Handle<Localization>);
+
+/// `Plugin` to add the `Localization` asset and its loader. Requires the `AssetPlugin`.
+pub struct LazySignalsLocalizationPlugin;
+
+impl Plugin for LazySignalsLocalizationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<Localization>()
+            .init_asset_loader::<LocalizationLoader>()
+            .add_systems(PreUpdate, refresh_localized.before(LazySignalsSystemSet));
+    }
+}
+
+// run the localize effects again for each table that was added, loaded, or hot-reloaded
+fn refresh_localized(
+    mut asset_events: EventReader<AssetEvent<Localization>>,
+    query_localized: Query<(Entity, &LocalizedBy)>,
+    mut commands: Commands
+) {
+    for event in asset_events.read() {
+        let id = match event {
+            AssetEvent::Added { id } |
+            AssetEvent::LoadedWithDependencies { id } |
+            AssetEvent::Modified { id } => id,
+            _ => {
+                continue;
+            }
+        };
+        for (effect, localized_by) in query_localized.iter() {
+            if localized_by.0.id() == *id {
+                trace!("-refreshing localized text of {:?}", effect);
+                commands.entity(effect).insert((DeferredEffect, Triggered));
+            }
+        }
+    }
+}
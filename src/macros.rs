@@ -0,0 +1,137 @@
+/// Create a `Computed` from a closure whose params each name their type and source entity, so the
+/// args tuple and the `sources` vec are always generated in the same order.
+///
+/// A source that is not a plain identifier needs parentheses, e.g. `msg: StaticStrRef = (test.signal[1])`.
+///
+/// ```ignore
+/// let login_msg = computed!(&mut commands, |logged_in: bool = signal0, msg: StaticStrRef = signal1| {
+///     LazySignals::result(if logged_in == Some(true) { msg.unwrap_or("hi") } else { "bye" })
+/// });
+/// ```
+#[macro_export]
+macro_rules! computed {
+    ($commands:expr, |$($name:ident : $type:ty = $source:tt),* $(,)?| $body:expr) => {
+        $crate::api::LazySignals.computed::<($(::core::option::Option<$type>,)*), _>(
+            move |($($name,)*): ($(::core::option::Option<$type>,)*)| $body,
+            ::std::vec![$($source),*],
+            $commands
+        )
+    };
+}
+
+/// Create an `Effect` the same way as `computed!`. The first closure param is the `&mut World` and
+/// an optional list of trigger entities may follow the closure.
+///
+/// ```ignore
+/// let log_logins = effect!(&mut commands, |world, logged_in: bool = signal0| {
+///     info!("logged in: {:?}", logged_in);
+///     None
+/// }, [trigger0]);
+/// ```
+#[macro_export]
+macro_rules! effect {
+    (
+        $commands:expr,
+        |$world:ident $(, $name:ident : $type:ty = $source:tt)* $(,)?| $body:expr
+        $(, [$($trigger:expr),* $(,)?])?
+    ) => {
+        $crate::api::LazySignals.effect::<($(::core::option::Option<$type>,)*)>(
+            move |($($name,)*): ($(::core::option::Option<$type>,)*), $world| $body,
+            ::std::vec![$($source),*],
+            ::std::vec![$($($trigger),*)?],
+            $commands
+        )
+    };
+}
+
+/// Create a `String` computed from a format string and sources declared the same way as
+/// `computed!`. Each param is passed to `format!` as a named argument of the same name. The
+/// result has no value unless every source does, and effects only see a change when the formatted
+/// string is different.
+///
+/// ```ignore
+/// let hp_text = formatted!(&mut commands, "{hp} / {max}", hp: u32 = hp, max: u32 = hp_max);
+/// ```
+#[macro_export]
+macro_rules! formatted {
+    ($commands:expr, $format:literal $(, $name:ident : $type:ty = $source:tt)* $(,)?) => {
+        $crate::api::LazySignals.computed::<
+            ($(::core::option::Option<$type>,)*),
+            ::std::string::String
+        >(
+            move |($($name,)*): ($(::core::option::Option<$type>,)*)| {
+                #[allow(unreachable_patterns)]
+                match ($($name,)*) {
+                    ($(::core::option::Option::Some($name),)*) => {
+                        let text = ::std::format!($format, $($name = $name),*);
+                        $crate::api::LazySignals::result(text)
+                    }
+                    _ => $crate::api::LazySignals::option(::core::option::Option::None),
+                }
+            },
+            ::std::vec![$($source),*],
+            $commands
+        )
+    };
+}
+
+/// Declare a struct of args with a named accessor for each source, and a struct of the sources by
+/// the same names that turns into the `sources` vec. Both are filled in declaration order, so
+/// reordering the fields can't pass a source to the wrong param.
+///
+/// ```ignore
+/// signal_params! {
+///     pub struct AuthParams(AuthSources) {
+///         logged_in: bool,
+///         msg: StaticStrRef,
+///     }
+/// }
+///
+/// let login_msg = LazySignals.computed::<AuthParams, StaticStrRef>(
+///     |p| LazySignals::result(if p.logged_in() == Some(&true) { "hi" } else { "bye" }),
+///     AuthSources { logged_in: signal0, msg: signal1 }.into(),
+///     &mut commands
+/// );
+/// ```
+#[macro_export]
+macro_rules! signal_params {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $params:ident($sources:ident) {
+            $($(#[$field_meta:meta])* $name:ident : $type:ty),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(
+            ::bevy::prelude::Reflect,
+            $crate::framework::LazySignalsParams,
+            ::core::cmp::PartialEq,
+            ::core::fmt::Debug
+        )]
+        $vis struct $params {
+            $($name: ::core::option::Option<$type>,)*
+        }
+
+        impl $params {
+            $(
+                $(#[$field_meta])*
+                #[allow(dead_code)]
+                pub fn $name(&self) -> ::core::option::Option<&$type> {
+                    self.$name.as_ref()
+                }
+            )*
+        }
+
+        /// The source of each param of the args struct with the same fields.
+        #[derive(::core::clone::Clone, ::core::marker::Copy, ::core::fmt::Debug)]
+        $vis struct $sources {
+            $(pub $name: ::bevy::prelude::Entity,)*
+        }
+
+        impl ::core::convert::From<$sources> for ::std::vec::Vec<::bevy::prelude::Entity> {
+            fn from(sources: $sources) -> Self {
+                ::std::vec![$(sources.$name),*]
+            }
+        }
+    };
+}
@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use bevy::prelude::*;
+
+use crate::framework::*;
+
+/// What to do with a send after a middleware has seen it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MiddlewareDecision {
+    /// Pass the (possibly rewritten) value on to the next middleware, and then the signal.
+    Continue,
+
+    /// Drop the send. Later middleware does not run.
+    Drop,
+}
+
+/// Runs for every value sent to a `Signal`, with the value as `&mut dyn Reflect` so it can be
+/// rewritten in place.
+pub type MiddlewareFn = dyn Fn(Entity, &mut dyn Reflect, &mut World) -> MiddlewareDecision +
+    Send +
+    Sync;
+
+/// ## Middleware
+/// Closures that run in order whenever a value is sent to a `Signal`, before its validator, for
+/// cross-cutting concerns such as logging, rate limiting, permission checks, or rewriting values.
+#[derive(Resource, Default)]
+pub struct LazySignalsMiddleware {
+    pipeline: Vec<Arc<MiddlewareFn>>,
+}
+
+impl LazySignalsMiddleware {
+    pub fn is_empty(&self) -> bool {
+        self.pipeline.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.pipeline.len()
+    }
+
+    /// Add a middleware to the end of the pipeline.
+    pub fn push(
+        &mut self,
+        middleware: impl Fn(Entity, &mut dyn Reflect, &mut World) -> MiddlewareDecision +
+            Send +
+            Sync +
+            'static
+    ) {
+        self.pipeline.push(Arc::new(middleware));
+    }
+}
+
+/// Pass a value being sent to a signal through the middleware pipeline, returning the value to send
+/// or `None` if a middleware dropped it.
+pub fn run_middleware<T: LazySignalsData>(signal: Entity, data: T, world: &mut World) -> Option<T> {
+    // clone the pipeline since each middleware gets the world
+    let pipeline = match world.get_resource::<LazySignalsMiddleware>() {
+        Some(middleware) if !middleware.is_empty() => middleware.pipeline.clone(),
+        _ => {
+            return Some(data);
+        }
+    };
+
+    let mut data = data;
+    for middleware in pipeline {
+        if middleware(signal, data.as_reflect_mut(), world) == MiddlewareDecision::Drop {
+            trace!("middleware dropped send to {}", entity_label(signal, world));
+            return None;
+        }
+    }
+    Some(data)
+}
+
+/// Same as `run_middleware` for a value only known through reflection. Returns whether the value
+/// should still be sent.
+pub fn run_middleware_reflect(signal: Entity, data: &mut dyn Reflect, world: &mut World) -> bool {
+    let pipeline = match world.get_resource::<LazySignalsMiddleware>() {
+        Some(middleware) if !middleware.is_empty() => middleware.pipeline.clone(),
+        _ => {
+            return true;
+        }
+    };
+
+    for middleware in pipeline {
+        if middleware(signal, data, world) == MiddlewareDecision::Drop {
+            trace!("middleware dropped send to {}", entity_label(signal, world));
+            return false;
+        }
+    }
+    true
+}
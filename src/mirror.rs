@@ -0,0 +1,89 @@
+use bevy::{ ecs::entity::EntityHashMap, prelude::* };
+
+use crate::{ arcane_wizardry::with_observable, framework::* };
+
+/// ## Mirroring
+/// Copies the values of `Mirrored` signals and computeds into a `MirroredSignals` resource that can
+/// be cloned into another world, such as a sub-app or the render world, where the entities
+/// themselves do not exist.
+///
+/// For a sub-app, call `extract_mirrored` from its extract function. For the render world, add a
+/// system to `ExtractSchedule` that does the same with `Extract<Res<MirroredSignals>>`:
+///
+/// ```ignore
+/// render_app.add_systems(ExtractSchedule, |mirrored: Extract<Res<MirroredSignals>>, mut commands: Commands| {
+///     commands.insert_resource(mirrored.clone());
+/// });
+/// ```
+#[derive(Component)]
+pub struct Mirrored;
+
+/// Latest values of every `Mirrored` signal, keyed by the main world entity.
+#[derive(Resource, Default)]
+pub struct MirroredSignals {
+    values: EntityHashMap<Box<dyn Reflect>>,
+}
+
+impl Clone for MirroredSignals {
+    fn clone(&self) -> Self {
+        let values = self.values
+            .iter()
+            .map(|(entity, value)| (*entity, value.clone_value()))
+            .collect();
+        Self { values }
+    }
+}
+
+impl MirroredSignals {
+    /// Get the mirrored value of the given main world entity.
+    pub fn get<T: LazySignalsData>(&self, entity: Entity) -> Option<T> {
+        self.values.get(&entity).and_then(|value| T::from_reflect(value.as_ref()))
+    }
+
+    /// Get the mirrored value of the given main world entity without knowing its type.
+    pub fn get_reflect(&self, entity: Entity) -> Option<&dyn Reflect> {
+        self.values.get(&entity).map(|value| value.as_ref())
+    }
+}
+
+// copy the values of mirrored signals that changed (or were just marked) into the resource
+pub fn collect_mirrored(
+    world: &mut World,
+    query_mirrored: &mut QueryState<(Entity, Has<ValueChanged>), With<Mirrored>>
+) {
+    let mirrored: Vec<(Entity, bool)> = query_mirrored.iter(world).collect();
+
+    let mut stale = Vec::<Entity>::new();
+    world.resource_scope(|world, mut mirrored_signals: Mut<MirroredSignals>| {
+        // forget anything that was despawned or unmarked
+        mirrored_signals.values.retain(|entity, _| mirrored.iter().any(|(e, _)| e == entity));
+
+        for (entity, changed) in mirrored.iter() {
+            if !*changed && mirrored_signals.values.contains_key(entity) {
+                continue;
+            }
+            match with_observable(*entity, world, |observable, _| observable.data_reflect()) {
+                Some(Some(value)) => {
+                    mirrored_signals.values.insert(*entity, value);
+                }
+                Some(None) => {
+                    mirrored_signals.values.remove(entity);
+                }
+                None => stale.push(*entity),
+            }
+        }
+    });
+
+    for entity in stale {
+        error!("mirrored entity {} is not a signal", entity_label(entity, world));
+        world.entity_mut(entity).remove::<Mirrored>();
+    }
+}
+
+/// Copy the `MirroredSignals` from the main world into a sub-app world.
+/// Matches the signature of `SubApp::set_extract` so it may be used directly.
+pub fn extract_mirrored(main_world: &mut World, sub_world: &mut World) {
+    if let Some(mirrored) = main_world.get_resource::<MirroredSignals>() {
+        sub_world.insert_resource(mirrored.clone());
+    }
+}
@@ -0,0 +1,236 @@
+use std::{ collections::BTreeMap, fmt, ops::Bound };
+
+use bevy::prelude::*;
+
+use crate::{
+    arcane_wizardry::with_observable,
+    commands::merge_signal_reflect,
+    framework::*,
+    introspection::tagged,
+};
+
+/// ## Namespace
+/// Hierarchical names for `LazySignals` entities, using dot-separated paths such as
+/// `"ui.settings.volume"`, so a whole subtree can be looked up or operated on at once.
+///
+/// Paths are kept sorted, so `lookup_prefix("ui.settings.")` returns the subtree in path order.
+#[derive(Resource, Default)]
+pub struct LazySignalsNamespace {
+    paths: BTreeMap<String, Entity>,
+}
+
+impl LazySignalsNamespace {
+    /// Give an entity a path, returning the entity that previously had it, if any.
+    pub fn insert(&mut self, path: impl Into<String>, entity: Entity) -> Option<Entity> {
+        self.paths.insert(path.into(), entity)
+    }
+
+    pub fn remove(&mut self, path: &str) -> Option<Entity> {
+        self.paths.remove(path)
+    }
+
+    pub fn lookup(&self, path: &str) -> Option<Entity> {
+        self.paths.get(path).copied()
+    }
+
+    /// Iterate over every path that starts with the prefix, in order.
+    pub fn lookup_prefix<'a>(
+        &'a self,
+        prefix: &'a str
+    ) -> impl Iterator<Item = (&'a str, Entity)> + 'a {
+        self.paths
+            .range::<str, _>((Bound::Included(prefix), Bound::Unbounded))
+            .take_while(move |(path, _)| path.starts_with(prefix))
+            .map(|(path, entity)| (path.as_str(), *entity))
+    }
+
+    /// Find the path of an entity (slow, for diagnostics).
+    pub fn path_of(&self, entity: Entity) -> Option<&str> {
+        self.paths
+            .iter()
+            .find(|(_, e)| **e == entity)
+            .map(|(path, _)| path.as_str())
+    }
+}
+
+/// Values of a namespace subtree at a point in time, keyed by path. A path with no value held
+/// `None` when the snapshot was taken.
+#[derive(Default)]
+pub struct NamespaceSnapshot {
+    pub(crate) values: BTreeMap<String, Option<Box<dyn Reflect>>>,
+
+    // the entities captured by tag without a path, keyed by their label
+    pub(crate) entities: BTreeMap<String, Entity>,
+}
+
+impl NamespaceSnapshot {
+    /// Capture the current value of every `Signal` or `Computed` under the prefix.
+    pub fn capture(prefix: &str, world: &mut World) -> Self {
+        let entities: Vec<(String, Entity)> = world
+            .resource::<LazySignalsNamespace>()
+            .lookup_prefix(prefix)
+            .map(|(path, entity)| (path.to_string(), entity))
+            .collect();
+
+        let mut values = BTreeMap::new();
+        for (path, entity) in entities {
+            if let Some(value) = with_observable(entity, world, |observable, _| observable.data_reflect()) {
+                values.insert(path, value);
+            }
+        }
+        Self { values, ..default() }
+    }
+
+    /// Capture the current value of every `Signal` or `Computed` with the tag in its
+    /// `LazySignalsMeta`, keyed by its path, or by its label (see `entity_label`) if it has none.
+    pub fn capture_tagged(tag: &str, world: &mut World) -> Self {
+        let mut snapshot = Self::default();
+        for entity in tagged(tag, world) {
+            let Some(value) = with_observable(entity, world, |observable, _| {
+                observable.data_reflect()
+            }) else {
+                continue;
+            };
+            let path = world.resource::<LazySignalsNamespace>().path_of(entity).map(String::from);
+            let key = path.unwrap_or_else(|| {
+                let label = entity_label(entity, world);
+                snapshot.entities.insert(label.clone(), entity);
+                label
+            });
+            snapshot.values.insert(key, value);
+        }
+        snapshot
+    }
+
+    pub fn get<T: LazySignalsData>(&self, path: &str) -> Option<T> {
+        self.values
+            .get(path)
+            .and_then(|value| value.as_ref())
+            .and_then(|value| T::from_reflect(value.as_ref()))
+    }
+
+    /// List the paths whose values differ between this snapshot and a later one, in path order,
+    /// e.g. to assert in a test that only the expected signals changed after an interaction.
+    pub fn diff(&self, other: &NamespaceSnapshot) -> Vec<SignalDiff> {
+        let mut diffs = Vec::new();
+        for (path, before) in self.values.iter() {
+            match other.values.get(path) {
+                None => {
+                    diffs.push(SignalDiff::Removed { path: path.clone(), value: clone(before) })
+                }
+                Some(after) if !same_value(before, after) => {
+                    diffs.push(SignalDiff::Changed {
+                        path: path.clone(),
+                        before: clone(before),
+                        after: clone(after),
+                    })
+                }
+                _ => {}
+            }
+        }
+        for (path, after) in other.values.iter() {
+            if !self.values.contains_key(path) {
+                diffs.push(SignalDiff::Added { path: path.clone(), value: clone(after) });
+            }
+        }
+        diffs.sort_by(|a, b| a.path().cmp(b.path()));
+        diffs
+    }
+
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        self.values.keys().map(|path| path.as_str())
+    }
+
+    /// Send each value back to the `Signal` currently at its path (or the one it was captured from,
+    /// if it had no path). `Computed` values are skipped.
+    pub fn restore(&self, world: &mut World) {
+        for (path, value) in self.values.iter() {
+            let entity = world
+                .resource::<LazySignalsNamespace>()
+                .lookup(path)
+                .or_else(|| self.entities.get(path).copied());
+            let Some(entity) = entity else {
+                warn!("nothing at path {} to restore", path);
+                continue;
+            };
+
+            // a computed will catch up from its restored sources
+            if world.get::<ComputedImmutable>(entity).is_some() {
+                continue;
+            }
+
+            // restoring is a write like any other, so guards and validators get their say
+            let value = value.as_ref().map(|value| value.clone_value());
+            match merge_signal_reflect(entity, value, world) {
+                Ok(()) => {}
+                Err(LazySignalsError::SendRefused(_)) => warn!("{} refused its snapshot value", path),
+                Err(LazySignalsError::NoSignalError) => {
+                    error!("{} at path {} is not a signal", entity_label(entity, world), path);
+                }
+                Err(error) => error!("could not restore {}: {}", path, error),
+            }
+        }
+    }
+}
+
+/// A difference between two `NamespaceSnapshot`s. Displays as one line, `+ path: value` for a path
+/// that was added, `- path: value` for one that was removed, and `~ path: before -> after`, with
+/// `None` for no value.
+#[derive(Debug)]
+pub enum SignalDiff {
+    Added {
+        path: String,
+        value: Option<Box<dyn Reflect>>,
+    },
+    Removed {
+        path: String,
+        value: Option<Box<dyn Reflect>>,
+    },
+    Changed {
+        path: String,
+        before: Option<Box<dyn Reflect>>,
+        after: Option<Box<dyn Reflect>>,
+    },
+}
+
+impl SignalDiff {
+    pub fn path(&self) -> &str {
+        match self {
+            SignalDiff::Added { path, .. } => path,
+            SignalDiff::Removed { path, .. } => path,
+            SignalDiff::Changed { path, .. } => path,
+        }
+    }
+}
+
+impl fmt::Display for SignalDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SignalDiff::Added { path, value } => write!(f, "+ {}: {}", path, show(value)),
+            SignalDiff::Removed { path, value } => write!(f, "- {}: {}", path, show(value)),
+            SignalDiff::Changed { path, before, after } => {
+                write!(f, "~ {}: {} -> {}", path, show(before), show(after))
+            }
+        }
+    }
+}
+
+fn clone(value: &Option<Box<dyn Reflect>>) -> Option<Box<dyn Reflect>> {
+    value.as_ref().map(|value| value.clone_value())
+}
+
+fn show(value: &Option<Box<dyn Reflect>>) -> String {
+    match value {
+        Some(value) => format!("{:?}", value),
+        None => "None".to_string(),
+    }
+}
+
+// values that cannot be compared by reflection count as different
+fn same_value(a: &Option<Box<dyn Reflect>>, b: &Option<Box<dyn Reflect>>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a.reflect_partial_eq(b.as_ref()) == Some(true),
+        (None, None) => true,
+        _ => false,
+    }
+}
@@ -0,0 +1,125 @@
+use std::time::Duration;
+
+use bevy::{ ecs::world::Command, prelude::* };
+
+use crate::{
+    api::LazySignals,
+    arcane_wizardry::with_observable,
+    clock::now,
+    commands::merge_signal_reflect,
+    despawn::despawn_with_policy,
+    framework::*,
+};
+
+/// ## Optimistic Updates
+/// An `OptimisticUpdate` component holds the value a `Signal` had before an optimistic send, until
+/// its confirmation trigger fires. If the deadline passes first, the signal is sent that value
+/// again.
+#[derive(Component)]
+pub struct OptimisticUpdate {
+    previous: Option<Box<dyn Reflect>>,
+    deadline: Duration,
+    confirmation: Entity,
+    confirmed: bool,
+}
+
+impl OptimisticUpdate {
+    /// The time the update is rolled back if it is not confirmed, given the `LazySignals` time
+    /// (see `clock`).
+    pub fn deadline(&self) -> Duration {
+        self.deadline
+    }
+
+    pub fn is_confirmed(&self) -> bool {
+        self.confirmed
+    }
+}
+
+/// Command to send a value right away and roll it back unless `confirmation` fires within
+/// `timeout`. Another optimistic send before then replaces the pending one but keeps the value to
+/// roll back to, so a rollback always returns to the last confirmed value.
+pub struct SendOptimisticCommand<T: LazySignalsData> {
+    pub signal: Entity,
+    pub data: T,
+    pub confirmation: Entity,
+    pub timeout: Duration,
+}
+
+impl<T: LazySignalsData> Command for SendOptimisticCommand<T> {
+    fn apply(self, world: &mut World) {
+        if world.get::<ComputedImmutable>(self.signal).is_some() {
+            error!("{} is a computed, it cannot be sent", entity_label(self.signal, world));
+            return;
+        }
+
+        let previous = match world.entity_mut(self.signal).take::<OptimisticUpdate>() {
+            Some(pending) => {
+                let _ = despawn_with_policy(pending.confirmation, DependentPolicy::Error, world);
+                pending.previous
+            }
+            None => {
+                let previous = with_observable(self.signal, world, |observable, _| {
+                    observable.data_reflect()
+                });
+                let Some(previous) = previous else {
+                    error!("could not send optimistic value to {:?}", self.signal);
+                    return;
+                };
+                previous
+            }
+        };
+
+        LazySignals.send_now(self.signal, self.data, world);
+
+        let signal = self.signal;
+        let mut commands = world.commands();
+        let confirmation = LazySignals.effect::<()>(
+            move |_, world| {
+                if let Some(mut pending) = world.get_mut::<OptimisticUpdate>(signal) {
+                    pending.confirmed = true;
+                }
+                None
+            },
+            vec![],
+            vec![self.confirmation],
+            &mut commands
+        );
+        world.flush();
+
+        let deadline = now(world) + self.timeout;
+        world.entity_mut(signal).insert(OptimisticUpdate {
+            previous,
+            deadline,
+            confirmation,
+            confirmed: false,
+        });
+    }
+}
+
+// keep confirmed optimistic values and roll back the ones whose time is up
+pub fn settle_optimistic_updates(
+    world: &mut World,
+    query_updates: &mut QueryState<(Entity, &OptimisticUpdate)>
+) {
+    let now = now(world);
+    let settled: Vec<Entity> = query_updates
+        .iter(world)
+        .filter(|(_, pending)| pending.confirmed || pending.deadline <= now)
+        .map(|(signal, _)| signal)
+        .collect();
+
+    for signal in settled {
+        let pending = world.entity_mut(signal).take::<OptimisticUpdate>().unwrap();
+        let _ = despawn_with_policy(pending.confirmation, DependentPolicy::Error, world);
+        if pending.confirmed {
+            trace!("-optimistic value of {} confirmed", entity_label(signal, world));
+            continue;
+        }
+
+        info!("rolling back optimistic value of {}", entity_label(signal, world));
+        // the rollback is a write too, so a guard can refuse it and keep the optimistic value
+        if let Err(error) = merge_signal_reflect(signal, pending.previous, world) {
+            warn!("could not roll back {}: {}", entity_label(signal, world), error);
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use bevy::{ color::palettes::css, ecs::entity::EntityHashMap, prelude::* };
+
+use crate::{ framework::*, stats::LazySignalsStats, LazySignalsPlugin, LazySignalsSystemSet };
+
+/// ## Overlay
+/// What a node of the graph did during the last frame it was active.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum NodeActivity {
+    /// A `Signal` was sent and its value changed.
+    Sent,
+    /// A `Computed` recomputed.
+    Recomputed,
+    /// An `Effect` ran (or an `Action` was spawned).
+    EffectRan,
+}
+
+impl NodeActivity {
+    pub fn color(&self) -> Color {
+        match self {
+            NodeActivity::Sent => css::GOLD.into(),
+            NodeActivity::Recomputed => css::DEEP_SKY_BLUE.into(),
+            NodeActivity::EffectRan => css::LIME.into(),
+        }
+    }
+}
+
+/// Settings for the overlay, which lays out the graph in columns by depth (signals on the left,
+/// effects on the right) and lights up each node when it is active. The light travels one column
+/// every `wave_delay` seconds, so the way a change fans out can be followed by eye.
+#[derive(Resource, Clone, Debug)]
+pub struct LazySignalsOverlay {
+    pub enabled: bool,
+
+    /// Position of the first node of the first column, in world space.
+    pub origin: Vec2,
+
+    /// Distance between columns (x) and between the nodes of a column (y).
+    pub spacing: Vec2,
+
+    pub radius: f32,
+
+    /// Seconds between lighting up one column and the next.
+    pub wave_delay: f32,
+
+    /// Seconds a node stays lit.
+    pub fade: f32,
+}
+
+impl Default for LazySignalsOverlay {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            origin: Vec2::new(-500.0, 300.0),
+            spacing: Vec2::new(120.0, 40.0),
+            radius: 10.0,
+            wave_delay: 0.1,
+            fade: 0.6,
+        }
+    }
+}
+
+/// A node of the graph as laid out by the overlay.
+#[derive(Clone, Copy, Debug)]
+pub struct OverlayNode {
+    pub position: Vec2,
+    pub depth: usize,
+    pub is_effect: bool,
+
+    /// The last activity of the node and the time (in seconds) it lights up.
+    pub activity: Option<(NodeActivity, f32)>,
+}
+
+/// The graph drawn by the overlay, rebuilt after each pass while the overlay is enabled.
+#[derive(Resource, Default)]
+pub struct OverlayGraph {
+    nodes: EntityHashMap<OverlayNode>,
+    edges: Vec<(Entity, Entity)>,
+}
+
+impl OverlayGraph {
+    pub fn get(&self, entity: Entity) -> Option<&OverlayNode> {
+        self.nodes.get(&entity)
+    }
+
+    pub fn edges(&self) -> &[(Entity, Entity)] {
+        &self.edges
+    }
+}
+
+/// `Plugin` to draw the signal graph with gizmos. Needs `bevy_gizmos` (e.g. from `DefaultPlugins`)
+/// and a 2D camera. Adds `LazySignalsPlugin` if it has not been added yet.
+pub struct LazySignalsOverlayPlugin;
+
+impl Plugin for LazySignalsOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<LazySignalsPlugin>() {
+            app.add_plugins(LazySignalsPlugin);
+        }
+
+        app.init_resource::<LazySignalsOverlay>()
+            .init_resource::<OverlayGraph>()
+            .add_systems(PreUpdate, record_overlay.after(LazySignalsSystemSet))
+            .add_systems(Update, draw_overlay);
+    }
+}
+
+type NodeParam<'a> = (
+    Entity,
+    Option<&'a ComputedImmutable>,
+    Option<&'a LazyEffect>,
+    Has<ValueChanged>,
+);
+type NodeFilter = Or<(With<ImmutableState>, With<LazyEffect>)>;
+
+// lay out the graph and note which nodes were active during this pass
+pub fn record_overlay(
+    world: &mut World,
+    query_nodes: &mut QueryState<NodeParam, NodeFilter>
+) {
+    let Some(settings) = world.get_resource::<LazySignalsOverlay>().cloned() else {
+        return;
+    };
+    if !settings.enabled {
+        return;
+    }
+    let now = world.get_resource::<Time>().map_or(0.0, |time| time.elapsed_seconds());
+
+    let mut sources = EntityHashMap::<Vec<Entity>>::default();
+    let mut active = Vec::<(Entity, NodeActivity)>::new();
+    let mut effects = Vec::<Entity>::new();
+    let stats = world.get_resource::<LazySignalsStats>();
+    for (entity, computed, effect, changed) in query_nodes.iter(world) {
+        let mut node_sources = Vec::new();
+        if let Some(computed) = computed {
+            node_sources.extend(computed.sources.iter());
+        }
+        if let Some(effect) = effect {
+            node_sources.extend(effect.sources.iter().chain(effect.triggers.iter()));
+            effects.push(entity);
+        }
+        sources.insert(entity, node_sources);
+
+        let entity_stats = stats.and_then(|stats| stats.get(entity));
+        if entity_stats.is_some_and(|stats| stats.runs > 0) {
+            active.push((entity, NodeActivity::EffectRan));
+        } else if entity_stats.is_some_and(|stats| stats.recomputes > 0) {
+            active.push((entity, NodeActivity::Recomputed));
+        } else if changed && computed.is_none() {
+            active.push((entity, NodeActivity::Sent));
+        }
+    }
+
+    // a node sits one column to the right of its deepest source
+    let mut depths = EntityHashMap::<usize>::default();
+    for entity in sources.keys() {
+        depth_of(*entity, &sources, &mut depths, &mut Vec::new());
+    }
+    let mut columns = Vec::<Vec<Entity>>::new();
+    for (entity, depth) in depths.iter() {
+        if columns.len() <= *depth {
+            columns.resize(*depth + 1, Vec::new());
+        }
+        columns[*depth].push(*entity);
+    }
+
+    let mut graph = world.resource_mut::<OverlayGraph>();
+    let mut nodes = EntityHashMap::<OverlayNode>::default();
+    for (depth, column) in columns.iter_mut().enumerate() {
+        column.sort();
+        for (row, entity) in column.iter().enumerate() {
+            let offset = Vec2::new(depth as f32, -(row as f32)) * settings.spacing;
+            nodes.insert(*entity, OverlayNode {
+                position: settings.origin + offset,
+                depth,
+                is_effect: effects.contains(entity),
+                activity: graph.nodes.get(entity).and_then(|node| node.activity),
+            });
+        }
+    }
+
+    // the wave starts at the shallowest node that was active
+    let start = active.iter().filter_map(|(entity, _)| depths.get(entity)).min().copied();
+    for (entity, activity) in active {
+        if let Some(node) = nodes.get_mut(&entity) {
+            let delay = (node.depth - start.unwrap_or_default()) as f32 * settings.wave_delay;
+            node.activity = Some((activity, now + delay));
+        }
+    }
+
+    graph.edges = sources
+        .iter()
+        .flat_map(|(entity, sources)| sources.iter().map(|source| (*source, *entity)))
+        .filter(|(source, _)| nodes.contains_key(source))
+        .collect();
+    graph.nodes = nodes;
+}
+
+// depth of the node in the graph, guarding against cycles
+fn depth_of(
+    entity: Entity,
+    sources: &EntityHashMap<Vec<Entity>>,
+    depths: &mut EntityHashMap<usize>,
+    visiting: &mut Vec<Entity>
+) -> usize {
+    if let Some(depth) = depths.get(&entity) {
+        return *depth;
+    }
+    if visiting.contains(&entity) {
+        return 0;
+    }
+    visiting.push(entity);
+    let depth = sources
+        .get(&entity)
+        .into_iter()
+        .flatten()
+        .filter(|source| sources.contains_key(*source))
+        .map(|source| depth_of(*source, sources, depths, visiting) + 1)
+        .max()
+        .unwrap_or_default();
+    visiting.pop();
+    depths.insert(entity, depth);
+    depth
+}
+
+// draw the nodes and edges, lighting up the active ones as the wave reaches them
+pub fn draw_overlay(
+    settings: Res<LazySignalsOverlay>,
+    graph: Res<OverlayGraph>,
+    time: Res<Time>,
+    mut gizmos: Gizmos
+) {
+    if !settings.enabled {
+        return;
+    }
+    let now = time.elapsed_seconds();
+    let lit = |node: &OverlayNode| {
+        node.activity.and_then(|(activity, at)| {
+            let elapsed = now - at;
+            (elapsed >= 0.0 && elapsed < settings.fade).then(|| {
+                activity.color().with_alpha(1.0 - elapsed / settings.fade)
+            })
+        })
+    };
+
+    let idle = Color::from(css::GRAY).with_alpha(0.5);
+    for (source, subscriber) in graph.edges.iter() {
+        let (Some(from), Some(to)) = (graph.nodes.get(source), graph.nodes.get(subscriber)) else {
+            continue;
+        };
+        gizmos.line_2d(from.position, to.position, lit(to).unwrap_or(idle));
+    }
+
+    for node in graph.nodes.values() {
+        let color = lit(node).unwrap_or(idle);
+        if node.is_effect {
+            gizmos.rect_2d(node.position, 0.0, Vec2::splat(settings.radius * 2.0), color);
+        } else {
+            gizmos.circle_2d(node.position, settings.radius, color);
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use bevy::{ ecs::schedule::{ InternedScheduleLabel, ScheduleLabel }, prelude::* };
+
+use crate::{ systems::effect::apply_deferred_effects, LazySignalsPlugin };
+
+/// ## Processing Classes
+/// Lets an `Effect` or `Action` run at another point in the frame than the rest of the chain, e.g.
+/// in `PostUpdate` after UI layout. Memos still resolve with the chain so every reader sees the
+/// same values, and the effect runs during the next pass of its class if one of its sources changed
+/// or it was triggered in the meantime.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ProcessingClass {
+    Ui,
+    Sim,
+    Background,
+}
+
+/// The class whose effects are being applied, `None` while the regular chain runs.
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct ActiveProcessingClass(pub Option<ProcessingClass>);
+
+/// Whether the entity belongs to the processing class whose effects are being applied.
+pub fn in_active_class(entity: Entity, world: &World) -> bool {
+    let active = world.get_resource::<ActiveProcessingClass>().copied().unwrap_or_default();
+    active.0 == world.get::<ProcessingClass>(entity).copied()
+}
+
+/// The schedule holding the effect system of one processing class.
+#[derive(ScheduleLabel, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct LazySignalsClassSchedule(pub ProcessingClass);
+
+/// `Plugin` to apply the effects of each processing class in its own schedule. By default `Ui`
+/// effects run in `PostUpdate`, `Sim` in `FixedUpdate`, and `Background` in `Last`. Adds
+/// `LazySignalsPlugin` if it has not been added yet.
+pub struct LazySignalsProcessingPlugin {
+    pub schedules: Vec<(ProcessingClass, InternedScheduleLabel)>,
+}
+
+impl Default for LazySignalsProcessingPlugin {
+    fn default() -> Self {
+        Self {
+            schedules: vec![
+                (ProcessingClass::Ui, PostUpdate.intern()),
+                (ProcessingClass::Sim, FixedUpdate.intern()),
+                (ProcessingClass::Background, Last.intern())
+            ],
+        }
+    }
+}
+
+impl LazySignalsProcessingPlugin {
+    /// Apply the effects of a class in the given schedule instead.
+    pub fn with_schedule(mut self, class: ProcessingClass, schedule: impl ScheduleLabel) -> Self {
+        self.schedules.retain(|(c, _)| *c != class);
+        self.schedules.push((class, schedule.intern()));
+        self
+    }
+}
+
+impl Plugin for LazySignalsProcessingPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<LazySignalsPlugin>() {
+            app.add_plugins(LazySignalsPlugin);
+        }
+
+        app.init_resource::<ActiveProcessingClass>();
+        for (class, schedule) in self.schedules.iter() {
+            let class = *class;
+            app.add_systems(LazySignalsClassSchedule(class), apply_deferred_effects).add_systems(
+                *schedule,
+                move |world: &mut World| {
+                    world.insert_resource(ActiveProcessingClass(Some(class)));
+                    world.run_schedule(LazySignalsClassSchedule(class));
+                    world.insert_resource(ActiveProcessingClass(None));
+                }
+            );
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use bevy::{
+    prelude::*,
+    reflect::{ serde::{ ReflectDeserializer, ReflectSerializer }, TypeRegistry },
+    utils::HashMap,
+};
+use serde::de::DeserializeSeed;
+
+use crate::{
+    arcane_wizardry::with_observable,
+    commands::merge_signal_reflect,
+    framework::*,
+    LazySignalsSystemSet,
+};
+
+/// ## Replication
+/// Server-authoritative replication of signal values, independent of the networking crate.
+///
+/// On the server, each change to a `Replicated` signal or computed is serialized (as JSON, via
+/// reflection) into a `ReplicatedSignalChanged` event. The application forwards those to clients
+/// with its networking crate (e.g. as `bevy_replicon` server events) and sends each one it receives
+/// on the client as an `ApplyReplicatedSignal` event, which is merged into the client's `Replicated`
+/// signal with the same name during the next batch. With the `replicon` feature,
+/// `replicon::LazySignalsRepliconPlugin` does the forwarding.
+#[derive(Component, Clone, Debug)]
+pub struct Replicated {
+    pub name: String,
+}
+
+/// Sent on the server when the value of a `Replicated` signal changes.
+/// A `value` of `None` means the signal was cleared.
+#[derive(Event, Clone, Debug)]
+pub struct ReplicatedSignalChanged {
+    pub name: String,
+    pub value: Option<String>,
+}
+
+/// Sent on the client to apply a value received from the server.
+#[derive(Event, Clone, Debug)]
+#[cfg_attr(feature = "replicon", derive(serde::Serialize, serde::Deserialize))]
+pub struct ApplyReplicatedSignal {
+    pub name: String,
+    pub value: Option<String>,
+}
+
+/// Which side of the connection this app is on.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReplicationRole {
+    Server,
+    Client,
+}
+
+/// `Plugin` to add the replication events and systems for the given role.
+pub struct LazySignalsReplicationPlugin {
+    pub role: ReplicationRole,
+}
+
+impl LazySignalsReplicationPlugin {
+    pub fn server() -> Self {
+        Self { role: ReplicationRole::Server }
+    }
+
+    pub fn client() -> Self {
+        Self { role: ReplicationRole::Client }
+    }
+}
+
+impl Plugin for LazySignalsReplicationPlugin {
+    fn build(&self, app: &mut App) {
+        match self.role {
+            ReplicationRole::Server => {
+                app.add_event::<ReplicatedSignalChanged>().add_systems(
+                    PreUpdate,
+                    // `ValueChanged` is still there until the next batch starts
+                    send_replicated.after(LazySignalsSystemSet)
+                );
+            }
+            ReplicationRole::Client => {
+                app.add_event::<ApplyReplicatedSignal>().add_systems(
+                    PreUpdate,
+                    apply_replicated.before(LazySignalsSystemSet)
+                );
+            }
+        }
+    }
+}
+
+fn to_json(data: &dyn Reflect, type_registry: &TypeRegistry) -> Result<String, serde_json::Error> {
+    serde_json::to_string(&ReflectSerializer::new(data, type_registry))
+}
+
+fn from_json(
+    json: &str,
+    type_registry: &TypeRegistry
+) -> Result<Box<dyn Reflect>, serde_json::Error> {
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    ReflectDeserializer::new(type_registry).deserialize(&mut deserializer)
+}
+
+// serialize the value of each replicated signal that changed during this batch
+pub fn send_replicated(
+    world: &mut World,
+    query_changed: &mut QueryState<(Entity, &Replicated), With<ValueChanged>>
+) {
+    let changed: Vec<(Entity, String)> = query_changed
+        .iter(world)
+        .map(|(entity, replicated)| (entity, replicated.name.clone()))
+        .collect();
+
+    for (entity, name) in changed {
+        if let Some(value) = serialize_replicated(entity, &name, world) {
+            trace!("-replicating {} from {:?}", name, entity);
+            world.send_event(ReplicatedSignalChanged { name, value });
+        }
+    }
+}
+
+/// The current value of a replicated signal as JSON, or `None` (after logging why) if it could not
+/// be serialized.
+pub fn serialize_replicated(entity: Entity, name: &str, world: &mut World) -> Option<Option<String>> {
+    let value = with_observable(entity, world, |observable, type_registry| {
+        observable
+            .data_reflect()
+            .map(|data| to_json(&*data, type_registry))
+            .transpose()
+    });
+
+    match value {
+        Some(Ok(value)) => Some(value),
+        Some(Err(error)) => {
+            error!("could not serialize replicated signal {}: {}", name, error);
+            None
+        }
+        None => {
+            error!("replicated entity {} is not a signal", entity_label(entity, world));
+            None
+        }
+    }
+}
+
+// merge each value received from the server into the client signal with the same name
+pub fn apply_replicated(
+    world: &mut World,
+    query_replicated: &mut QueryState<(Entity, &Replicated)>
+) {
+    let received: Vec<ApplyReplicatedSignal> = world
+        .resource_mut::<Events<ApplyReplicatedSignal>>()
+        .drain()
+        .collect();
+    if received.is_empty() {
+        return;
+    }
+
+    let signals: HashMap<String, Entity> = query_replicated
+        .iter(world)
+        .map(|(entity, replicated)| (replicated.name.clone(), entity))
+        .collect();
+
+    for ApplyReplicatedSignal { name, value } in received {
+        let Some(&entity) = signals.get(&name) else {
+            warn!("no replicated signal named {}", name);
+            continue;
+        };
+
+        let data = {
+            let type_registry = world.resource::<AppTypeRegistry>().read();
+            value
+                .as_deref()
+                .map(|json| from_json(json, &type_registry))
+                .transpose()
+        };
+        let data = match data {
+            Ok(data) => data,
+            Err(error) => {
+                error!("could not deserialize replicated signal {}: {}", name, error);
+                continue;
+            }
+        };
+
+        // guards still apply, e.g. so only this system may write the client copy
+        match merge_signal_reflect(entity, data, world) {
+            Ok(()) => {}
+            Err(LazySignalsError::SendRefused(_)) => warn!("{} refused a replicated value", name),
+            Err(error) => error!("could not apply replicated signal {}: {}", name, error),
+        }
+    }
+}
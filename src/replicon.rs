@@ -0,0 +1,98 @@
+use bevy::{ ecs::event::ManualEventReader, prelude::* };
+use bevy_replicon::prelude::*;
+
+use crate::{
+    replication::{
+        apply_replicated,
+        send_replicated,
+        serialize_replicated,
+        ApplyReplicatedSignal,
+        Replicated,
+        ReplicatedSignalChanged,
+    },
+    LazySignalsSystemSet,
+};
+
+/// ## Replicon
+/// `Plugin` to carry `Replicated` signal values from a `bevy_replicon` server to its clients, so
+/// lobby or match state can live in signals without any netcode of its own. Add it after
+/// `RepliconPlugins` (and the messaging backend) on both sides; which half runs is decided each
+/// frame by whether the server is running or the client is connected, so one binary can be either.
+///
+/// The server sends each change to a `Replicated` signal or computed to every client over an
+/// ordered channel, and the current value of all of them to a client when it connects. The client
+/// merges each value into its `Replicated` signal with the same name during the next batch, through
+/// the write guard, middleware, and validator of that signal like any other send. Use
+/// `LazySignalsReplicationPlugin` instead to forward the events with another networking crate.
+pub struct LazySignalsRepliconPlugin;
+
+impl Plugin for LazySignalsRepliconPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ReplicatedSignalChanged>()
+            .add_server_event::<ApplyReplicatedSignal>(ChannelKind::Ordered)
+            .add_systems(
+                PreUpdate,
+                (
+                    // `ValueChanged` is still there until the next batch starts
+                    (send_replicated, broadcast_replicated)
+                        .chain()
+                        .after(LazySignalsSystemSet)
+                        .run_if(server_running),
+                    send_all_replicated.after(ServerSet::Receive).run_if(server_running),
+                    apply_replicated
+                        .after(ClientSet::Receive)
+                        .before(LazySignalsSystemSet)
+                        .run_if(client_connected),
+                )
+            );
+    }
+}
+
+// forward each change to every client
+fn broadcast_replicated(
+    mut changed: EventReader<ReplicatedSignalChanged>,
+    mut to_clients: EventWriter<ToClients<ApplyReplicatedSignal>>
+) {
+    for ReplicatedSignalChanged { name, value } in changed.read().cloned() {
+        to_clients.send(ToClients {
+            mode: SendMode::Broadcast,
+            event: ApplyReplicatedSignal { name, value },
+        });
+    }
+}
+
+// bring each client that just connected up to date with every replicated value
+fn send_all_replicated(
+    world: &mut World,
+    query_replicated: &mut QueryState<(Entity, &Replicated)>,
+    mut connected: Local<ManualEventReader<ServerEvent>>
+) {
+    let clients: Vec<ClientId> = connected
+        .read(world.resource::<Events<ServerEvent>>())
+        .filter_map(|event| {
+            match event {
+                ServerEvent::ClientConnected { client_id } => Some(*client_id),
+                ServerEvent::ClientDisconnected { .. } => None,
+            }
+        })
+        .collect();
+    if clients.is_empty() {
+        return;
+    }
+
+    let replicated: Vec<(Entity, String)> = query_replicated
+        .iter(world)
+        .map(|(entity, replicated)| (entity, replicated.name.clone()))
+        .collect();
+    for (entity, name) in replicated {
+        let Some(value) = serialize_replicated(entity, &name, world) else {
+            continue;
+        };
+        for client_id in clients.iter() {
+            world.send_event(ToClients {
+                mode: SendMode::Direct(*client_id),
+                event: ApplyReplicatedSignal { name: name.clone(), value: value.clone() },
+            });
+        }
+    }
+}
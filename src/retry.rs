@@ -0,0 +1,192 @@
+use std::{ sync::Arc, time::Duration };
+
+use bevy::{
+    prelude::*,
+    tasks::{ block_on, futures_lite::future, AsyncComputeTaskPool, Task, TaskPool },
+};
+
+use crate::{ api::LazySignals, clock::now, commands::LazySignalsCommandsExt, framework::* };
+
+/// ## Retry
+/// What a resolve made by `LazySignals.resolve` is doing.
+#[derive(Reflect, Clone, PartialEq, Eq, Default, Debug)]
+pub enum ResolveStatus {
+    /// Nothing has been resolved yet.
+    #[default]
+    Idle,
+
+    /// The first attempt is running.
+    Pending,
+
+    /// An attempt failed and this retry (starting at 1) is waiting or running.
+    Retrying(u32),
+
+    /// Every attempt failed, the last one with this error.
+    Failed(String),
+
+    /// The last attempt succeeded and the value was sent.
+    Ready,
+}
+
+/// How many times a resolve is attempted and how long it waits between attempts. The wait starts at
+/// `initial_delay` and is multiplied by `multiplier` after each failed attempt, up to `max_delay`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct RetryPolicy {
+    /// Attempts in total, including the first one.
+    pub max_attempts: u32,
+    pub initial_delay: Duration,
+    pub multiplier: f32,
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Try once and give up on the first error.
+    pub fn never() -> Self {
+        Self { max_attempts: 1, ..default() }
+    }
+
+    /// The wait before the given retry (starting at 1).
+    pub fn delay(&self, retry: u32) -> Duration {
+        let factor = self.multiplier.max(1.0).powi(retry.saturating_sub(1) as i32);
+        self.initial_delay.mul_f32(factor).min(self.max_delay)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_delay: Duration::from_millis(500),
+            multiplier: 2.0,
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+/// The signals of a resolve made by `LazySignals.resolve`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Resolve {
+    /// `ResolveStatus` signal.
+    pub status: Entity,
+
+    /// Signal with the last value resolved, which is kept while the next one is resolving or if it
+    /// fails.
+    pub value: Entity,
+}
+
+// sends the resolved value
+type SendValue = Box<dyn FnOnce(&mut World) + Send>;
+
+// starts another attempt with the same args
+type StartFn = Arc<dyn Fn() -> Task<Result<SendValue, String>> + Send + Sync>;
+
+/// A `ResolveInFlight` component on the status signal of a resolve holds the attempt being made,
+/// or the time to make the next one. Replacing or removing it drops the task, so the value of a
+/// canceled attempt is never sent.
+#[derive(Component)]
+pub struct ResolveInFlight {
+    attempt: u32,
+    policy: RetryPolicy,
+    start: StartFn,
+    task: Option<Task<Result<SendValue, String>>>,
+    retry_at: Duration,
+}
+
+/// Create the signals of a resolve and an `Effect` that runs the fallible closure on the
+/// `AsyncComputeTaskPool` right away and whenever a source changes, canceling the attempt in
+/// flight, if any. A failed attempt is retried according to the policy.
+pub fn resolve<P: LazySignalsArgs, R: LazySignalsData>(
+    closure: impl Fn(&P) -> Result<R, String> + Send + Sync + 'static,
+    sources: Vec<Entity>,
+    policy: RetryPolicy,
+    commands: &mut Commands
+) -> Resolve {
+    let status = LazySignals.state(ResolveStatus::Idle, commands);
+    let value = commands.spawn_empty().id();
+    commands.create_empty_state::<R>(value);
+
+    let closure = Arc::new(closure);
+    let effect = LazySignals.effect::<P>(
+        move |args, world| {
+            let args = Arc::new(args);
+            let closure = Arc::clone(&closure);
+            let start: StartFn = Arc::new(move || {
+                let args = Arc::clone(&args);
+                let closure = Arc::clone(&closure);
+                AsyncComputeTaskPool::get_or_init(TaskPool::default).spawn(async move {
+                    closure(&args).map(|data| {
+                        Box::new(move |world: &mut World| LazySignals.send_now(value, data, world))
+                            as SendValue
+                    })
+                })
+            });
+
+            trace!("-resolving {}", entity_label(status, world));
+            let task = Some(start());
+            if let Some(mut entity) = world.get_entity_mut(status) {
+                let retry_at = Duration::ZERO;
+                entity.insert(ResolveInFlight { attempt: 1, policy, start, task, retry_at });
+            }
+            LazySignals.send_now(status, ResolveStatus::Pending, world);
+            None
+        },
+        sources,
+        vec![],
+        commands
+    );
+    commands.entity(effect).insert(InitialEvaluation(true));
+
+    Resolve { status, value }
+}
+
+// send the value of each attempt that succeeded, and retry or give up on the ones that failed
+pub fn poll_resolves(
+    world: &mut World,
+    query_resolves: &mut QueryState<(Entity, &mut ResolveInFlight)>
+) {
+    let now = now(world);
+    let mut finished = Vec::<(Entity, Result<SendValue, String>)>::new();
+    for (status, mut in_flight) in query_resolves.iter_mut(world) {
+        let in_flight = in_flight.as_mut();
+        match in_flight.task.as_mut() {
+            Some(task) => {
+                if let Some(result) = block_on(future::poll_once(task)) {
+                    finished.push((status, result));
+                }
+            }
+            None if now >= in_flight.retry_at => {
+                trace!("-retrying attempt {}", in_flight.attempt);
+                in_flight.task = Some((in_flight.start)());
+            }
+            None => {}
+        }
+    }
+
+    for (status, result) in finished {
+        let error = match result {
+            Ok(send_value) => {
+                world.entity_mut(status).remove::<ResolveInFlight>();
+                send_value(world);
+                LazySignals.send_now(status, ResolveStatus::Ready, world);
+                continue;
+            }
+            Err(error) => error,
+        };
+
+        let mut entity = world.entity_mut(status);
+        let mut in_flight = entity.get_mut::<ResolveInFlight>().unwrap();
+        if in_flight.attempt >= in_flight.policy.max_attempts {
+            entity.remove::<ResolveInFlight>();
+            warn!("resolve for {} failed: {}", entity_label(status, world), error);
+            LazySignals.send_now(status, ResolveStatus::Failed(error), world);
+            continue;
+        }
+
+        let retry = in_flight.attempt;
+        trace!("-attempt {} failed, retrying: {}", retry, error);
+        in_flight.attempt += 1;
+        in_flight.task = None;
+        in_flight.retry_at = now + in_flight.policy.delay(retry);
+        LazySignals.send_now(status, ResolveStatus::Retrying(retry), world);
+    }
+}
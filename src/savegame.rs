@@ -0,0 +1,204 @@
+use std::{ any::TypeId, collections::BTreeMap, marker::PhantomData };
+
+use bevy::{
+    ecs::world::Command,
+    prelude::*,
+    reflect::{
+        serde::{ TypedReflectDeserializer, TypedReflectSerializer },
+        GetTypeRegistration,
+        TypeRegistry,
+    },
+    utils::HashMap,
+};
+use serde::de::DeserializeSeed;
+use serde_json::{ json, Value };
+
+use crate::{
+    arcane_wizardry::data_type_info,
+    framework::*,
+    namespace::{ LazySignalsNamespace, NamespaceSnapshot },
+};
+
+/// ## Savegames
+/// The current schema version of each namespace path, and the migrations that bring a value saved
+/// at an older version up to date when a save is loaded. A path with no version is at version 0.
+#[derive(Resource, Default)]
+pub struct LazySignalsSchema {
+    versions: HashMap<String, u32>,
+    migrations: HashMap<(String, u32), Migration>,
+}
+
+impl LazySignalsSchema {
+    /// The version values for the path are saved with.
+    pub fn version(&self, path: &str) -> u32 {
+        self.versions.get(path).copied().unwrap_or_default()
+    }
+
+    /// Whether there is a migration from the version to the next one for the path.
+    pub fn has_migration(&self, path: &str, from_version: u32) -> bool {
+        self.migrations.contains_key(&(path.to_string(), from_version))
+    }
+
+    fn migration(&self, path: &str, from_version: u32) -> Option<&Migration> {
+        self.migrations.get(&(path.to_string(), from_version))
+    }
+}
+
+/// Converts the value saved for a path at one schema version into the value at the next one.
+type MigrateFn = Box<dyn Fn(&dyn Reflect) -> Option<Box<dyn Reflect>> + Send + Sync>;
+
+struct Migration {
+    // the type the value was saved as, to deserialize it before migrating
+    from_type: TypeId,
+    migrate: MigrateFn,
+}
+
+/// Command to set the schema version of a namespace path.
+pub struct SchemaVersionCommand {
+    pub path: String,
+    pub version: u32,
+}
+
+impl Command for SchemaVersionCommand {
+    fn apply(self, world: &mut World) {
+        world
+            .get_resource_or_insert_with(LazySignalsSchema::default)
+            .versions.insert(self.path, self.version);
+    }
+}
+
+/// Command to register the migration of a path from `from_version` to the next version, and raise
+/// the schema version of the path to at least the next one. The old type is registered for
+/// reflection so saves holding it can still be read.
+pub struct RegisterMigrationCommand<O, N, F> {
+    pub path: String,
+    pub from_version: u32,
+    pub function: F,
+    pub types: PhantomData<(O, N)>,
+}
+
+impl<O, N, F> Command
+    for RegisterMigrationCommand<O, N, F>
+    where
+        O: FromReflect + GetTypeRegistration,
+        N: Reflect,
+        F: Fn(O) -> N + Send + Sync + 'static
+{
+    fn apply(self, world: &mut World) {
+        world.resource::<AppTypeRegistry>().write().register::<O>();
+
+        let function = self.function;
+        let migration = Migration {
+            from_type: TypeId::of::<O>(),
+            migrate: Box::new(move |old| {
+                O::from_reflect(old).map(|old| Box::new(function(old)) as Box<dyn Reflect>)
+            }),
+        };
+
+        let mut schema = world.get_resource_or_insert_with(LazySignalsSchema::default);
+        let version = schema.versions.entry(self.path.clone()).or_default();
+        *version = (*version).max(self.from_version + 1);
+        schema.migrations.insert((self.path, self.from_version), migration);
+    }
+}
+
+impl NamespaceSnapshot {
+    /// Serialize the snapshot as a JSON object keyed by path, with the schema version of each path
+    /// next to its value: `{ "ui.settings.volume": { "version": 1, "value": 0.5 } }`.
+    pub fn to_json(&self, world: &World) -> Result<String, serde_json::Error> {
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        let schema = world.get_resource::<LazySignalsSchema>();
+
+        let mut saved = serde_json::Map::new();
+        for (path, value) in self.values.iter() {
+            let version = schema.map_or(0, |schema| schema.version(path));
+            let value = match value {
+                Some(value) => {
+                    let serializer = TypedReflectSerializer::new(value.as_ref(), &type_registry);
+                    serde_json::to_value(serializer)?
+                }
+                None => Value::Null,
+            };
+            saved.insert(path.clone(), json!({ "version": version, "value": value }));
+        }
+        serde_json::to_string(&Value::Object(saved))
+    }
+
+    /// Read a snapshot saved by `to_json`, running the registered migrations on values saved at an
+    /// older schema version. A path that is not in the namespace, was saved by a newer version, or
+    /// cannot be migrated is logged and left out. Call `restore` on the result to send the values.
+    pub fn from_json(json: &str, world: &World) -> Result<Self, serde_json::Error> {
+        let saved: BTreeMap<String, Value> = serde_json::from_str(json)?;
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        let namespace = world.resource::<LazySignalsNamespace>();
+        let default_schema = LazySignalsSchema::default();
+        let schema = world.get_resource::<LazySignalsSchema>().unwrap_or(&default_schema);
+
+        let mut values = BTreeMap::new();
+        for (path, entry) in saved {
+            let Some(entity) = namespace.lookup(&path) else {
+                warn!("nothing at path {} to load", path);
+                continue;
+            };
+            let version = entry
+                .get("version")
+                .and_then(Value::as_u64)
+                .and_then(|version| u32::try_from(version).ok())
+                .unwrap_or_default();
+            let value = entry.get("value").cloned().unwrap_or_default();
+
+            match load_value(&path, entity, version, value, schema, &type_registry, world) {
+                Ok(value) => {
+                    values.insert(path, value);
+                }
+                Err(error) => error!("could not load {}: {}", path, error),
+            }
+        }
+        Ok(Self { values, ..default() })
+    }
+}
+
+// deserialize a saved value as the type it was saved with and migrate it to the current version
+fn load_value(
+    path: &str,
+    entity: Entity,
+    version: u32,
+    value: Value,
+    schema: &LazySignalsSchema,
+    type_registry: &TypeRegistry,
+    world: &World
+) -> Result<Option<Box<dyn Reflect>>, String> {
+    let current = schema.version(path);
+    if version > current {
+        return Err(format!("saved at version {} but the schema is at {}", version, current));
+    }
+    if value.is_null() {
+        return Ok(None);
+    }
+
+    let type_id = match version == current {
+        true => data_type_info(entity, world, type_registry)
+            .map(|info| info.type_id())
+            .ok_or_else(|| format!("{} is not a signal", entity_label(entity, world)))?,
+        false => schema
+            .migration(path, version)
+            .map(|migration| migration.from_type)
+            .ok_or_else(|| format!("no migration from version {}", version))?,
+    };
+    let registration = type_registry
+        .get(type_id)
+        .ok_or_else(|| format!("type of version {} is not registered", version))?;
+    let mut data = TypedReflectDeserializer::new(registration, type_registry)
+        .deserialize(value)
+        .map_err(|error| format!("could not deserialize version {}: {}", version, error))?;
+
+    for from_version in version..current {
+        let migration = schema
+            .migration(path, from_version)
+            .ok_or_else(|| format!("no migration from version {}", from_version))?;
+        data = (migration.migrate)(data.as_ref()).ok_or_else(|| {
+            format!("migration from version {} got the wrong type", from_version)
+        })?;
+    }
+    Ok(Some(data))
+}
@@ -0,0 +1,109 @@
+use bevy::prelude::*;
+
+use crate::{ context::in_active_context, framework::*, strict::CountedSection };
+
+/// ## Sequences
+/// Runs a list of `Effect` or `Action` entities one at a time, each only after the previous one
+/// has completed (for an `Action`, when its task finishes). Trigger the sequence entity to start
+/// it from the first step, which also restarts one already in progress.
+#[derive(Component)]
+pub struct LazySignalsSequence {
+    pub steps: Vec<Entity>,
+    current: Option<usize>,
+}
+
+impl LazySignalsSequence {
+    pub fn new(steps: Vec<Entity>) -> Self {
+        Self { steps, current: None }
+    }
+
+    /// Index of the step that is running or waiting to run, if the sequence is in progress.
+    pub fn current_step(&self) -> Option<usize> {
+        self.current
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.current.is_some()
+    }
+}
+
+/// Start a sequence from the first step, cancelling the step in progress, if any.
+pub fn start_sequence(sequence: Entity, world: &mut World) {
+    cancel_sequence(sequence, world);
+    let Some(mut state) = world.get_mut::<LazySignalsSequence>(sequence) else {
+        return;
+    };
+    let Some(first) = state.steps.first().copied() else {
+        return;
+    };
+    state.current = Some(0);
+    run_step(first, world);
+}
+
+/// Stop a sequence, dropping the task of the step in progress if it is an `Action`.
+pub fn cancel_sequence(sequence: Entity, world: &mut World) {
+    let Some(mut state) = world.get_mut::<LazySignalsSequence>(sequence) else {
+        return;
+    };
+    let Some(step) = state.current.take().map(|index| state.steps[index]) else {
+        return;
+    };
+    trace!(
+        "cancelling sequence {} at step {}",
+        entity_label(sequence, world),
+        entity_label(step, world)
+    );
+    if let Some(mut step) = world.get_entity_mut(step) {
+        step.remove::<(DeferredEffect, Triggered, RunningTask)>();
+    }
+}
+
+fn run_step(step: Entity, world: &mut World) {
+    if let Some(mut step) = world.get_entity_mut(step) {
+        step.insert((DeferredEffect, Triggered));
+    }
+}
+
+// move each running sequence on to its next step once the current one has completed
+pub fn advance_sequences(
+    world: &mut World,
+    query_sequences: &mut QueryState<(Entity, &LazySignalsSequence)>
+) {
+    let _counted = CountedSection::open();
+    let mut buffers = take_buffers(world);
+    buffers.stack.extend(
+        query_sequences
+            .iter(world)
+            .filter(|(entity, sequence)| {
+                sequence.current.is_some() && in_active_context(*entity, world)
+            })
+            .map(|(entity, _)| entity)
+    );
+
+    for sequence in buffers.stack.iter().copied() {
+        let state = world.get::<LazySignalsSequence>(sequence).unwrap();
+        let index = state.current.unwrap();
+        let step = state.steps[index];
+
+        // still waiting to run, or an action whose task has not finished
+        let pending = world
+            .get_entity(step)
+            .is_some_and(|step| step.contains::<DeferredEffect>() || step.contains::<RunningTask>());
+        if pending {
+            continue;
+        }
+
+        let mut state = world.get_mut::<LazySignalsSequence>(sequence).unwrap();
+        match state.steps.get(index + 1).copied() {
+            Some(next) => {
+                state.current = Some(index + 1);
+                run_step(next, world);
+            }
+            None => {
+                state.current = None;
+                trace!("sequence {} completed", entity_label(sequence, world));
+            }
+        }
+    }
+    return_buffers(buffers, world);
+}
@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+
+#[cfg(feature = "camera")]
+use bevy::render::camera::Camera;
+
+use crate::api::LazySignals;
+
+/// ## Spatial Signals
+/// A `WithinDistance` component makes a `bool` signal track whether two entities are no further
+/// than `radius` apart, for "in range" UI and AI gating. The value is `false` if either entity is
+/// gone.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct WithinDistance {
+    pub a: Entity,
+    pub b: Entity,
+    pub radius: f32,
+}
+
+// check the distance between each pair of entities where either one moved
+pub fn update_proximity(
+    query_signals: Query<(Entity, Ref<WithinDistance>)>,
+    query_transforms: Query<Ref<GlobalTransform>>,
+    mut commands: Commands
+) {
+    for (signal, within) in query_signals.iter() {
+        let a = query_transforms.get(within.a).ok();
+        let b = query_transforms.get(within.b).ok();
+        let changed =
+            within.is_changed() ||
+            a.as_ref().map_or(true, |transform| transform.is_changed()) ||
+            b.as_ref().map_or(true, |transform| transform.is_changed());
+        if !changed {
+            continue;
+        }
+
+        let in_range = match (a, b) {
+            (Some(a), Some(b)) => {
+                a.translation().distance_squared(b.translation()) <= within.radius * within.radius
+            }
+            _ => false,
+        };
+        LazySignals.send(signal, in_range, &mut commands);
+    }
+}
+
+/// A `ScreenPositionOf` component makes an `Option<Vec2>` signal track where an entity appears in
+/// the viewport of a camera, in logical pixels from the top left, for floating health bars and
+/// nameplates. The value is `None` while the entity is behind the camera, or if either entity is
+/// gone.
+#[cfg(feature = "camera")]
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ScreenPositionOf {
+    pub target: Entity,
+    pub camera: Entity,
+}
+
+// project each tracked entity whose transform or camera changed
+#[cfg(feature = "camera")]
+pub fn update_screen_positions(
+    query_signals: Query<(Entity, Ref<ScreenPositionOf>)>,
+    query_transforms: Query<Ref<GlobalTransform>>,
+    query_cameras: Query<(Ref<Camera>, Ref<GlobalTransform>)>,
+    mut commands: Commands
+) {
+    for (signal, position_of) in query_signals.iter() {
+        let target = query_transforms.get(position_of.target).ok();
+        let camera = query_cameras.get(position_of.camera).ok();
+        let changed =
+            position_of.is_changed() ||
+            target.as_ref().map_or(true, |transform| transform.is_changed()) ||
+            camera
+                .as_ref()
+                .map_or(true, |(camera, transform)| camera.is_changed() || transform.is_changed());
+        if !changed {
+            continue;
+        }
+
+        let position = match (target, camera) {
+            (Some(target), Some((camera, camera_transform))) => {
+                camera.world_to_viewport(&camera_transform, target.translation())
+            }
+            _ => None,
+        };
+        LazySignals.send(signal, position, &mut commands);
+    }
+}
@@ -0,0 +1,175 @@
+use std::time::Duration;
+
+use bevy::{ ecs::entity::EntityHashMap, prelude::* };
+
+use crate::{ framework::{ entity_label, BudgetExceeded, MaxDuration }, leaks::LeakKind };
+
+/// ## Statistics
+/// Counters for each `Computed` and `Effect` that has run, to find the hot and useless nodes in a
+/// large graph. By default they are reset at the start of each frame.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct EntityStats {
+    /// Number of times an `Effect` ran (or an `Action` was spawned).
+    pub runs: u32,
+
+    /// Number of times a `Computed` recomputed.
+    pub recomputes: u32,
+
+    /// Number of recomputes that produced the same value as before.
+    pub wasted_recomputes: u32,
+
+    /// How long the closure took the last time it ran.
+    pub last_duration: Duration,
+
+    /// Number of runs that took longer than the `MaxDuration` of the entity.
+    pub over_budget: u32,
+}
+
+/// Per-entity counters, updated by the `LazySignals` systems.
+#[derive(Resource)]
+pub struct LazySignalsStats {
+    /// Clear the counters at the start of each frame.
+    pub reset_each_frame: bool,
+
+    /// In debug builds, warn when a `Computed` recomputes this many times in a row without its
+    /// value changing (0 to turn off). The streaks are not reset each frame.
+    pub wasted_recompute_warning: u32,
+
+    entities: EntityHashMap<EntityStats>,
+    wasted_streaks: EntityHashMap<u32>,
+    leaks: Vec<(Entity, LeakKind)>,
+    recompute_order: Vec<Entity>,
+}
+
+impl Default for LazySignalsStats {
+    fn default() -> Self {
+        Self {
+            reset_each_frame: true,
+            wasted_recompute_warning: 60,
+            entities: EntityHashMap::default(),
+            wasted_streaks: EntityHashMap::default(),
+            leaks: Vec::new(),
+            recompute_order: Vec::new(),
+        }
+    }
+}
+
+impl LazySignalsStats {
+    pub fn get(&self, entity: Entity) -> Option<&EntityStats> {
+        self.entities.get(&entity)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, &EntityStats)> {
+        self.entities.iter().map(|(entity, stats)| (*entity, stats))
+    }
+
+    /// The entities found by the last leak detection check (see `LazySignalsLeakDetectionPlugin`).
+    /// Not reset each frame.
+    pub fn leaks(&self) -> &[(Entity, LeakKind)] {
+        &self.leaks
+    }
+
+    /// The `Computed`s that recomputed since the counters were last reset, in the order they ran.
+    pub fn recompute_order(&self) -> &[Entity] {
+        &self.recompute_order
+    }
+
+    pub fn reset(&mut self) {
+        self.entities.clear();
+        self.recompute_order.clear();
+    }
+
+    /// Record a `Computed` run and whether its value changed. Returns `true` when the entity should
+    /// be warned about for recomputing the same value too many times in a row, which only happens
+    /// in debug builds.
+    pub fn record_recompute(&mut self, entity: Entity, duration: Duration, changed: bool) -> bool {
+        let stats = self.entities.entry(entity).or_default();
+        stats.recomputes += 1;
+        if !changed {
+            stats.wasted_recomputes += 1;
+        }
+        stats.last_duration = duration;
+        self.recompute_order.push(entity);
+        self.record_wasted_streak(entity, changed)
+    }
+
+    #[cfg(debug_assertions)]
+    fn record_wasted_streak(&mut self, entity: Entity, changed: bool) -> bool {
+        if changed {
+            self.wasted_streaks.remove(&entity);
+        } else if self.wasted_recompute_warning > 0 {
+            let streak = self.wasted_streaks.entry(entity).or_default();
+            *streak += 1;
+            return *streak == self.wasted_recompute_warning;
+        }
+        false
+    }
+
+    #[cfg(not(debug_assertions))]
+    fn record_wasted_streak(&mut self, _entity: Entity, _changed: bool) -> bool {
+        false
+    }
+
+    /// The number of times in a row the `Computed` has recomputed without its value changing, as
+    /// counted for `wasted_recompute_warning`. Always 0 in release builds.
+    pub fn wasted_streak(&self, entity: Entity) -> u32 {
+        self.wasted_streaks.get(&entity).copied().unwrap_or_default()
+    }
+
+    /// Record a run that took longer than its `MaxDuration`.
+    pub fn record_over_budget(&mut self, entity: Entity) {
+        self.entities.entry(entity).or_default().over_budget += 1;
+    }
+
+    pub(crate) fn set_leaks(&mut self, leaks: Vec<(Entity, LeakKind)>) {
+        self.leaks = leaks;
+    }
+
+    /// Record an `Effect` run.
+    pub fn record_run(&mut self, entity: Entity, duration: Duration) {
+        let stats = self.entities.entry(entity).or_default();
+        stats.runs += 1;
+        stats.last_duration = duration;
+    }
+}
+
+/// Check a run of a `Computed` or `Effect` against its `MaxDuration`, if it has one, recording it
+/// and sending `BudgetExceeded` when it went over.
+pub fn check_budget(entity: Entity, duration: Duration, world: &mut World) {
+    let Some(max_duration) = world.get::<MaxDuration>(entity).map(|max| max.0) else {
+        return;
+    };
+    if duration <= max_duration {
+        return;
+    }
+    warn!(
+        "{} took {:?}, over its budget of {:?}",
+        entity_label(entity, world),
+        duration,
+        max_duration
+    );
+    if let Some(mut stats) = world.get_resource_mut::<LazySignalsStats>() {
+        stats.record_over_budget(entity);
+    }
+    world.send_event(BudgetExceeded { entity, duration, max_duration });
+}
+
+/// Hint that a `Computed` keeps recomputing without its value changing. Only logs in debug builds.
+#[cfg(debug_assertions)]
+pub fn warn_wasted_recompute(entity: Entity, world: &World) {
+    warn!(
+        "computed {} keeps recomputing without changing, consider a finer-grained source (e.g. a \
+        lens) or a custom PartialEq for its sources",
+        entity_label(entity, world)
+    );
+}
+
+#[cfg(not(debug_assertions))]
+pub fn warn_wasted_recompute(_entity: Entity, _world: &World) {}
+
+// clear the counters if they are only meant to cover a single frame
+pub fn reset_stats(mut stats: ResMut<LazySignalsStats>) {
+    if stats.reset_each_frame {
+        stats.reset();
+    }
+}
@@ -0,0 +1,120 @@
+use async_channel::{ Receiver, TryRecvError };
+use bevy::{
+    ecs::world::Command,
+    prelude::*,
+    tasks::{ futures_lite::{ Stream, StreamExt }, AsyncComputeTaskPool, TaskPool },
+};
+
+use crate::{
+    api::make_effect_with,
+    commands::{ LazySignalsCommandsExt, SendSignalCommand },
+    despawn::despawn_with_policy,
+    framework::*,
+};
+
+/// ## Streams
+/// Forwards the items of a `Stream` to a `Signal` made by `LazySignals.from_stream`. Each frame,
+/// the items that arrived are sent before the next batch, same as `LazySignals.send`, so the
+/// `Backpressure` policy of the signal decides what happens to a burst.
+#[derive(Component)]
+pub struct StreamSource {
+    drain: StreamDrain,
+}
+
+/// Sends whatever arrived from a stream to its signal, returning `false` once the stream has ended.
+type StreamDrain = Box<dyn FnMut(Entity, &mut World) -> bool + Send + Sync>;
+
+/// Make a `Stream` that yields each new value of the `Signal` or `Computed`, e.g. to await it in a
+/// task. Values that arrive while the stream is not being polled are buffered. The value the
+/// signal already holds is not yielded. Once the stream is dropped, the `Effect` feeding it is
+/// despawned the next time the signal changes.
+pub fn into_stream<T: LazySignalsData>(
+    immutable: Entity,
+    commands: &mut Commands
+) -> impl Stream<Item = T> {
+    let (sender, receiver) = async_channel::unbounded();
+    let effect = commands.spawn_empty().id();
+    let closure = move |(data,): (Option<T>,), world: &mut World| {
+        if sender.try_send(data?).is_err() {
+            trace!("-stream of {} was dropped", entity_label(immutable, world));
+            world.commands().add(move |world: &mut World| {
+                if let Err(error) = despawn_with_policy(effect, DependentPolicy::Error, world) {
+                    error!("could not despawn stream effect {:?}: {}", effect, error);
+                }
+            });
+        }
+        None
+    };
+    commands.create_effect::<(Option<T>,)>(
+        effect,
+        make_effect_with(closure),
+        vec![immutable],
+        vec![]
+    );
+    commands.entity(effect).insert(InitialEvaluation(false));
+    receiver
+}
+
+/// Create a `Signal` with no value that is sent each item of the `Stream`. The stream is polled on
+/// the `AsyncComputeTaskPool` until it ends.
+pub fn from_stream<T: LazySignalsData>(
+    stream: impl Stream<Item = T> + Send + 'static,
+    commands: &mut Commands
+) -> Entity {
+    let signal = commands.spawn_empty().id();
+    commands.create_empty_state::<T>(signal);
+
+    let (sender, receiver) = async_channel::unbounded();
+    AsyncComputeTaskPool::get_or_init(TaskPool::default)
+        .spawn(async move {
+            let mut stream = Box::pin(stream);
+            while let Some(item) = stream.next().await {
+                if sender.send(item).await.is_err() {
+                    break;
+                }
+            }
+        })
+        .detach();
+
+    commands.entity(signal).insert(StreamSource { drain: Box::new(drain_into::<T>(receiver)) });
+    signal
+}
+
+// send whatever arrived, returning false once the stream has ended
+fn drain_into<T: LazySignalsData>(
+    receiver: Receiver<T>
+) -> impl FnMut(Entity, &mut World) -> bool {
+    move |signal, world| {
+        loop {
+            match receiver.try_recv() {
+                Ok(data) => SendSignalCommand { signal, data }.apply(world),
+                Err(TryRecvError::Empty) => {
+                    return true;
+                }
+                Err(TryRecvError::Closed) => {
+                    return false;
+                }
+            }
+        }
+    }
+}
+
+// send the items that arrived from each stream since the last frame
+pub fn drain_streams(
+    world: &mut World,
+    query_streams: &mut QueryState<Entity, With<StreamSource>>
+) {
+    let signals: Vec<Entity> = query_streams.iter(world).collect();
+    for signal in signals {
+        let Some(mut source) = world.get_entity_mut(signal).and_then(|mut entity| {
+            entity.take::<StreamSource>()
+        }) else {
+            continue;
+        };
+        if (source.drain)(signal, world) {
+            world.entity_mut(signal).insert(source);
+        } else {
+            trace!("-stream feeding {} ended", entity_label(signal, world));
+        }
+    }
+}
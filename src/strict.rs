@@ -0,0 +1,155 @@
+use std::{
+    alloc::{ GlobalAlloc, Layout, System },
+    cell::Cell,
+    sync::atomic::{ AtomicBool, AtomicU64, Ordering },
+};
+
+use bevy::prelude::*;
+
+use crate::{ framework::*, systems::{ effect::check_tasks, payload::clear_payloads } };
+
+/// ## Strict Mode
+/// Global allocator that counts the allocations made by the propagation systems, so
+/// `LazySignalsStrictMode` can tell whether the pass allocated. Each system counts only while its
+/// own body runs, on whichever thread the executor picked, so neither the executor's bookkeeping
+/// nor systems running in parallel are included. It only counts and hands everything to `System`.
+/// Install it in the application (a library cannot do that for you):
+///
+/// `#[global_allocator] static ALLOCATOR: CountingAllocator = CountingAllocator;`
+pub struct CountingAllocator;
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+// allocations made inside a `CountedSection` on any thread
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    // const and without a destructor, so reading it never allocates
+    static COUNTING: Cell<bool> = const { Cell::new(false) };
+}
+
+fn count_allocation() {
+    if !INSTALLED.load(Ordering::Relaxed) {
+        INSTALLED.store(true, Ordering::Relaxed);
+    }
+    if COUNTING.try_with(|counting| counting.get()).unwrap_or(false) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count_allocation();
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count_allocation();
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count_allocation();
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+/// The number of allocations made inside a `CountedSection` so far, or `None` if
+/// `CountingAllocator` is not the global allocator.
+pub fn counted_allocations() -> Option<u64> {
+    match INSTALLED.load(Ordering::Relaxed) {
+        true => Some(ALLOCATIONS.load(Ordering::Relaxed)),
+        false => None,
+    }
+}
+
+/// While a `CountedSection` is alive, `CountingAllocator` counts the allocations made on the thread
+/// that opened it. Each propagation system opens one for the length of its body.
+pub struct CountedSection {
+    was_counting: bool,
+}
+
+impl CountedSection {
+    pub fn open() -> Self {
+        let was_counting = COUNTING.try_with(|counting| counting.replace(true)).unwrap_or(false);
+        Self { was_counting }
+    }
+}
+
+impl Drop for CountedSection {
+    fn drop(&mut self) {
+        let _ = COUNTING.try_with(|counting| counting.set(self.was_counting));
+    }
+}
+
+/// What `LazySignalsStrictModePlugin` found about the propagation pass.
+#[derive(Resource, Default, Debug)]
+pub struct LazySignalsStrictMode {
+    /// Heap allocations made during the last propagation pass, or `None` if they are not being
+    /// counted (see `CountingAllocator`).
+    pub last_pass_allocations: Option<u64>,
+
+    /// The number of passes that allocated.
+    pub violations: u64,
+
+    pass_start: Option<u64>,
+}
+
+/// `Plugin` for soft real-time targets (consoles, embedded) that cannot afford to allocate on the
+/// hot path. Before each propagation pass it sizes the `LazySignalsBuffers` for every entity and
+/// component in the world, so the pass reuses them instead of allocating as the graph grows, and
+/// with `CountingAllocator` installed it checks that the pass made no heap allocations at all,
+/// logging a warning in debug builds when it did.
+///
+/// The guarantee covers the pass once the graph is built: sending, marking subscribers, and running
+/// computeds and effects whose sources hold data that does not allocate to clone (numbers, `bool`,
+/// `Entity`, `&'static str`, small `Copy` structs). Building the graph (spawning primitives,
+/// changing sources) may allocate, and so may the closures themselves, e.g. by cloning a `String`
+/// or an effect returning a system to run.
+pub struct LazySignalsStrictModePlugin;
+
+impl Plugin for LazySignalsStrictModePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LazySignalsStrictMode>().add_systems(
+            PreUpdate,
+            (
+                begin_strict_pass.in_set(crate::LazySignalsSystemSet).before(check_tasks),
+                end_strict_pass.in_set(crate::LazySignalsSystemSet).after(clear_payloads),
+            )
+        );
+        if counted_allocations().is_none() {
+            warn!("strict mode cannot count allocations without CountingAllocator installed");
+        }
+    }
+}
+
+// size the buffers for the graph as it is now, then start counting
+pub fn begin_strict_pass(world: &mut World) {
+    let entities = world.entities().total_count();
+    let mut buffers = take_buffers(world);
+    buffers.reserve(entities, world.components());
+    return_buffers(buffers, world);
+    let start = counted_allocations();
+    if let Some(mut strict) = world.get_resource_mut::<LazySignalsStrictMode>() {
+        strict.pass_start = start;
+    }
+}
+
+// stop counting and report the pass if it allocated
+pub fn end_strict_pass(world: &mut World) {
+    let end = counted_allocations();
+    let Some(mut strict) = world.get_resource_mut::<LazySignalsStrictMode>() else {
+        return;
+    };
+    let allocations = strict.pass_start.take().zip(end).map(|(start, end)| end - start);
+    strict.last_pass_allocations = allocations;
+    if allocations.is_some_and(|allocations| allocations > 0) {
+        strict.violations += 1;
+        #[cfg(debug_assertions)]
+        warn!("signal propagation made {} heap allocation(s)", allocations.unwrap());
+    }
+}
@@ -1,51 +1,70 @@
-use bevy::{ ecs::world::World, prelude::*, reflect::DynamicTuple };
+use bevy::{ ecs::world::World, prelude::*, reflect::TypeRegistry, utils::Instant };
 
-use crate::{ arcane_wizardry::*, framework::* };
+use crate::{
+    arcane_wizardry::*,
+    context::in_active_context,
+    framework::*,
+    stats::{ check_budget, warn_wasted_recompute, LazySignalsStats },
+    strict::CountedSection,
+};
 
 // recompute all the dirty computeds
 pub fn compute_memos(
     world: &mut World,
     query_memos: &mut QueryState<(Entity, &ImmutableState, &ComputedImmutable), With<ComputeMemo>>
 ) {
+    let _counted = CountedSection::open();
     trace!("MEMOS");
 
-    let mut component_id_set = ComponentIdSet::new();
-    let mut component_info_set = ComponentInfoSet::new();
-    let mut processed = empty_set();
-    let mut sources = EntityRelationshipSet::new();
-    let mut stack = Vec::<Entity>::new();
+    let mut buffers = take_buffers(world);
 
     query_memos.iter(world).for_each(|(entity, immutable, computed)| {
+        if !in_active_context(entity, world) {
+            return;
+        }
         let component_id = immutable.component_id;
         trace!("-found computed {:#?} with component ID {:?}", entity, component_id);
-        component_id_set.insert(entity, component_id);
+        buffers.component_ids.insert(entity, component_id);
         if let Some(info) = world.components().get_info(component_id) {
-            component_info_set.insert(component_id, info.clone());
+            buffers.component_infos.insert(component_id, info.clone());
         }
 
-        sources.insert(entity, computed.sources.clone());
+        buffers.relate(entity, computed.sources.iter().copied());
 
         // doesn't matter what order we evaluate things in since it all has to get resolved
 
         // the value of each computed memo is deterministic since the data is immutable
-        stack.push(entity);
+        buffers.stack.push(entity);
     });
 
+    let LazySignalsBuffers {
+        component_ids: component_id_set,
+        component_infos: component_info_set,
+        processed,
+        dirty: dirty_sources,
+        stack,
+        relationships: memo_sources,
+        related,
+        ..
+    } = &mut buffers;
+
     // main loop: evaluate highest index (pop the stack)
     while let Some(computed) = stack.pop() {
-        trace!("COMPUTED {:?}", computed);
+        trace!("COMPUTED {}", entity_label(computed, world));
         // do not run this Computed if already in the processed set
         if processed.contains(computed) {
             trace!("-skipping");
             continue;
         }
 
-        let sources = sources.get(computed).unwrap();
-        let mut dirty_sources = Vec::<Entity>::new();
+        let sources = &related[memo_sources.get(computed).unwrap().clone()];
         for source in sources {
             trace!("-checking source for dirt: {:?}", source);
             let source = *source;
-            if world.entity(source).contains::<Dirty>() {
+            // only wait for sources that will be recomputed in this pass (not ones that are
+            // paused or in another context), so each one runs before this, and this runs once
+            let pending = memo_sources.contains(source) && !processed.contains(source);
+            if pending && world.get::<Dirty>(source).is_some() {
                 trace!("- - - durrrrty - - -");
                 dirty_sources.push(source);
             }
@@ -55,7 +74,25 @@ pub fn compute_memos(
         if !dirty_sources.is_empty() {
             trace!("-pushing on the stack");
             stack.push(computed);
-            stack.append(&mut dirty_sources);
+            stack.append(dirty_sources);
+        } else if let Some(missing) = sources.iter().find(|s| world.get_entity(**s).is_none()) {
+            // a source was despawned out from under it, so keep whatever error it was left with
+            trace!("-source {:?} is gone, not recomputing", missing);
+            world.entity_mut(computed).remove::<(ComputeMemo, Dirty)>();
+            processed.insert(computed, ());
+        } else if sources_unchanged(computed, sources, world) {
+            // marked without any source getting a new value (e.g. a trigger), so nothing to do
+            trace!("-sources unchanged, not recomputing");
+            world.resource_scope(|world, type_registry: Mut<AppTypeRegistry>| {
+                let type_registry = type_registry.read();
+
+                // still subscribe so it is notified next time
+                for source in sources.iter() {
+                    subscribe(&computed, source, &type_registry, world);
+                }
+            });
+            world.entity_mut(computed).remove::<(ComputeMemo, Dirty)>();
+            processed.insert(computed, ());
         } else {
             // otherwise, if all sources are up to date, then recompute
             trace!("***COMPUTE***");
@@ -73,12 +110,15 @@ pub fn compute_memos(
             // remove the ComputeMemo component
             world.entity_mut(computed).remove::<ComputeMemo>();
 
+            // a tracked computed reads its own sources and subscribes to them afterward
+            let tracked = world.entity(computed).contains::<TrackedSources>();
+
             world.resource_scope(|world, type_registry: Mut<AppTypeRegistry>| {
                 let type_registry = type_registry.read();
 
-                // prepare the args
-                let mut args = DynamicTuple::default();
-                for source in sources.iter() {
+                // prepare the args, reusing the ones from the last run
+                let mut args = take_source_args(computed, sources.len(), world);
+                for (index, source) in sources.iter().filter(|_| !tracked).enumerate() {
                     trace!("Processing source {:?}", source);
                     let component_id = component_id_set.get(*source).unwrap();
                     let type_id = component_info_set.get(*component_id).unwrap().type_id().unwrap();
@@ -86,6 +126,7 @@ pub fn compute_memos(
                     // call the copy_data method via reflection
                     // this will append the source data to the args tuple
                     // FIXME indicate an error if the args don't line up?
+                    let passive = is_passive(computed, *source, world);
                     if let Some(mut source) = world.get_entity_mut(*source) {
                         // insert arcane wizardry here
                         run_as_observable(
@@ -95,10 +136,15 @@ pub fn compute_memos(
                             component_id,
                             &type_id,
                             &type_registry,
-                            Box::new(|observable, args, target| {
-                                observable.copy_data(*target.unwrap(), args.unwrap());
+                            move |observable, args, target| {
+                                observable.copy_data(*target.unwrap(), args.unwrap(), index);
+
+                                // reading the data subscribes, which a passive source must not do
+                                if passive {
+                                    observable.unsubscribe(*target.unwrap());
+                                }
                                 None
-                            })
+                            }
                         );
                     }
 
@@ -106,8 +152,17 @@ pub fn compute_memos(
                     subscribe(&computed, source, &type_registry, world);
                 }
 
+                // remember the old error so a rewrapped one is not reported as a change
+                let previous_error = with_observable_in(
+                    computed,
+                    world,
+                    &type_registry,
+                    |observable, _| observable.error_reflect()
+                ).flatten();
+
                 let mut changed = false;
                 let mut clean = false;
+                let started = Instant::now();
 
                 // actually compute the computed
                 {
@@ -138,12 +193,77 @@ pub fn compute_memos(
                     }
                 }
 
+                if clean {
+                    let elapsed = started.elapsed();
+                    let wasted = world
+                        .get_resource_mut::<LazySignalsStats>()
+                        .is_some_and(|mut stats| {
+                            stats.record_recompute(computed, elapsed, changed)
+                        });
+                    check_budget(computed, elapsed, world);
+                    if wasted {
+                        warn_wasted_recompute(computed, world);
+                    }
+                }
+
+                return_source_args(computed, args, world);
+
+                let mut tracked_sources = None;
+                if tracked {
+                    let mut handle = world.entity_mut(computed);
+                    let sources = handle.get::<TrackedSources>().unwrap().sources.clone();
+                    handle.get_mut::<ComputedImmutable>().unwrap().sources = sources.clone();
+                    for source in sources.iter() {
+                        subscribe(&computed, source, &type_registry, world);
+                    }
+                    if let Some(mut index) = world.get_resource_mut::<LazySignalsDependents>() {
+                        for source in sources.iter() {
+                            index.insert(*source, computed);
+                        }
+                    }
+                    tracked_sources = Some(sources);
+                }
+                let current_sources = tracked_sources.as_deref().unwrap_or(sources);
+
+                // remember the versions it ran with, to skip it until one of them changes
+                if clean {
+                    record_versions(computed, current_sources, world, &type_registry);
+                }
+
+                // if the computed failed while a source holds an error, wrap the source error so
+                // the failure can be traced back to the signal that originated it
+                let failed = with_observable_in(computed, world, &type_registry, |observable, _| {
+                    observable.error_reflect().is_some()
+                });
+                if clean && failed == Some(true) {
+                    let upstream = current_sources.iter().find_map(|source| {
+                        with_observable_in(*source, world, &type_registry, |observable, _| {
+                            observable.error_reflect()
+                        })
+                            .flatten()
+                            .map(|error| (*source, error))
+                    });
+                    if let Some((at, caused_by)) = upstream {
+                        let error = with_observable_in(
+                            computed,
+                            world,
+                            &type_registry,
+                            |observable, _| {
+                                observable.chain_error(at, caused_by);
+                                observable.error_reflect()
+                            }
+                        ).flatten();
+                        changed = error != previous_error;
+                    }
+                }
+
                 if changed || clean {
                     let mut handle = world.entity_mut(computed);
 
                     if changed {
                         trace!("-marking changed");
                         handle.insert(ValueChanged);
+                        mark_changed(&mut handle);
                     }
 
                     if clean {
@@ -151,7 +271,64 @@ pub fn compute_memos(
                         handle.remove::<Dirty>();
                     }
                 }
+
+                if changed {
+                    let handle = world.entity(computed);
+                    let type_id = handle.get::<ComputedImmutable>().unwrap().result_type;
+                    emit_changed(computed, type_id, world);
+
+                    // write-through memos copy the new value straight into their target
+                    let write = world.get::<WriteThrough>(computed).map(|w| w.write.clone());
+                    if let Some(write) = write {
+                        write(computed, world);
+                    }
+                }
             });
         }
     }
+
+    return_buffers(buffers, world);
+}
+
+// whether every source still holds the version of its value the computed last ran with
+fn sources_unchanged(computed: Entity, sources: &[Entity], world: &World) -> bool {
+    let Some(last) = world.get::<SourceVersions>(computed) else {
+        return false;
+    };
+    if last.versions.len() != sources.len() {
+        return false;
+    }
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    last.versions.iter().zip(sources).all(|((source, version), current)| {
+        source == current && state_version(*source, world, &type_registry) == Some(*version)
+    })
+}
+
+// overwrite the versions the computed ran with in place, so it only allocates the first time
+fn record_versions(
+    computed: Entity,
+    sources: &[Entity],
+    world: &mut World,
+    type_registry: &TypeRegistry
+) {
+    let mut versions = world
+        .get_mut::<SourceVersions>(computed)
+        .map(|mut last| std::mem::take(&mut last.versions))
+        .unwrap_or_default();
+    versions.clear();
+    versions.extend(
+        sources
+            .iter()
+            .filter_map(|source| {
+                state_version(*source, world, type_registry).map(|version| (*source, version))
+            })
+    );
+    match world.get_mut::<SourceVersions>(computed) {
+        Some(mut last) => {
+            last.versions = versions;
+        }
+        None => {
+            world.entity_mut(computed).insert(SourceVersions { versions });
+        }
+    }
 }
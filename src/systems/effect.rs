@@ -2,16 +2,35 @@ use bevy::{
     ecs::{ system::BoxedSystem, world::CommandQueue },
     prelude::*,
     reflect::DynamicTuple,
-    tasks::{ block_on, futures_lite::future, Task },
+    tasks::{ block_on, futures_lite::future, ComputeTaskPool, Task, TaskPool },
+    utils::Instant,
 };
 
-use crate::{ arcane_wizardry::*, framework::* };
+use std::sync::Arc;
+
+use crate::{
+    arcane_wizardry::*,
+    context::{ in_active_context, ActiveSignalContext, SignalContext },
+    framework::*,
+    processing::in_active_class,
+    stats::{ check_budget, LazySignalsStats },
+    strict::CountedSection,
+};
 
 type DeferredEffectsParam = (With<DeferredEffect>, Without<RunningTask>);
 
 // get all the currently running tasks
-pub fn check_tasks(mut running_tasks: Query<(Entity, &mut RunningTask)>, mut commands: Commands) {
-    for (entity, mut running) in running_tasks.iter_mut() {
+pub fn check_tasks(
+    mut running_tasks: Query<(Entity, &mut RunningTask, Option<&SignalContext>)>,
+    active_context: Option<Res<ActiveSignalContext>>,
+    mut commands: Commands
+) {
+    let _counted = CountedSection::open();
+    let active_context = active_context.map(|context| *context).unwrap_or_default();
+    for (entity, mut running, context) in running_tasks.iter_mut() {
+        if !active_context.contains(context) {
+            continue;
+        }
         if let Some(mut commands_queue) = block_on(future::poll_once(&mut running.task)) {
             // append the returned command queue to have it execute later
             commands.append(&mut commands_queue);
@@ -29,39 +48,52 @@ pub fn apply_deferred_effects(
     query_changed: &mut QueryState<(Entity,), With<ValueChanged>>,
     query_effects: &mut QueryState<(Entity, &LazyEffect, Option<&Triggered>), DeferredEffectsParam>
 ) {
+    let _counted = CountedSection::open();
     trace!("EFFECTS");
 
+    let mut buffers = take_buffers(world);
+
     // build a set of changed Computeds and Signals
-    let mut changed = empty_set();
     query_changed.iter(world).for_each(|(entity,)| {
-        changed.insert(entity, ());
+        buffers.changed.insert(entity, ());
     });
 
     // store newly created Tasks here
     let mut new_tasks = Vec::<(Entity, Task<CommandQueue>)>::new();
 
+    // pooled effects run together once the args of all of them are ready
+    let mut pooled = Vec::<(Entity, Arc<dyn PooledEffectWrapper>, DynamicTuple)>::new();
+
     // collapse the query or get world concurrency errors
-    let mut relationships = EntityRelationshipSet::new();
-    let mut triggered = empty_set();
     query_effects.iter(world).for_each(|(entity, effect, triggered_effect)| {
+        if !in_active_context(entity, world) {
+            return;
+        }
         // only add the effect if it isn't already running
-        let mut deps = Vec::<Entity>::new();
-        deps.append(&mut effect.sources.clone());
-        deps.append(&mut effect.triggers.clone());
-        relationships.insert(entity, deps);
+        buffers.relate(entity, effect.sources.iter().chain(effect.triggers.iter()).copied());
         if triggered_effect.is_some() {
-            triggered.insert(entity, ());
+            buffers.triggered.insert(entity, ());
         }
     });
 
-    let mut effects = empty_set();
+    let LazySignalsBuffers {
+        changed,
+        component_ids: component_id_set,
+        component_infos: component_info_set,
+        running: effects,
+        triggered,
+        relationships,
+        related,
+        ..
+    } = &mut buffers;
 
     trace!("Processing effects {:#?}", relationships);
 
     // read, mostly
-    for (effect, sources) in relationships.iter() {
+    for (effect, range) in relationships.iter() {
         let effect = *effect;
-        trace!("Processing effect {:?}", effect);
+        let sources = &related[range.clone()];
+        trace!("Processing effect {}", entity_label(effect, world));
 
         // only run an effect if at least one of its sources is in the changed set
         // OR it has been explicitly triggered
@@ -79,6 +111,16 @@ pub fn apply_deferred_effects(
             }
         }
 
+        // an effect of another processing class is marked to run during the pass of its class,
+        // by which time the changed set will be gone. It keeps its DeferredEffect until then, which
+        // flush and process_subgraph know not to wait for
+        if !in_active_class(effect, world) {
+            if actually_run {
+                world.entity_mut(effect).insert(Triggered);
+            }
+            continue;
+        }
+
         let mut entity = world.entity_mut(effect);
         if actually_run {
             effects.insert(effect, ());
@@ -102,12 +144,23 @@ pub fn apply_deferred_effects(
 
     // write
     for effect in effects.indices() {
-        let sources = relationships.get(effect).map_or(Vec::<Entity>::new(), |s| s.to_vec());
+        let sources = relationships.get(effect).map_or(&[][..], |range| &related[range.clone()]);
         trace!("-found effect with sources {:#?}", sources);
 
-        // add the source component ID to the set (probably could be optimized)
-        let mut component_id_set = ComponentIdSet::new();
-        let mut component_info_set = ComponentInfoSet::new();
+        // the args can't line up if a source was despawned out from under the effect
+        if let Some(missing) = sources.iter().find(|source| world.get_entity(**source).is_none()) {
+            warn!("not running {}, source {:?} is gone", entity_label(effect, world), missing);
+            continue;
+        }
+
+        // an effect waiting for its sources is not armed until all of them have a value
+        if world.entity(effect).contains::<WaitForSources>() {
+            let type_registry = world.resource::<AppTypeRegistry>().read();
+            if !is_ready(effect, world, &type_registry) {
+                trace!("-not running {}, waiting for sources", entity_label(effect, world));
+                continue;
+            }
+        }
 
         // build component id -> info map
         for source in sources.iter() {
@@ -123,9 +176,9 @@ pub fn apply_deferred_effects(
         world.resource_scope(|world, type_registry: Mut<AppTypeRegistry>| {
             let type_registry = type_registry.read();
 
-            // prepare the args
-            let mut args = DynamicTuple::default();
-            for source in sources.iter() {
+            // prepare the args, reusing the ones from the last run
+            let mut args = take_source_args(effect, sources.len(), world);
+            for (index, source) in sources.iter().enumerate() {
                 let component_id = component_id_set.get(*source).unwrap();
                 let type_id = component_info_set.get(*component_id).unwrap().type_id().unwrap();
 
@@ -141,10 +194,10 @@ pub fn apply_deferred_effects(
                         component_id,
                         &type_id,
                         &type_registry,
-                        Box::new(|observable, args, target| {
-                            observable.copy_data(*target.unwrap(), args.unwrap());
+                        |observable, args, target| {
+                            observable.copy_data(*target.unwrap(), args.unwrap(), index);
                             None
-                        })
+                        }
                     );
                 }
             }
@@ -152,6 +205,8 @@ pub fn apply_deferred_effects(
             // actually run the effect
             let mut effect_system = Option::<BoxedSystem>::None;
             let mut new_task = false;
+            let mut pooled_function = None;
+            let started = Instant::now();
 
             // drop the UnsafeWorldCell after this block so we can access the real world again
             {
@@ -172,6 +227,9 @@ pub fn apply_deferred_effects(
                                 trace!("Running task {:?}", effect);
                                 new_task = true;
                             }
+                            EffectContext::Pooled(function) => {
+                                pooled_function = Some(function.clone());
+                            }
                         }
                     }
                 }
@@ -190,6 +248,19 @@ pub fn apply_deferred_effects(
                 }
             }
 
+            // a pooled effect is timed on the pool instead
+            if let Some(function) = pooled_function {
+                pooled.push((effect, function, args));
+                return;
+            }
+            return_source_args(effect, args, world);
+
+            let elapsed = started.elapsed();
+            if let Some(mut stats) = world.get_resource_mut::<LazySignalsStats>() {
+                stats.record_run(effect, elapsed);
+            }
+            check_budget(effect, elapsed, world);
+
             // run the effect system
             if let Some(effect_system) = effect_system {
                 // FIXME this seems horribly inefficient
@@ -197,15 +268,41 @@ pub fn apply_deferred_effects(
                 let id = world.register_boxed_system(effect_system);
                 match world.run_system(id) {
                     Ok(_) => {}
-                    Err(_) => error!("error running effect system"),
+                    Err(_) => error!("error running system of effect {}", entity_label(effect, world)),
                 }
                 world.despawn(id.entity());
             }
         });
     }
 
+    // run the pooled effects in parallel, then apply what they queued in order
+    if !pooled.is_empty() {
+        trace!("Running {} pooled effects", pooled.len());
+        let results = ComputeTaskPool::get_or_init(TaskPool::default).scope(|scope| {
+            for (effect, function, args) in pooled.iter() {
+                scope.spawn(async move {
+                    let started = Instant::now();
+                    let queue = function(args);
+                    (*effect, queue, started.elapsed())
+                });
+            }
+        });
+        for (effect, mut queue, elapsed) in results {
+            if let Some(mut stats) = world.get_resource_mut::<LazySignalsStats>() {
+                stats.record_run(effect, elapsed);
+            }
+            check_budget(effect, elapsed, world);
+            queue.apply(world);
+        }
+        for (effect, _, args) in pooled {
+            return_source_args(effect, args, world);
+        }
+    }
+
     // mark the new tasks as running
     for task in new_tasks.drain(..) {
         world.entity_mut(task.0).insert(RunningTask { task: task.1 });
     }
+
+    return_buffers(buffers, world);
 }
@@ -1,50 +1,219 @@
-use bevy::{ ecs::world::World, prelude::* };
+use std::any::TypeId;
 
-use crate::{ arcane_wizardry::*, framework::* };
+use bevy::{ ecs::world::World, prelude::*, reflect::TypeInfo };
 
-type DerivedParam<'a> = (Entity, Option<&'a ComputedImmutable>, Option<&'a LazyEffect>);
+use crate::{
+    arcane_wizardry::*,
+    context::in_active_context,
+    framework::*,
+    strict::CountedSection,
+};
+
+type DerivedParam<'a> = (
+    Entity,
+    Option<&'a ComputedImmutable>,
+    Option<&'a LazyEffect>,
+    Option<&'a InitialEvaluation>,
+);
 // remove ValueChanged components
 pub fn init_lazy_signals(
     world: &mut World,
     query_deriveds: &mut QueryState<DerivedParam, With<InitDependencies>>,
+    query_retargeted: &mut QueryState<Entity, With<SourceChanges>>,
     query_value_changed: &mut QueryState<Entity, With<ValueChanged>>
 ) {
+    let _counted = CountedSection::open();
     // reset the internal change tracking
-    let mut changed = empty_set();
-    for entity in query_value_changed.iter_mut(world) {
-        changed.insert(entity, ());
+    let mut buffers = take_buffers(world);
+    for entity in query_value_changed.iter(world) {
+        if in_active_context(entity, world) {
+            buffers.changed.insert(entity, ());
+        }
     }
-    for (entity, _) in changed.iter() {
+    for (entity, _) in buffers.changed.iter() {
         world.entity_mut(*entity).remove::<ValueChanged>();
     }
+    return_buffers(buffers, world);
 
     // build the branches of the subscriber trees
     // FIXME should we actually just compute and trigger everything that is marked instead of faking it?
     let mut relationships = EntityRelationshipSet::new();
+    let mut params = Vec::<(Entity, Vec<(usize, Entity)>, &'static TypeInfo)>::new();
+    let mut evaluate = Vec::<(Entity, bool)>::new();
+
+    let initial_evaluation = world
+        .get_resource::<LazySignalsConfig>()
+        .is_some_and(|config| config.initial_evaluation);
 
-    query_deriveds.iter(world).for_each(|(entity, computed, effect)| {
+    query_deriveds.iter(world).for_each(|(entity, computed, effect, evaluation)| {
+        if !in_active_context(entity, world) {
+            return;
+        }
         let mut subs = Vec::<Entity>::new();
         if let Some(computed) = computed {
-            subs.append(&mut computed.sources.clone());
+            subs.extend(computed.sources.iter());
+            params.push((entity, positions(&computed.sources, |_| true), computed.params_type));
         }
         if let Some(effect) = effect {
-            subs.append(&mut effect.sources.clone());
-            subs.append(&mut effect.triggers.clone());
+            subs.extend(effect.sources.iter().chain(effect.triggers.iter()));
+            params.push((entity, positions(&effect.sources, |_| true), effect.params_type));
         }
         relationships.insert(entity, subs);
+
+        if evaluation.map_or(initial_evaluation, |evaluation| evaluation.0) {
+            evaluate.push((entity, computed.is_some()));
+        }
     });
 
+    // retargeted subscribers only need the edges that changed
+    let mut retargeted = Vec::<(Entity, SourceChanges)>::new();
+    for entity in query_retargeted.iter(world) {
+        if in_active_context(entity, world) {
+            retargeted.push((entity, SourceChanges::default()));
+        }
+    }
+    for (entity, changes) in retargeted.iter_mut() {
+        let mut entity_mut = world.entity_mut(*entity);
+        *changes = entity_mut.take::<SourceChanges>().unwrap();
+        let added = |source: &Entity| changes.added.contains(source);
+        if let Some(computed) = entity_mut.get::<ComputedImmutable>() {
+            params.push((*entity, positions(&computed.sources, added), computed.params_type));
+        } else if let Some(effect) = entity_mut.get::<LazyEffect>() {
+            params.push((*entity, positions(&effect.sources, added), effect.params_type));
+        }
+    }
+
+    // look up what is needed to reflect each source up front, so the type registry is only locked
+    // briefly (and only for new types) rather than for the whole pass
+    let mut sources = Vec::<Entity>::new();
+    for (_, subs) in relationships.iter() {
+        sources.extend(subs.iter());
+    }
+    for (_, changes) in retargeted.iter() {
+        sources.extend(changes.removed.iter().chain(changes.added.iter()));
+    }
+    cache_accessors(sources, world);
+
     // run the subscribe method on all sources and triggers
-    world.resource_scope(|world, type_registry: Mut<AppTypeRegistry>| {
-        let type_registry = type_registry.read();
-        for (entity, subs) in relationships.iter() {
-            // loop through the sources
+    for (entity, subs) in relationships.iter() {
+        // loop through the sources
+        for source in subs.iter() {
+            subscribe_cached(*entity, *source, world);
+        }
+        if let Some(mut dependents) = world.get_resource_mut::<LazySignalsDependents>() {
             for source in subs.iter() {
-                subscribe(entity, source, &type_registry, world);
+                dependents.insert(*source, *entity);
             }
+        }
 
-            // mark as processed
-            world.get_entity_mut(*entity).unwrap().remove::<InitDependencies>();
+        // mark as processed
+        world.get_entity_mut(*entity).unwrap().remove::<InitDependencies>();
+    }
+
+    for (entity, changes) in retargeted.iter() {
+        trace!("-retargeting {:?}: {:?}", entity, changes);
+        for source in changes.removed.iter() {
+            with_cached_observable(*source, world, |observable| {
+                observable.unsubscribe(*entity);
+            });
         }
-    });
+        for source in changes.added.iter() {
+            subscribe_cached(*entity, *source, world);
+        }
+        if let Some(mut dependents) = world.get_resource_mut::<LazySignalsDependents>() {
+            for source in changes.removed.iter() {
+                dependents.remove(*source, *entity);
+            }
+            for source in changes.added.iter() {
+                dependents.insert(*source, *entity);
+            }
+        }
+    }
+
+    // make sure each source holds the type its subscriber expects
+    for (subscriber, sources, params_type) in params {
+        check_source_types(subscriber, &sources, params_type, world);
+    }
+
+    // schedule the initial evaluation so values are ready without waiting for a signal
+    for (entity, is_computed) in evaluate {
+        trace!("-scheduling initial evaluation of {:?}", entity);
+        let mut entity = world.entity_mut(entity);
+        if is_computed {
+            entity.insert((ComputeMemo, Dirty));
+        } else {
+            entity.insert((DeferredEffect, Triggered));
+        }
+    }
+}
+
+// the sources to check along with their index in the args
+fn positions(sources: &[Entity], filter: impl Fn(&Entity) -> bool) -> Vec<(usize, Entity)> {
+    sources
+        .iter()
+        .enumerate()
+        .filter(|(_, source)| filter(source))
+        .map(|(index, source)| (index, *source))
+        .collect()
+}
+
+// report each source whose data type does not match the element of the args it is passed as
+fn check_source_types(
+    subscriber: Entity,
+    sources: &[(usize, Entity)],
+    params_type: &'static TypeInfo,
+    world: &mut World
+) {
+    for (index, source) in sources.iter() {
+        let mismatch = source_type_mismatch(subscriber, *index, *source, params_type, world);
+        if let Some((expected, found)) = mismatch {
+            world.send_event(SourceTypeMismatch {
+                subscriber,
+                source: *source,
+                index: *index,
+                expected,
+                found,
+            });
+        }
+    }
+}
+
+/// The type the element of the args at `index` expects and the one the source holds, if they
+/// differ. Sources whose accessor is not cached (see `cache_accessors`) are not checked.
+pub(crate) fn source_type_mismatch(
+    subscriber: Entity,
+    index: usize,
+    source: Entity,
+    params_type: &'static TypeInfo,
+    world: &World
+) -> Option<(&'static str, &'static str)> {
+    let unwrapped = world.get::<UnwrappedArgs>(subscriber).is_some();
+
+    // each element of the args is an `Option<T>`, or a `T` if the args are unwrapped
+    let (expected_id, expected): (TypeId, &'static str) = match params_type {
+        TypeInfo::Tuple(info) => info.field_at(index).map(|f| (f.type_id(), f.type_path())),
+        TypeInfo::Struct(info) => info.field_at(index).map(|f| (f.type_id(), f.type_path())),
+        _ => None,
+    }?;
+
+    // ...and so is the initial value of the source's `LazySignalsState<T>`
+    let (found_id, found) = cached_accessor(source, world).and_then(|(_, accessor)| {
+        match unwrapped {
+            true => accessor.data_type().map(|info| (info.type_id(), info.type_path())),
+            false => accessor.param_type(),
+        }
+    })?;
+
+    if expected_id == found_id {
+        return None;
+    }
+    error!(
+        "{} expects {} from source {} (args index {}) but the source holds {}",
+        entity_label(subscriber, world),
+        expected,
+        entity_label(source, world),
+        index,
+        found
+    );
+    Some((expected, found))
 }
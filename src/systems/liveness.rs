@@ -0,0 +1,41 @@
+use bevy::{ ecs::entity::Entities, prelude::* };
+
+use crate::{
+    context::{ ActiveSignalContext, SignalContext },
+    framework::*,
+    lazy_immutable::{ LazySignalsImmutable, LazySignalsState },
+    strict::CountedSection,
+};
+
+// send None (or an error) to each tracked Entity signal whose entity has been despawned
+pub fn check_liveness(
+    mut query_tracked: Query<
+        (Entity, &mut LazySignalsState<Entity>, &TrackLiveness, Option<&SignalContext>)
+    >,
+    active_context: Option<Res<ActiveSignalContext>>,
+    entities: &Entities,
+    mut commands: Commands
+) {
+    let _counted = CountedSection::open();
+    let active_context = active_context.map(|context| *context).unwrap_or_default();
+    for (signal, mut state, liveness, context) in query_tracked.iter_mut() {
+        if !active_context.contains(context) {
+            continue;
+        }
+        if let Some(target) = state.get() {
+            if entities.contains(target) {
+                continue;
+            }
+
+            trace!("-entity {:?} held by signal {:?} was despawned", target, signal);
+            let error = match liveness.policy {
+                LivenessPolicy::Clear => None,
+                LivenessPolicy::Error => Some(LazySignalsError::EntityDespawned(target)),
+            };
+            state
+                .bypass_change_detection()
+                .merge_next(LazySignalsResult { data: None, error }, false);
+            commands.entity(signal).insert(SendSignal);
+        }
+    }
+}
@@ -2,4 +2,6 @@
 pub mod computed;
 pub mod effect;
 pub mod init;
+pub mod liveness;
+pub mod payload;
 pub mod signal;
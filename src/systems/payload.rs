@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+
+use crate::{
+    arcane_wizardry::with_observable,
+    context::in_active_context,
+    framework::*,
+    strict::CountedSection,
+};
+
+// forget the payload of each trigger that fired, now that its subscribers have seen it
+pub fn clear_payloads(
+    world: &mut World,
+    query_fired: &mut QueryState<Entity, (With<TransientPayload>, With<ValueChanged>)>
+) {
+    let _counted = CountedSection::open();
+    let mut buffers = take_buffers(world);
+    buffers.stack.extend(
+        query_fired.iter(world).filter(|trigger| in_active_context(*trigger, world))
+    );
+    for trigger in buffers.stack.iter() {
+        trace!("-clearing payload of {:?}", trigger);
+        with_observable(*trigger, world, |observable, _| observable.clear());
+    }
+    return_buffers(buffers, world);
+}
@@ -1,6 +1,11 @@
 use bevy::{ ecs::world::World, prelude::* };
 
-use crate::{ arcane_wizardry::*, framework::* };
+use crate::{
+    arcane_wizardry::*,
+    context::in_active_context,
+    framework::*,
+    strict::CountedSection,
+};
 
 // add subscribers to the next running set
 fn add_subs_to_running(
@@ -12,10 +17,12 @@ fn add_subs_to_running(
 ) {
     for subscriber in subs.iter() {
         if changed || triggered {
-            trace!("-adding subscriber {:?} to running set", subscriber);
-            let subscriber = *subscriber;
-            next_running.insert(subscriber, ());
-            let mut subscriber = world.entity_mut(subscriber);
+            trace!("-adding subscriber {} to running set", entity_label(*subscriber, world));
+            // a subscriber that has been despawned simply drops out of the subscriber set
+            let Some(mut subscriber) = world.get_entity_mut(*subscriber) else {
+                continue;
+            };
+            next_running.insert(subscriber.id(), ());
             subscriber.insert(Dirty);
 
             // add Triggered to Effects only
@@ -45,23 +52,32 @@ pub fn send_signals(
     world: &mut World,
     query_signals: &mut QueryState<(Entity, &ImmutableState), With<SendSignal>>
 ) {
+    let _counted = CountedSection::open();
     trace!("SIGNALS");
 
-    let mut changed = empty_set();
-    let mut next_running = empty_set();
-    let mut processed = empty_set();
-    let mut running = empty_set();
-    let mut triggered = empty_set();
+    // reuse the sets from the last pass rather than allocating new ones
+    let mut buffers = take_buffers(world);
+    let LazySignalsBuffers {
+        changed,
+        component_ids: component_id_set,
+        component_infos: component_info_set,
+        next_running,
+        processed,
+        running,
+        subscribers: subs,
+        triggered,
+        ..
+    } = &mut buffers;
 
     // Phase One: find all the updated signals and schedule their direct subscribers to run
     trace!("looking for signals");
     let mut count = 0;
 
-    let mut component_id_set = ComponentIdSet::new();
-    let mut component_info_set = ComponentInfoSet::new();
-
     // build component id -> info map
     query_signals.iter(world).for_each(|(entity, immutable)| {
+        if !in_active_context(entity, world) {
+            return;
+        }
         let component_id = immutable.component_id;
         trace!("-found a signal with component ID {:#?}", component_id);
         component_id_set.insert(entity, component_id);
@@ -92,8 +108,9 @@ pub fn send_signals(
 
             // the component_id is saved when the command to make the concrete Signal runs
 
-            // merge the next data value and return a list of subscribers to the change
-            // and whether these subscribers should be triggered too
+            // merge the next data value and collect the subscribers to the change (into a list
+            // that is reused rather than a new one) and whether they should be triggered too
+            subs.clear();
             let result = run_as_observable(
                 &mut signal_to_send,
                 None,
@@ -101,10 +118,12 @@ pub fn send_signals(
                 &component_id,
                 &type_id,
                 &type_registry,
-                Box::new(|observable, _args, _target| { observable.merge() })
+                |observable, _args, _target| {
+                    let (changed, triggered) = observable.merge_into(subs);
+                    Some((Vec::new(), changed, triggered))
+                }
             ).unwrap();
 
-            let subs = result.0;
             let changed_flag = result.1;
             let triggered_flag = result.2;
 
@@ -121,18 +140,23 @@ pub fn send_signals(
 
             if changed_flag {
                 signal_to_send.insert(ValueChanged);
+                mark_changed(&mut signal_to_send);
+            }
+
+            if changed_flag {
+                emit_changed(entity, type_id, world);
             }
 
             // add subscribers to the running set and mark if triggered
             //info!("SUBS for {:#?} are: {:#?}", entity, subs);
-            add_subs_to_running(&subs, changed_flag, triggered_flag, &mut next_running, world);
+            add_subs_to_running(subs, changed_flag, triggered_flag, next_running, world);
         }
 
         // Phase Two: fire notifications up the subscriber tree
         let mut count = 0;
 
         // as long as there is a next_running set, move next_running set into the current one
-        while merge_running(&mut running, &mut next_running) {
+        while merge_running(running, next_running) {
             count += 1;
             trace!("Sending signals iteration {}", count);
 
@@ -158,25 +182,31 @@ pub fn send_signals(
                         trace!("--got component_id {:?} and type_id {:?}", component_id, type_id);
 
                         // get a list of subscribers
-                        let subs = run_as_observable(
+                        subs.clear();
+                        run_as_observable(
                             &mut subscriber,
                             None,
                             None,
                             &component_id,
                             &type_id,
                             &type_registry,
-                            Box::new(|observable, _args, _target| {
-                                Some((observable.get_subscribers(), false, false))
-                            })
+                            |observable, _args, _target| {
+                                observable.append_subscribers(subs);
+                                None
+                            }
                         );
 
                         // computed has its own subscribers, so add those to the next_running set
-                        // and mark triggered if appropriate
+                        // and mark triggered if appropriate: they may change once it recomputes,
+                        // and nothing else marks them, so a chain of computeds would stop here.
+                        // This does not make them run for nothing: a computed whose source
+                        // versions did not change is skipped, and effects still check for
+                        // ValueChanged before running
                         add_subs_to_running(
-                            &subs.unwrap().0,
-                            false,
+                            subs,
+                            true,
                             triggered.contains(runner),
-                            &mut next_running,
+                            next_running,
                             world
                         );
                     }
@@ -187,4 +217,6 @@ pub fn send_signals(
             running.clear();
         }
     });
+
+    return_buffers(buffers, world);
 }
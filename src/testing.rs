@@ -0,0 +1,154 @@
+use std::{ fmt::Debug, time::Duration };
+
+use bevy::prelude::*;
+
+use crate::{
+    api::LazySignals,
+    clock::{ now, LazySignalsClock },
+    framework::*,
+    namespace::NamespaceSnapshot,
+    stats::LazySignalsStats,
+    LazySignalsPlugin,
+};
+
+/// ## Test harness
+/// Headless `App` with `MinimalPlugins` and `LazySignalsPlugin` for exercising a signal graph in
+/// a unit or integration test. No window, renderer, or runner loop is involved: each `step()` runs
+/// the schedules exactly once.
+pub struct LazySignalsTestApp {
+    pub app: App,
+}
+
+impl Default for LazySignalsTestApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LazySignalsTestApp {
+    pub fn new() -> Self {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, LazySignalsPlugin));
+        Self { app }
+    }
+
+    /// Issue commands (e.g. create primitives or send signals) and apply them right away.
+    pub fn commands<R>(&mut self, closure: impl FnOnce(&mut Commands) -> R) -> R {
+        let world = self.world_mut();
+        let result = closure(&mut world.commands());
+        world.flush();
+        result
+    }
+
+    /// Process signals, memos, and effects until the graph settles, without running a full update.
+    #[track_caller]
+    pub fn flush(&mut self) -> &mut Self {
+        assert!(LazySignals.flush(self.world_mut()), "signal graph did not settle");
+        self
+    }
+
+    /// Run one full update of the app (one tick).
+    pub fn step(&mut self) -> &mut Self {
+        self.app.update();
+        self
+    }
+
+    /// Move the `LazySignalsClock` forward without waiting, inserting one at the current time if
+    /// there is none yet. Cooldowns and other timers see the new time during the next `step()`.
+    pub fn advance_clock(&mut self, by: Duration) -> &mut Self {
+        let world = self.world_mut();
+        let elapsed = now(world);
+        world.get_resource_or_insert_with(|| LazySignalsClock::new(elapsed)).advance(by);
+        self
+    }
+
+    /// Run `count` updates of the app.
+    pub fn step_n(&mut self, count: usize) -> &mut Self {
+        for _ in 0..count {
+            self.step();
+        }
+        self
+    }
+
+    /// Get the current value of a `Signal` or `Computed`.
+    pub fn read<T: LazySignalsData>(&self, entity: Entity) -> Option<T> {
+        LazySignals.read::<T>(entity, self.world())
+    }
+
+    /// Panic if the current value of the given entity does not equal the expected value.
+    #[track_caller]
+    pub fn assert_signal_eq<T: LazySignalsData + Debug>(&self, entity: Entity, expected: T) {
+        let actual = self.read::<T>(entity);
+        assert_eq!(actual, Some(expected), "unexpected value for signal {:?}", entity);
+    }
+
+    /// Panic if the given entity currently has a value.
+    #[track_caller]
+    pub fn assert_signal_none<T: LazySignalsData + Debug>(&self, entity: Entity) {
+        let actual = self.read::<T>(entity);
+        assert_eq!(actual, None, "expected no value for signal {:?}", entity);
+    }
+
+    /// The `Computed`s that recomputed during the last `step()` (and any `flush()` since), in the
+    /// order they ran.
+    pub fn recompute_order(&self) -> Vec<Entity> {
+        self.world()
+            .get_resource::<LazySignalsStats>()
+            .map(|stats| stats.recompute_order().to_vec())
+            .unwrap_or_default()
+    }
+
+    /// Panic unless the `Computed` recomputed exactly once during the last `step()`, after each of
+    /// the given computeds recomputed, e.g. the bottom of a diamond (C depends on A and B, which
+    /// both depend on the signal S) runs once after both sides when S changes.
+    #[track_caller]
+    pub fn assert_recomputed_once_after(&self, computed: Entity, after: &[Entity]) {
+        let order = self.recompute_order();
+        let world = self.world();
+        let listed: Vec<String> = order.iter().map(|entity| entity_label(*entity, world)).collect();
+        let runs: Vec<usize> = (0..order.len()).filter(|i| order[*i] == computed).collect();
+        assert_eq!(
+            runs.len(),
+            1,
+            "{} recomputed {} times, order was [{}]",
+            entity_label(computed, world),
+            runs.len(),
+            listed.join(", ")
+        );
+        for source in after {
+            let last = order.iter().rposition(|entity| entity == source);
+            assert!(
+                last.is_some_and(|last| last < runs[0]),
+                "{} did not recompute after {}, order was [{}]",
+                entity_label(computed, world),
+                entity_label(*source, world),
+                listed.join(", ")
+            );
+        }
+    }
+
+    /// Capture the values under a namespace prefix, to compare with `assert_changed` later.
+    pub fn snapshot(&mut self, prefix: &str) -> NamespaceSnapshot {
+        LazySignals.snapshot(prefix, self.world_mut())
+    }
+
+    /// Panic unless exactly the given paths changed under the prefix since the snapshot was taken,
+    /// listing every difference.
+    #[track_caller]
+    pub fn assert_changed(&mut self, before: &NamespaceSnapshot, prefix: &str, paths: &[&str]) {
+        let diffs = before.diff(&self.snapshot(prefix));
+        let changed: Vec<&str> = diffs.iter().map(|diff| diff.path()).collect();
+        let mut expected = paths.to_vec();
+        expected.sort();
+        let listed: Vec<String> = diffs.iter().map(|diff| diff.to_string()).collect();
+        assert_eq!(changed, expected, "unexpected changes:\n{}", listed.join("\n"));
+    }
+
+    pub fn world(&self) -> &World {
+        self.app.world()
+    }
+
+    pub fn world_mut(&mut self) -> &mut World {
+        self.app.world_mut()
+    }
+}
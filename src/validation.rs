@@ -0,0 +1,113 @@
+use bevy::prelude::*;
+
+use crate::framework::*;
+
+/// ## Graph Validation
+/// What graph validation found wrong with a `Computed` or `Effect`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GraphProblem {
+    /// None of its sources or triggers exist (or it has none), so nothing will ever run it.
+    NoLiveDependencies,
+
+    /// This source or trigger does not exist.
+    MissingDependency(Entity),
+
+    /// This source or trigger exists but is not a `Signal` or `Computed`, i.e. it has no
+    /// `ImmutableState`, so it will never notify anything.
+    NotASignal(Entity),
+}
+
+/// `Plugin` to check the signal graph once `Startup` is done, so a graph that was put together
+/// wrong fails right away with a report of every problem instead of silently doing nothing later.
+/// Meant for development builds.
+pub struct LazySignalsValidationPlugin {
+    /// Panic if there are problems, rather than only logging them as errors.
+    pub panic: bool,
+}
+
+impl Default for LazySignalsValidationPlugin {
+    fn default() -> Self {
+        Self { panic: true }
+    }
+}
+
+impl Plugin for LazySignalsValidationPlugin {
+    fn build(&self, app: &mut App) {
+        let panic = self.panic;
+        app.add_systems(PostStartup, move |world: &mut World| {
+            let problems = validate_graph(world);
+            if problems.is_empty() {
+                return;
+            }
+            let report = graph_report(&problems, world);
+            match panic {
+                true => panic!("{}", report),
+                false => error!("{}", report),
+            }
+        });
+    }
+}
+
+/// Check that every `Computed` and `Effect` has at least one source or trigger that exists, and
+/// that each source and trigger is a `Signal` or `Computed`. A tracked `Computed` is skipped, since
+/// it finds its sources the first time it runs.
+pub fn validate_graph(world: &mut World) -> Vec<(Entity, GraphProblem)> {
+    let mut dependencies = Vec::<(Entity, Vec<Entity>)>::new();
+    let mut query_computeds = world.query_filtered::<
+        (Entity, &ComputedImmutable),
+        Without<TrackedSources>
+    >();
+    for (entity, computed) in query_computeds.iter(world) {
+        dependencies.push((entity, computed.sources.clone()));
+    }
+    let mut query_effects = world.query::<(Entity, &LazyEffect)>();
+    for (entity, effect) in query_effects.iter(world) {
+        let mut deps = effect.sources.clone();
+        deps.extend(effect.triggers.iter());
+        dependencies.push((entity, deps));
+    }
+
+    let mut problems = Vec::new();
+    for (entity, deps) in dependencies {
+        let mut live = false;
+        for dependency in deps {
+            match world.get_entity(dependency) {
+                Some(dependency) if dependency.contains::<ImmutableState>() => {
+                    live = true;
+                }
+                Some(_) => {
+                    live = true;
+                    problems.push((entity, GraphProblem::NotASignal(dependency)));
+                }
+                None => problems.push((entity, GraphProblem::MissingDependency(dependency))),
+            }
+        }
+        if !live {
+            problems.push((entity, GraphProblem::NoLiveDependencies));
+        }
+    }
+    problems.sort_by_key(|(entity, _)| *entity);
+    problems
+}
+
+/// A readable report of the problems found by `validate_graph`, one per line.
+pub fn graph_report(problems: &[(Entity, GraphProblem)], world: &World) -> String {
+    let mut report = format!("signal graph validation found {} problem(s):", problems.len());
+    for (entity, problem) in problems {
+        let kind = match world.get::<ComputedImmutable>(*entity) {
+            Some(_) => "computed",
+            None => "effect",
+        };
+        let problem = match problem {
+            GraphProblem::NoLiveDependencies => "has no sources or triggers that exist".to_string(),
+            GraphProblem::MissingDependency(dependency) => {
+                format!("depends on {:?}, which does not exist", dependency)
+            }
+            GraphProblem::NotASignal(dependency) => {
+                format!("depends on {}, which is not a signal", entity_label(*dependency, world))
+            }
+        };
+        report.push_str(&format!("\n  {} {} {}", kind, entity_label(*entity, world), problem));
+    }
+    report
+}
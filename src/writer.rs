@@ -0,0 +1,57 @@
+use bevy::{ ecs::system::SystemParam, prelude::* };
+
+use crate::{ commands::merge_signal, framework::* };
+
+/// ## Signal Writers
+/// Queue of values sent through a `SignalWriter<T>`, drained into the signals once per frame before
+/// `LazySignalsSystemSet`. The queue keeps its capacity, so once it has grown to the usual number of
+/// sends per frame, sending does not allocate (unless there is send middleware).
+#[derive(Resource)]
+pub struct LazySignalsSendQueue<T: LazySignalsData + Copy> {
+    values: Vec<(Entity, T)>,
+}
+
+impl<T: LazySignalsData + Copy> Default for LazySignalsSendQueue<T> {
+    fn default() -> Self {
+        Self { values: Vec::new() }
+    }
+}
+
+impl<T: LazySignalsData + Copy> LazySignalsSendQueue<T> {
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+/// `SystemParam` to send signals of a `Copy` type without boxing a `Command` for each one. The type
+/// must be registered with `app.register_signal_writer::<T>()`.
+#[derive(SystemParam)]
+pub struct SignalWriter<'w, T: LazySignalsData + Copy> {
+    queue: ResMut<'w, LazySignalsSendQueue<T>>,
+}
+
+impl<'w, T: LazySignalsData + Copy> SignalWriter<'w, T> {
+    /// Send a signal to be applied during the next batch, same as `LazySignals.send`.
+    pub fn send(&mut self, signal: Entity, data: T) {
+        self.queue.values.push((signal, data));
+    }
+}
+
+// merge the queued values and mark their signals to be sent
+pub fn drain_send_queue<T: LazySignalsData + Copy>(world: &mut World) {
+    world.resource_scope(|world, mut queue: Mut<LazySignalsSendQueue<T>>| {
+        if queue.is_empty() {
+            return;
+        }
+        trace!("draining {} queued {}", queue.len(), T::type_path());
+        for (signal, data) in queue.values.drain(..) {
+            if merge_signal(signal, data, false, world) {
+                world.entity_mut(signal).insert(SendSignal);
+            }
+        }
+    });
+}
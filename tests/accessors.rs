@@ -0,0 +1,32 @@
+use bevy_lazy_signals::{ api::LazySignals, framework::*, testing::LazySignalsTestApp };
+
+#[test]
+fn accessors_are_cached_once_per_state_type() {
+    let mut t = LazySignalsTestApp::new();
+    let (count, labels) = t.commands(|commands| {
+        let count = LazySignals.state(0u32, commands);
+        let name = LazySignals.state("player".to_string(), commands);
+        let labels: Vec<_> = (0..200u32)
+            .map(|row| {
+                LazySignals.computed::<(Option<u32>, Option<String>), String>(
+                    move |(count, name)| {
+                        let name = name.unwrap_or_default();
+                        LazySignals::result(format!("{} {}: {}", name, row, count.unwrap_or(0)))
+                    },
+                    vec![count, name],
+                    commands
+                )
+            })
+            .collect();
+        (count, labels)
+    });
+    t.step();
+
+    // one entry for the u32 state and one for the String state, however many edges there are
+    assert_eq!(t.world().resource::<LazySignalsAccessors>().len(), 2);
+
+    t.commands(|commands| LazySignals.send(count, 3u32, commands));
+    t.step();
+    t.assert_signal_eq(labels[0], "player 0: 3".to_string());
+    t.assert_signal_eq(labels[199], "player 199: 3".to_string());
+}
@@ -0,0 +1,33 @@
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+#[test]
+fn consumers_of_an_alias_follow_it_when_it_is_repointed() {
+    let mut t = LazySignalsTestApp::new();
+    let (first, second, profile, volume_label) = t.commands(|commands| {
+        let first = LazySignals.state(5u32, commands);
+        let second = LazySignals.state(8u32, commands);
+        let profile = LazySignals.alias::<u32>(first, commands);
+        let volume_label = LazySignals.computed::<(Option<u32>,), String>(
+            |(volume,)| LazySignals::result(format!("volume {}", volume.unwrap_or(0))),
+            vec![profile],
+            commands
+        );
+        (first, second, profile, volume_label)
+    });
+    t.step();
+    t.assert_signal_eq(profile, 5u32);
+
+    t.commands(|commands| LazySignals.send(first, 6u32, commands));
+    t.step();
+    t.assert_signal_eq(volume_label, "volume 6".to_string());
+
+    // switching the profile only touches the alias
+    t.commands(|commands| LazySignals.repoint(profile, second, commands));
+    t.step();
+    t.assert_signal_eq(profile, 8u32);
+    t.assert_signal_eq(volume_label, "volume 8".to_string());
+
+    t.commands(|commands| LazySignals.send(first, 1u32, commands));
+    t.step();
+    t.assert_signal_eq(volume_label, "volume 8".to_string());
+}
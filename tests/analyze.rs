@@ -0,0 +1,43 @@
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+#[test]
+fn report_measures_depth_fan_out_and_reachability() {
+    let mut t = LazySignalsTestApp::new();
+    let (hub, leaf, lost_source, lost) = t.commands(|commands| {
+        let hub = LazySignals.state(1u32, commands);
+        let mut last = hub;
+        for _ in 0..3 {
+            last = LazySignals.computed::<(Option<u32>,), u32>(
+                |(value,)| LazySignals::result(value.unwrap_or(0) + 1),
+                vec![last],
+                commands
+            );
+        }
+        let leaf = LazySignals.effect::<(Option<u32>,)>(
+            |_, _world| None,
+            vec![last],
+            vec![],
+            commands
+        );
+        for _ in 0..4 {
+            LazySignals.effect::<(Option<u32>,)>(|_, _world| None, vec![hub], vec![], commands);
+        }
+        let lost_source = LazySignals.state(1u32, commands);
+        let lost = LazySignals.computed::<(Option<u32>,), u32>(
+            |(value,)| LazySignals::result(value.unwrap_or(0)),
+            vec![lost_source],
+            commands
+        );
+        (hub, leaf, lost_source, lost)
+    });
+    t.step();
+    t.world_mut().despawn(lost_source);
+
+    let report = LazySignals.analyze(t.world());
+    assert!(report.is_acyclic());
+    assert_eq!(report.max_depth, 4);
+    assert_eq!(report.deepest, Some(leaf));
+    assert_eq!(report.max_fan_out, 5);
+    assert_eq!(report.widest, Some(hub));
+    assert_eq!(report.unreachable, vec![lost]);
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "assets")]
+
+use bevy::{ ecs::event::ManualEventReader, prelude::* };
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    assets::LazySignalsAssetsExt,
+    testing::LazySignalsTestApp,
+};
+
+#[derive(Asset, TypePath)]
+struct Tint(f32);
+
+#[derive(Resource)]
+struct TintHandle(Handle<Tint>);
+
+#[derive(Resource)]
+struct Brightness(f32);
+
+#[test]
+fn effects_change_assets_with_change_detection() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.add_plugins(AssetPlugin::default()).init_asset::<Tint>();
+    let handle = t.world_mut().resource_mut::<Assets<Tint>>().add(Tint(0.0));
+    t.world_mut().insert_resource(TintHandle(handle.clone()));
+    t.world_mut().insert_resource(Brightness(0.5));
+
+    let alpha = t.commands(|commands| {
+        let alpha = LazySignals.state(0.0f32, commands);
+        LazySignals.effect::<(Option<f32>,)>(
+            |(alpha,), world| {
+                let handle = world.resource::<TintHandle>().0.clone();
+                world.asset_mut::<Tint>(&handle).unwrap().0 = alpha.unwrap_or(0.0);
+                None
+            },
+            vec![alpha],
+            vec![],
+            commands
+        );
+        alpha
+    });
+    t.step();
+    let mut reader = ManualEventReader::<AssetEvent<Tint>>::default();
+    reader.read(t.world().resource::<Events<AssetEvent<Tint>>>()).count();
+
+    t.commands(|commands| LazySignals.send(alpha, 0.8f32, commands));
+    t.step();
+    assert_eq!(t.world().resource::<Assets<Tint>>().get(&handle).unwrap().0, 0.8);
+    let events = t.world().resource::<Events<AssetEvent<Tint>>>();
+    assert!(reader.read(events).any(|event| event.is_modified(&handle)));
+
+    // the rest of the world is at hand while the asset is borrowed
+    let dimmed = t.world_mut().with_asset_mut::<Tint, _>(&handle, |tint, world| {
+        tint.0 *= world.resource::<Brightness>().0;
+        tint.0
+    });
+    assert_eq!(dimmed, Some(0.4));
+
+    // nothing to change once the asset is gone
+    t.world_mut().resource_mut::<Assets<Tint>>().remove(&handle);
+    assert!(t.world_mut().asset_mut::<Tint>(&handle).is_none());
+}
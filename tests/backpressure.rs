@@ -0,0 +1,79 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    backpressure::Backpressure,
+    testing::LazySignalsTestApp,
+};
+
+#[derive(Resource, Default)]
+struct Seen(Vec<u32>);
+
+fn flooded(policy: Backpressure) -> (LazySignalsTestApp, Entity) {
+    let mut t = LazySignalsTestApp::new();
+    t.app.init_resource::<Seen>();
+    let signal = t.commands(|commands| {
+        let signal = LazySignals.state(0u32, commands);
+        LazySignals.backpressure(signal, policy, commands);
+        LazySignals.effect::<(Option<u32>,)>(
+            |(value,), world| {
+                world.resource_mut::<Seen>().0.extend(value);
+                None
+            },
+            vec![signal],
+            vec![],
+            commands
+        );
+        signal
+    });
+    t.advance_clock(Duration::ZERO).step();
+    t.world_mut().resource_mut::<Seen>().0.clear();
+    t.commands(|commands| {
+        for value in 1..=4u32 {
+            LazySignals.send(signal, value, commands);
+        }
+    });
+    (t, signal)
+}
+
+fn seen(t: &LazySignalsTestApp) -> Vec<u32> {
+    t.world().resource::<Seen>().0.clone()
+}
+
+#[test]
+fn latest_keeps_the_last_send() {
+    let (mut t, _) = flooded(Backpressure::Latest);
+    t.step_n(3);
+    assert_eq!(seen(&t), vec![4]);
+}
+
+#[test]
+fn drop_keeps_the_first_send() {
+    let (mut t, _) = flooded(Backpressure::Drop);
+    t.step_n(3);
+    assert_eq!(seen(&t), vec![1]);
+}
+
+#[test]
+fn block_lets_values_through_one_per_pass() {
+    let (mut t, signal) = flooded(Backpressure::Block(2));
+    t.step();
+    t.assert_signal_eq(signal, 1u32);
+    t.step_n(4);
+
+    // two waited their turn behind the first, and the last one found the queue full
+    assert_eq!(seen(&t), vec![1, 2, 3]);
+}
+
+#[test]
+fn conflate_holds_sends_for_the_window() {
+    let (mut t, signal) = flooded(Backpressure::Conflate(Duration::from_secs(1)));
+    t.step();
+    t.assert_signal_eq(signal, 0u32);
+    assert!(seen(&t).is_empty());
+
+    t.advance_clock(Duration::from_secs(2)).step_n(2);
+    assert_eq!(seen(&t), vec![4]);
+}
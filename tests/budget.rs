@@ -0,0 +1,51 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    framework::*,
+    stats::LazySignalsStats,
+    testing::LazySignalsTestApp,
+};
+
+#[test]
+fn slow_runs_are_counted_and_reported() {
+    let mut t = LazySignalsTestApp::new();
+    let mut reader = t.world().resource::<Events<BudgetExceeded>>().get_reader();
+    let (signal, slow, fast) = t.commands(|commands| {
+        let signal = LazySignals.state(1u32, commands);
+        let slow = LazySignals.effect::<(Option<u32>,)>(
+            |_, _world| {
+                std::thread::sleep(Duration::from_millis(5));
+                None
+            },
+            vec![signal],
+            vec![],
+            commands
+        );
+        LazySignals.max_duration(slow, Duration::from_millis(1), commands);
+        let fast = LazySignals.effect::<(Option<u32>,)>(
+            |_, _world| None,
+            vec![signal],
+            vec![],
+            commands
+        );
+        LazySignals.max_duration(fast, Duration::from_secs(10), commands);
+        (signal, slow, fast)
+    });
+    t.step();
+
+    t.commands(|commands| LazySignals.send(signal, 2u32, commands));
+    t.step();
+    let events = t.world().resource::<Events<BudgetExceeded>>();
+    let exceeded: Vec<_> = reader.read(events).cloned().collect();
+    assert_eq!(exceeded.len(), 1);
+    assert_eq!(exceeded[0].entity, slow);
+    assert_eq!(exceeded[0].max_duration, Duration::from_millis(1));
+    assert!(exceeded[0].duration >= Duration::from_millis(5));
+
+    let stats = t.world().resource::<LazySignalsStats>();
+    assert_eq!(stats.get(slow).unwrap().over_budget, 1);
+    assert_eq!(stats.get(fast).unwrap().over_budget, 0);
+}
@@ -0,0 +1,43 @@
+use std::time::Duration;
+
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp, LazySignalsAppExt };
+
+#[test]
+fn buffer_count_keeps_the_last_values() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.register_signal_type::<Vec<u32>>();
+    let (source, buffer) = t.commands(|commands| {
+        let source = LazySignals.state(0u32, commands);
+        let buffer = LazySignals.buffer_count::<u32>(source, 3, commands);
+        (source, buffer)
+    });
+    t.step();
+
+    for value in 1..=4u32 {
+        t.commands(|commands| LazySignals.send(source, value, commands));
+        t.step();
+    }
+    t.assert_signal_eq(buffer, vec![2u32, 3, 4]);
+}
+
+#[test]
+fn buffer_time_drops_values_older_than_the_window() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.register_signal_type::<Vec<u32>>();
+    let (source, buffer) = t.commands(|commands| {
+        let source = LazySignals.state(0u32, commands);
+        let buffer = LazySignals.buffer_time::<u32>(source, Duration::from_secs(2), commands);
+        (source, buffer)
+    });
+    t.advance_clock(Duration::ZERO).step();
+
+    t.commands(|commands| LazySignals.send(source, 1u32, commands));
+    t.step();
+    t.advance_clock(Duration::from_secs(1));
+    t.commands(|commands| LazySignals.send(source, 2u32, commands));
+    t.step();
+    t.assert_signal_eq(buffer, vec![1u32, 2]);
+
+    t.advance_clock(Duration::from_millis(1500)).step_n(2);
+    t.assert_signal_eq(buffer, vec![2u32]);
+}
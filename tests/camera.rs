@@ -0,0 +1,47 @@
+#![cfg(all(feature = "camera", feature = "assets"))]
+
+use bevy::{
+    prelude::*,
+    render::{ camera::CameraPlugin, texture::ImagePlugin },
+    window::{ WindowPlugin, WindowResolution },
+};
+
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+#[test]
+fn screen_position_follows_the_target() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.add_plugins((
+        AssetPlugin::default(),
+        WindowPlugin {
+            primary_window: Some(Window {
+                resolution: WindowResolution::new(800.0, 600.0),
+                ..default()
+            }),
+            ..default()
+        },
+        ImagePlugin::default(),
+        CameraPlugin,
+        TransformPlugin,
+    ));
+    let camera = t.world_mut()
+        .spawn((
+            Camera::default(),
+            OrthographicProjection::default(),
+            Transform::from_xyz(0.0, 0.0, 100.0),
+            GlobalTransform::default(),
+        ))
+        .id();
+    let target = t.world_mut().spawn(TransformBundle::default()).id();
+    let position = t.commands(|commands| LazySignals.screen_position_of(target, camera, commands));
+    t.step_n(3);
+    t.assert_signal_eq(position, Some(Vec2::new(400.0, 300.0)));
+
+    t.world_mut().get_mut::<Transform>(target).unwrap().translation.x = 100.0;
+    t.step_n(2);
+    t.assert_signal_eq(position, Some(Vec2::new(500.0, 300.0)));
+
+    t.world_mut().despawn(target);
+    t.step_n(2);
+    t.assert_signal_eq(position, None::<Vec2>);
+}
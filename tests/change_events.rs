@@ -0,0 +1,42 @@
+use bevy::{ ecs::event::ManualEventReader, prelude::* };
+
+use bevy_lazy_signals::{ api::LazySignals, framework::*, testing::LazySignalsTestApp };
+
+fn changes(t: &LazySignalsTestApp, reader: &mut ManualEventReader<SignalChanged>) -> Vec<Entity> {
+    let events = t.world().resource::<Events<SignalChanged>>();
+    reader.read(events).map(|changed| changed.entity).collect()
+}
+
+#[test]
+fn only_opted_in_signals_send_events_and_only_on_change() {
+    let mut t = LazySignalsTestApp::new();
+    let (loud, quiet, doubled) = t.commands(|commands| {
+        let loud = LazySignals.state(1u32, commands);
+        let quiet = LazySignals.state(1u32, commands);
+        let doubled = LazySignals.computed::<(Option<u32>,), u32>(
+            |(value,)| LazySignals::option(value.map(|value| value * 2)),
+            vec![loud],
+            commands
+        );
+        LazySignals.emit_changes(loud, commands);
+        LazySignals.emit_changes(doubled, commands);
+        (loud, quiet, doubled)
+    });
+    t.step();
+    let mut reader = t.world().resource::<Events<SignalChanged>>().get_reader();
+    changes(&t, &mut reader);
+
+    t.commands(|commands| {
+        LazySignals.send(loud, 2u32, commands);
+        LazySignals.send(quiet, 2u32, commands);
+    });
+    t.step();
+    let mut changed = changes(&t, &mut reader);
+    changed.sort();
+    assert_eq!(changed, vec![loud, doubled]);
+
+    // the same value again is not a change
+    t.commands(|commands| LazySignals.send(loud, 2u32, commands));
+    t.step();
+    assert!(changes(&t, &mut reader).is_empty());
+}
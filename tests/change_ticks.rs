@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    framework::lazy_immutable::LazySignalsState,
+    testing::LazySignalsTestApp,
+};
+
+#[derive(Resource, Default)]
+struct Seen(Vec<Entity>);
+
+fn record_changes(
+    query: Query<Entity, Changed<LazySignalsState<u32>>>,
+    mut seen: ResMut<Seen>
+) {
+    seen.0.clear();
+    seen.0.extend(query.iter());
+    seen.0.sort();
+}
+
+#[test]
+fn changed_filter_sees_signal_and_computed_writes() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.init_resource::<Seen>().add_systems(Update, record_changes);
+    let (signal, parity, double) = t.commands(|commands| {
+        let signal = LazySignals.state(1u32, commands);
+        let parity = LazySignals.computed::<(Option<u32>,), u32>(
+            |(value,)| LazySignals::result(value.unwrap_or(0) % 2),
+            vec![signal],
+            commands
+        );
+        let double = LazySignals.computed::<(Option<u32>,), u32>(
+            |(value,)| LazySignals::result(value.unwrap_or(0) * 2),
+            vec![signal],
+            commands
+        );
+        (signal, parity, double)
+    });
+    t.commands(|commands| LazySignals.send(signal, 3u32, commands));
+    t.step();
+
+    t.commands(|commands| LazySignals.send(signal, 5u32, commands));
+    t.step();
+
+    // the parity did not change, so its component is not marked changed
+    let mut expected = vec![signal, double];
+    expected.sort();
+    assert_eq!(t.world().resource::<Seen>().0, expected);
+    assert!(!t.world().resource::<Seen>().0.contains(&parity));
+
+    // sending the same value again is not a change
+    t.commands(|commands| LazySignals.send(signal, 5u32, commands));
+    t.step();
+    assert!(t.world().resource::<Seen>().0.is_empty());
+}
@@ -0,0 +1,32 @@
+use std::{ thread, time::Duration };
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    clock::{ now, LazySignalsClock },
+    testing::LazySignalsTestApp,
+};
+
+#[test]
+fn timers_go_by_the_injected_clock_instead_of_time() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.insert_resource(LazySignalsClock::default());
+    let (fire, cooling) = t.commands(|commands| {
+        let fire = LazySignals.state((), commands);
+        let cooling = LazySignals.cooldown(fire, Duration::from_millis(50), commands);
+        (fire, cooling)
+    });
+    t.step();
+    t.commands(|commands| LazySignals.trigger(fire, commands));
+    t.step();
+    t.assert_signal_eq(cooling, true);
+
+    // real time passing does not move the clock
+    thread::sleep(Duration::from_millis(100));
+    t.step_n(2);
+    t.assert_signal_eq(cooling, true);
+    assert_eq!(now(t.world()), Duration::ZERO);
+
+    t.world_mut().resource_mut::<LazySignalsClock>().set(Duration::from_millis(60));
+    t.step_n(2);
+    t.assert_signal_eq(cooling, false);
+}
@@ -0,0 +1,60 @@
+use std::time::Duration;
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    clock::LazySignalsClock,
+    clock_sync::{ ClockSync, ClockSyncSample, LazySignalsClockSyncPlugin, RemoteTimestamp },
+    testing::LazySignalsTestApp,
+};
+
+fn secs(seconds: f64) -> Duration {
+    Duration::from_secs_f64(seconds)
+}
+
+#[test]
+fn client_estimates_server_time_and_rtt_from_samples() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.add_plugins(LazySignalsClockSyncPlugin::client());
+    t.app.insert_resource(LazySignalsClock::new(secs(10.0)));
+    let sync = *t.world().resource::<ClockSync>();
+    t.step();
+    assert_eq!(sync.offset(), None);
+
+    // the answer took half a second, so the server stamped it a quarter second before it arrived
+    t.world_mut().send_event(ClockSyncSample {
+        sent: secs(9.5),
+        server_time: secs(100.0),
+        received: secs(10.0),
+    });
+    t.step();
+    t.assert_signal_eq(sync.rtt, secs(0.5));
+    t.assert_signal_eq(sync.server_time, secs(100.25));
+
+    // the server time keeps moving with the local clock
+    t.advance_clock(secs(1.0));
+    t.step();
+    t.assert_signal_eq(sync.server_time, secs(101.25));
+    assert_eq!(t.world().resource::<ClockSync>().to_local_time(secs(101.25)), Some(secs(11.0)));
+}
+
+#[test]
+fn timestamped_sends_drop_older_remote_values() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.add_plugins(LazySignalsClockSyncPlugin::server());
+    let health = t.commands(|commands| LazySignals.state(100u32, commands));
+    t.step();
+
+    t.commands(|commands| LazySignals.send_with_timestamp(health, 90u32, secs(5.0), commands));
+    t.step();
+    t.assert_signal_eq(health, 90u32);
+
+    // arrived late, after a newer update
+    t.commands(|commands| LazySignals.send_with_timestamp(health, 95u32, secs(4.0), commands));
+    t.step();
+    t.assert_signal_eq(health, 90u32);
+
+    t.commands(|commands| LazySignals.send_with_timestamp(health, 80u32, secs(6.0), commands));
+    t.step();
+    t.assert_signal_eq(health, 80u32);
+    assert_eq!(t.world().get::<RemoteTimestamp>(health), Some(&RemoteTimestamp(secs(6.0))));
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "common_types")]
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+#[test]
+fn math_types_work_without_registering_them() {
+    let mut t = LazySignalsTestApp::new();
+    let (position, length) = t.commands(|commands| {
+        let position = LazySignals.state(Vec2::new(3.0, 4.0), commands);
+        let length = LazySignals.lens::<Vec2, f32>(
+            position,
+            |position| position.length(),
+            commands
+        );
+        (position, length)
+    });
+    t.step();
+
+    t.commands(|commands| LazySignals.send(position, Vec2::new(6.0, 8.0), commands));
+    t.step();
+    t.assert_signal_eq(length, 10.0f32);
+}
+
+#[test]
+fn color_and_duration_signals_propagate() {
+    let mut t = LazySignalsTestApp::new();
+    let (tint, delay, millis) = t.commands(|commands| {
+        let tint = LazySignals.state(Color::WHITE, commands);
+        let delay = LazySignals.state(Duration::from_millis(250), commands);
+        let millis = LazySignals.lens::<Duration, u64>(
+            delay,
+            |delay| delay.as_millis() as u64,
+            commands
+        );
+        (tint, delay, millis)
+    });
+    t.step();
+
+    t.commands(|commands| {
+        LazySignals.send(tint, Color::BLACK, commands);
+        LazySignals.send(delay, Duration::from_secs(2), commands);
+    });
+    t.step();
+    t.assert_signal_eq(tint, Color::BLACK);
+    t.assert_signal_eq(millis, 2000u64);
+}
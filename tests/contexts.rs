@@ -0,0 +1,72 @@
+use bevy::ecs::schedule::ScheduleLabel;
+
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp, LazySignalsPlugin };
+
+#[derive(ScheduleLabel, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+struct SimStep;
+
+#[test]
+fn labeled_context_runs_only_in_its_own_schedule() {
+    let mut t = LazySignalsTestApp::new();
+    t.app
+        .init_schedule(SimStep)
+        .add_plugins(LazySignalsPlugin::labeled("sim").in_schedule(SimStep));
+    let (ui, ui_double, sim, sim_double) = t.commands(|commands| {
+        let ui = LazySignals.state(1u32, commands);
+        let ui_double = LazySignals.computed::<(Option<u32>,), u32>(
+            |(value,)| LazySignals::result(value.unwrap_or(0) * 2),
+            vec![ui],
+            commands
+        );
+        let sim = LazySignals.in_context("sim", LazySignals.state(1u32, commands), commands);
+        let sim_double = LazySignals.computed::<(Option<u32>,), u32>(
+            |(value,)| LazySignals::result(value.unwrap_or(0) * 2),
+            vec![sim],
+            commands
+        );
+        let sim_double = LazySignals.in_context("sim", sim_double, commands);
+        (ui, ui_double, sim, sim_double)
+    });
+    t.commands(|commands| {
+        LazySignals.send(ui, 2u32, commands);
+        LazySignals.send(sim, 3u32, commands);
+    });
+
+    // a frame only processes the default context
+    t.step();
+    t.assert_signal_eq(ui_double, 4u32);
+    t.assert_signal_none::<u32>(sim_double);
+
+    t.world_mut().run_schedule(SimStep);
+    t.assert_signal_eq(sim_double, 6u32);
+}
+
+#[test]
+fn flush_leaves_a_labeled_context_for_its_own_schedule() {
+    let mut t = LazySignalsTestApp::new();
+    t.app
+        .init_schedule(SimStep)
+        .add_plugins(LazySignalsPlugin::labeled("sim").in_schedule(SimStep));
+    let (ui, ui_double, sim) = t.commands(|commands| {
+        let ui = LazySignals.state(1u32, commands);
+        let ui_double = LazySignals.computed::<(Option<u32>,), u32>(
+            |(value,)| LazySignals::result(value.unwrap_or(0) * 2),
+            vec![ui],
+            commands
+        );
+        let sim = LazySignals.in_context("sim", LazySignals.state(1u32, commands), commands);
+        (ui, ui_double, sim)
+    });
+    t.commands(|commands| {
+        LazySignals.send(ui, 2u32, commands);
+        LazySignals.send(sim, 3u32, commands);
+    });
+
+    // the pending sim send does not keep the default context from settling
+    assert!(LazySignals.flush(t.world_mut()));
+    t.assert_signal_eq(ui_double, 4u32);
+    t.assert_signal_eq(sim, 1u32);
+
+    t.world_mut().run_schedule(SimStep);
+    t.assert_signal_eq(sim, 3u32);
+}
@@ -0,0 +1,213 @@
+use std::sync::atomic::{ AtomicU32, Ordering };
+
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{
+    api::{ make_computed_with, LazySignals },
+    commands::LazySignalsCommandsExt,
+    framework::*,
+    testing::LazySignalsTestApp,
+};
+
+fn rejected(t: &LazySignalsTestApp) -> Vec<SignalNodeRejected> {
+    let events = t.world().resource::<Events<SignalNodeRejected>>();
+    events.get_reader().read(events).cloned().collect()
+}
+
+#[test]
+fn arity_mismatch_is_rejected() {
+    let mut t = LazySignalsTestApp::new();
+    let (a, b, computed, effect) = t.commands(|commands| {
+        let a = LazySignals.state(1u32, commands);
+        let b = LazySignals.state(2u32, commands);
+        let computed = LazySignals.computed::<(Option<u32>,), u32>(
+            |(a,)| LazySignals::option(a),
+            vec![a, b],
+            commands
+        );
+        let effect = LazySignals.effect::<(Option<u32>, Option<u32>)>(
+            |_, _| None,
+            vec![a],
+            vec![],
+            commands
+        );
+        (a, b, computed, effect)
+    });
+    assert!(t.world().get_entity(computed).is_none());
+    assert!(t.world().get_entity(effect).is_none());
+    let errors: Vec<_> = rejected(&t)
+        .into_iter()
+        .map(|rejected| (rejected.entity, rejected.error))
+        .collect();
+    assert_eq!(errors, vec![
+        (computed, LazySignalsError::ArityMismatch { sources: 2, args: 1 }),
+        (effect, LazySignalsError::ArityMismatch { sources: 1, args: 2 }),
+    ]);
+
+    // the rest of the graph keeps working
+    t.step();
+    t.commands(|commands| {
+        LazySignals.send(a, 3u32, commands);
+        LazySignals.send(b, 4u32, commands);
+    });
+    t.step();
+    t.assert_signal_eq(a, 3u32);
+    t.assert_signal_eq(b, 4u32);
+}
+
+#[derive(Reflect, LazySignalsParams, PartialEq)]
+struct AuthArgs {
+    logged_in: Option<bool>,
+    msg: Option<&'static str>,
+}
+
+#[test]
+fn struct_args_are_filled_in_declaration_order() {
+    let mut t = LazySignalsTestApp::new();
+    let (logged_in, login_msg) = t.commands(|commands| {
+        let logged_in = LazySignals.state(false, commands);
+        let msg = LazySignals.state("hi", commands);
+        let login_msg = LazySignals.computed::<AuthArgs, &'static str>(
+            |args| {
+                LazySignals::result(match args.logged_in {
+                    Some(true) => args.msg.unwrap_or("?"),
+                    _ => "bye",
+                })
+            },
+            vec![logged_in, msg],
+            commands
+        );
+        (logged_in, login_msg)
+    });
+    t.step();
+    t.commands(|commands| LazySignals.send(logged_in, true, commands));
+    t.step();
+    t.assert_signal_eq(login_msg, "hi");
+}
+
+#[derive(Reflect, LazySignalsParams, PartialEq)]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[test]
+fn struct_args_count_their_fields_and_can_be_unwrapped() {
+    let mut t = LazySignalsTestApp::new();
+    let (x, sum, short) = t.commands(|commands| {
+        let x = LazySignals.state(1.0f32, commands);
+        let y = LazySignals.state(2.0f32, commands);
+        let sum = LazySignals.computed_unwrapped::<Position, f32>(
+            |position| LazySignals::result(position.x + position.y),
+            vec![x, y],
+            commands
+        );
+        let short = LazySignals.computed::<AuthArgs, &'static str>(
+            |_| LazySignals::result("never"),
+            vec![x],
+            commands
+        );
+        (x, sum, short)
+    });
+    assert!(t.world().get_entity(short).is_none());
+    assert_eq!(rejected(&t)[0].error, LazySignalsError::ArityMismatch { sources: 1, args: 2 });
+
+    t.step();
+    t.commands(|commands| LazySignals.send(x, 5.0f32, commands));
+    t.step();
+    t.assert_signal_eq(sum, 7.0f32);
+}
+
+bevy_lazy_signals::signal_params! {
+    struct GreetingParams(GreetingSources) {
+        name: String,
+        excited: bool,
+    }
+}
+
+#[test]
+fn signal_params_are_args() {
+    let mut t = LazySignalsTestApp::new();
+    let (excited, greeting) = t.commands(|commands| {
+        let name = LazySignals.state("Ada".to_string(), commands);
+        let excited = LazySignals.state(false, commands);
+        let greeting = LazySignals.computed::<GreetingParams, String>(
+            |params| {
+                let mark = if params.excited() == Some(&true) { "!" } else { "." };
+                LazySignals::result(format!("Hi {}{}", params.name().map_or("", |n| n), mark))
+            },
+            GreetingSources { name, excited }.into(),
+            commands
+        );
+        (excited, greeting)
+    });
+    t.step();
+    t.commands(|commands| LazySignals.send(excited, true, commands));
+    t.step();
+    t.assert_signal_eq(greeting, "Hi Ada!".to_string());
+}
+
+#[test]
+fn duplicate_sources_are_rejected() {
+    let mut t = LazySignalsTestApp::new();
+    let (a, doubled) = t.commands(|commands| {
+        let a = LazySignals.state(1u32, commands);
+        let doubled = LazySignals.computed::<(Option<u32>, Option<u32>), u32>(
+            |(x, y)| LazySignals::result(x.unwrap_or(0) + y.unwrap_or(0)),
+            vec![a, a],
+            commands
+        );
+        (a, doubled)
+    });
+    assert!(t.world().get_entity(doubled).is_none());
+    assert_eq!(rejected(&t)[0].error, LazySignalsError::DuplicateDependency(a));
+
+    t.step();
+    t.commands(|commands| LazySignals.send(a, 2u32, commands));
+    t.step();
+    t.assert_signal_eq(a, 2u32);
+}
+
+#[test]
+fn duplicate_triggers_are_deduped() {
+    static RUNS: AtomicU32 = AtomicU32::new(0);
+
+    let mut t = LazySignalsTestApp::new();
+    let (fire, effect) = t.commands(|commands| {
+        let fire = LazySignals.state((), commands);
+        let effect = LazySignals.effect::<()>(
+            |_, _| {
+                RUNS.fetch_add(1, Ordering::SeqCst);
+                None
+            },
+            vec![],
+            vec![fire, fire],
+            commands
+        );
+        (fire, effect)
+    });
+    assert!(rejected(&t).is_empty());
+    assert_eq!(t.world().get::<LazyEffect>(effect).unwrap().triggers, vec![fire]);
+
+    t.step();
+    t.commands(|commands| LazySignals.trigger(fire, commands));
+    t.step();
+    assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn self_reference_is_rejected() {
+    let mut t = LazySignalsTestApp::new();
+    let computed = t.commands(|commands| {
+        let computed = commands.spawn_empty().id();
+        commands.create_computed::<(Option<u32>,), u32>(
+            computed,
+            make_computed_with(|(value,): (Option<u32>,)| LazySignals::option(value)),
+            vec![computed]
+        );
+        computed
+    });
+    t.step();
+    assert!(t.world().get_entity(computed).is_none());
+    assert_eq!(rejected(&t)[0].error, LazySignalsError::SelfReference);
+}
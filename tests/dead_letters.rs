@@ -0,0 +1,40 @@
+use bevy_lazy_signals::{
+    api::LazySignals,
+    dead_letters::{ DeadLetterReason, LazySignalsDeadLetters },
+    testing::LazySignalsTestApp,
+};
+
+#[test]
+fn dropped_sends_are_kept_in_a_ring_buffer() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.insert_resource(LazySignalsDeadLetters::new(2));
+    let (signal, gone) = t.commands(|commands| {
+        let signal = LazySignals.state(1u32, commands);
+        let gone = LazySignals.state(1u32, commands);
+        (signal, gone)
+    });
+    t.step();
+    t.world_mut().despawn(gone);
+
+    t.commands(|commands| LazySignals.send(gone, 2u32, commands));
+    t.step();
+    t.commands(|commands| LazySignals.send(signal, "wrong", commands));
+    t.step();
+
+    let letters = t.world().resource::<LazySignalsDeadLetters>();
+    let reasons: Vec<_> = letters.iter().map(|letter| (letter.signal, letter.reason)).collect();
+    assert_eq!(
+        reasons,
+        vec![(gone, DeadLetterReason::MissingEntity), (signal, DeadLetterReason::TypeMismatch)]
+    );
+    assert_eq!(letters.iter().last().unwrap().type_path, "&str");
+    t.assert_signal_eq(signal, 1u32);
+
+    // a third drop pushes out the oldest
+    t.commands(|commands| LazySignals.send(gone, 3u32, commands));
+    t.step();
+    let letters = t.world().resource::<LazySignalsDeadLetters>();
+    assert_eq!(letters.len(), 2);
+    assert_eq!(letters.total(), 3);
+    assert_eq!(letters.iter().next().unwrap().reason, DeadLetterReason::TypeMismatch);
+}
@@ -0,0 +1,71 @@
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{ api::LazySignals, framework::*, testing::LazySignalsTestApp };
+
+fn chain(t: &mut LazySignalsTestApp) -> (Entity, Entity, Entity) {
+    let chain = t.commands(|commands| {
+        let signal = LazySignals.state(1u32, commands);
+        let double = LazySignals.computed::<(Option<u32>,), u32>(
+            |(value,)| LazySignals::result(value.unwrap_or(0) * 2),
+            vec![signal],
+            commands
+        );
+        let effect = LazySignals.effect::<(Option<u32>,)>(
+            |_, _world| None,
+            vec![double],
+            vec![],
+            commands
+        );
+        (signal, double, effect)
+    });
+    t.step();
+    chain
+}
+
+#[test]
+fn cascade_despawns_every_dependent() {
+    let mut t = LazySignalsTestApp::new();
+    let (signal, double, effect) = chain(&mut t);
+
+    t.commands(|commands| LazySignals.despawn(signal, DependentPolicy::Cascade, commands));
+    t.step();
+    for entity in [signal, double, effect] {
+        assert!(t.world().get_entity(entity).is_none());
+    }
+}
+
+#[test]
+fn orphan_leaves_dependents_with_an_error() {
+    let mut t = LazySignalsTestApp::new();
+    let (signal, double, effect) = chain(&mut t);
+
+    t.commands(|commands| LazySignals.despawn(signal, DependentPolicy::Orphan, commands));
+    t.step();
+    assert!(t.world().get_entity(signal).is_none());
+    assert!(t.world().get_entity(effect).is_some());
+    assert!(
+        matches!(
+            LazySignals.get_error::<u32>(double, t.world()),
+            Some(LazySignalsError::SourceRemoved(removed)) if removed == signal
+        )
+    );
+}
+
+#[test]
+fn error_refuses_while_there_are_dependents() {
+    let mut t = LazySignalsTestApp::new();
+    let (signal, double, effect) = chain(&mut t);
+
+    t.commands(|commands| LazySignals.despawn(signal, DependentPolicy::Error, commands));
+    t.step();
+    assert!(t.world().get_entity(signal).is_some());
+
+    // once nothing depends on it, it goes
+    t.commands(|commands| {
+        LazySignals.despawn(effect, DependentPolicy::Error, commands);
+        LazySignals.despawn(double, DependentPolicy::Error, commands);
+        LazySignals.despawn(signal, DependentPolicy::Error, commands);
+    });
+    t.step();
+    assert!(t.world().get_entity(signal).is_none());
+}
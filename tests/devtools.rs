@@ -0,0 +1,189 @@
+#![cfg(feature = "devtools")]
+
+use std::{ io::{ ErrorKind, Read, Write }, net::TcpStream, time::Duration };
+
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{ api::LazySignals, devtools::*, testing::LazySignalsTestApp };
+
+fn serve(t: &mut LazySignalsTestApp, allow_send: bool) -> TcpStream {
+    t.app.add_plugins(LazySignalsDevtoolsPlugin {
+        address: "127.0.0.1:0".to_string(),
+        allow_send,
+        allow_remote: false,
+    });
+    let address = t.world().resource::<DevtoolsServer>().local_addr().unwrap();
+    let mut stream = TcpStream::connect(address).unwrap();
+    stream.set_read_timeout(Some(Duration::from_millis(20))).unwrap();
+    stream
+        .write_all(
+            b"GET / HTTP/1.1\r\nHost: localhost\r\nUpgrade: websocket\r\nConnection: Upgrade\r\n\
+            Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\nSec-WebSocket-Version: 13\r\n\r\n"
+        )
+        .unwrap();
+    let response = read_until(t, &mut stream, |read| read.windows(4).any(|w| w == b"\r\n\r\n"));
+    let response = String::from_utf8_lossy(&response).to_string();
+    let accept = response.lines().find_map(|line| {
+        let (header, value) = line.split_once(':')?;
+        header.eq_ignore_ascii_case("sec-websocket-accept").then(|| value.trim())
+    });
+    assert_eq!(accept, Some("s3pPLMBiTxaQ9kYGzzhZRbK+xOo="));
+    stream
+}
+
+// step the app until the client has read enough
+fn read_until(
+    t: &mut LazySignalsTestApp,
+    stream: &mut TcpStream,
+    done: impl Fn(&[u8]) -> bool
+) -> Vec<u8> {
+    let mut read = Vec::new();
+    let mut buffer = [0u8; 4096];
+    for _ in 0..50 {
+        t.step();
+        match stream.read(&mut buffer) {
+            Ok(len) => read.extend_from_slice(&buffer[..len]),
+            Err(error) if matches!(error.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
+            Err(error) => panic!("{}", error),
+        }
+        if done(&read) {
+            return read;
+        }
+    }
+    panic!("no reply, read {:?}", String::from_utf8_lossy(&read));
+}
+
+// send a masked text frame and return the text of the server frames until one contains `until`
+fn exchange(t: &mut LazySignalsTestApp, stream: &mut TcpStream, text: &str, until: &str) -> String {
+    let mask = [1u8, 2, 3, 4];
+    let mut frame = vec![0x81, 0x80 | (text.len() as u8)];
+    frame.extend_from_slice(&mask);
+    frame.extend(
+        text
+            .bytes()
+            .enumerate()
+            .map(|(index, byte)| byte ^ mask[index % 4])
+    );
+    stream.write_all(&frame).unwrap();
+    let read = read_until(t, stream, |read| String::from_utf8_lossy(read).contains(until));
+    String::from_utf8_lossy(&read).to_string()
+}
+
+// step the app until the server has dropped the connection
+fn assert_disconnected(t: &mut LazySignalsTestApp, stream: &mut TcpStream) {
+    let mut buffer = [0u8; 4096];
+    for _ in 0..50 {
+        t.step();
+        match stream.read(&mut buffer) {
+            Ok(0) => {
+                return;
+            }
+            Ok(_) => {}
+            Err(error) if matches!(error.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
+            Err(_) => {
+                return;
+            }
+        }
+    }
+    panic!("still connected");
+}
+
+fn health(t: &mut LazySignalsTestApp) -> Entity {
+    let signal = t.commands(|commands| {
+        let signal = LazySignals.state(75u32, commands);
+        LazySignals.register_path("health", signal, commands);
+        signal
+    });
+    t.step();
+    signal
+}
+
+#[test]
+fn refuses_sends_by_default() {
+    let mut t = LazySignalsTestApp::new();
+    let signal = health(&mut t);
+    let mut stream = serve(&mut t, false);
+
+    let send = r#"{"type":"send","name":"health","value":1}"#;
+    let reply = exchange(&mut t, &mut stream, send, "error");
+    assert!(reply.contains("sends are disabled"));
+    t.step();
+    t.assert_signal_eq(signal, 75u32);
+}
+
+#[test]
+fn sends_through_the_write_guard() {
+    let mut t = LazySignalsTestApp::new();
+    let signal = health(&mut t);
+    let mut stream = serve(&mut t, true);
+
+    exchange(&mut t, &mut stream, r#"{"type":"send","name":"health","value":70}"#, "70");
+    t.assert_signal_eq(signal, 70u32);
+
+    t.commands(|commands| LazySignals.write_guard(signal, |_| false, commands));
+    t.step();
+    let send = r#"{"type":"send","name":"health","value":1}"#;
+    let reply = exchange(&mut t, &mut stream, send, "error");
+    assert!(reply.contains("refused"));
+    t.assert_signal_eq(signal, 70u32);
+}
+
+#[test]
+fn refuses_a_remote_address() {
+    let error = DevtoolsServer::bind("0.0.0.0:0", false).err().unwrap();
+    assert_eq!(error.kind(), ErrorKind::PermissionDenied);
+    assert!(DevtoolsServer::bind("127.0.0.1:0", false).is_ok());
+    assert!(DevtoolsServer::bind("0.0.0.0:0", true).is_ok());
+}
+
+#[test]
+fn disconnects_an_oversized_request() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.add_plugins(LazySignalsDevtoolsPlugin {
+        address: "127.0.0.1:0".to_string(),
+        allow_send: false,
+        allow_remote: false,
+    });
+    let address = t.world().resource::<DevtoolsServer>().local_addr().unwrap();
+    let mut stream = TcpStream::connect(address).unwrap();
+    stream.set_read_timeout(Some(Duration::from_millis(20))).unwrap();
+
+    // a request that never ends its headers
+    stream.write_all(b"GET / HTTP/1.1\r\nX-Padding: ").unwrap();
+    stream.write_all(&vec![b'x'; MAX_REQUEST_LEN]).unwrap();
+    assert_disconnected(&mut t, &mut stream);
+    assert_eq!(t.world().resource::<DevtoolsServer>().connected(), 0);
+}
+
+#[test]
+fn disconnects_a_frame_over_the_cap() {
+    let mut t = LazySignalsTestApp::new();
+    let mut stream = serve(&mut t, false);
+    assert_eq!(t.world().resource::<DevtoolsServer>().connected(), 1);
+
+    let mut frame = vec![0x81, 0xff];
+    frame.extend_from_slice(&((MAX_FRAME_LEN as u64) + 1).to_be_bytes());
+    frame.extend_from_slice(&[1, 2, 3, 4]);
+    stream.write_all(&frame).unwrap();
+    assert_disconnected(&mut t, &mut stream);
+    assert_eq!(t.world().resource::<DevtoolsServer>().connected(), 0);
+}
+
+#[test]
+fn disconnects_a_client_that_falls_behind() {
+    let mut t = LazySignalsTestApp::new();
+    let text = t.commands(|commands| LazySignals.state(String::new(), commands));
+    t.step();
+    let _stream = serve(&mut t, false);
+    assert_eq!(t.world().resource::<DevtoolsServer>().connected(), 1);
+
+    // the client never reads, so the changes pile up until it passes the backlog cap
+    let mut sent = 0;
+    while t.world().resource::<DevtoolsServer>().connected() > 0 {
+        assert!(sent < 4 * MAX_BACKLOG_LEN, "still connected after {} bytes", sent);
+        let value = format!("{}{}", sent, "x".repeat(1 << 20));
+        sent += value.len();
+        t.commands(|commands| LazySignals.send(text, value, commands));
+        t.step();
+    }
+}
@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+// S feeds A and B, and C depends on both
+fn diamond(t: &mut LazySignalsTestApp) -> (Entity, Entity, Entity, Entity) {
+    t.commands(|commands| {
+        let s = LazySignals.state(1u32, commands);
+        let a = LazySignals.computed::<(Option<u32>,), u32>(
+            |(s,)| LazySignals::result(s.unwrap_or(0) + 1),
+            vec![s],
+            commands
+        );
+        let b = LazySignals.computed::<(Option<u32>,), u32>(
+            |(s,)| LazySignals::result(s.unwrap_or(0) * 10),
+            vec![s],
+            commands
+        );
+        let c = LazySignals.computed::<(Option<u32>, Option<u32>), u32>(
+            |(a, b)| LazySignals::result(a.unwrap_or(0) + b.unwrap_or(0)),
+            vec![a, b],
+            commands
+        );
+        (s, a, b, c)
+    })
+}
+
+#[test]
+fn bottom_of_a_diamond_runs_once_after_both_sides() {
+    let mut t = LazySignalsTestApp::new();
+    let (s, a, b, c) = diamond(&mut t);
+    t.commands(|commands| LazySignals.send(s, 2u32, commands));
+    t.step();
+    t.assert_recomputed_once_after(c, &[a, b]);
+    t.assert_signal_eq(c, 23u32);
+
+    t.commands(|commands| LazySignals.send(s, 3u32, commands));
+    t.step();
+    t.assert_recomputed_once_after(c, &[a, b]);
+    t.assert_signal_eq(c, 34u32);
+}
+
+#[test]
+#[should_panic(expected = "recomputed 0 times")]
+fn assertion_fails_when_the_computed_did_not_run() {
+    let mut t = LazySignalsTestApp::new();
+    let (s, a, b, c) = diamond(&mut t);
+    t.commands(|commands| LazySignals.send(s, 2u32, commands));
+    t.step();
+    t.step();
+    t.assert_recomputed_once_after(c, &[a, b]);
+}
@@ -0,0 +1,34 @@
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+#[test]
+fn effect_return_value_lands_in_its_output_signal() {
+    let mut t = LazySignalsTestApp::new();
+    let (signal, output, announced) = t.commands(|commands| {
+        let signal = LazySignals.state(1u32, commands);
+        let (_effect, output) = LazySignals.effect_with_result::<(Option<u32>,), String>(
+            |(score,), _world| format!("score: {}", score.unwrap_or(0)),
+            vec![signal],
+            vec![],
+            String::new(),
+            commands
+        );
+
+        // the output is a signal like any other
+        let announced = LazySignals.computed::<(Option<String>,), bool>(
+            |(text,)| LazySignals::result(text.is_some_and(|text| !text.is_empty())),
+            vec![output.entity()],
+            commands
+        );
+        (signal, output, announced)
+    });
+    t.step();
+    assert_eq!(output.read(t.world()), Some(String::new()));
+
+    t.commands(|commands| LazySignals.send(signal, 7u32, commands));
+    t.step();
+    assert_eq!(output.read(t.world()), Some("score: 7".to_string()));
+
+    // effects run last, so subscribers of the output hear about it in the next batch
+    t.step();
+    t.assert_signal_eq(announced, true);
+}
@@ -0,0 +1,45 @@
+use bevy_lazy_signals::{
+    api::LazySignals,
+    introspection::SignalKind,
+    testing::LazySignalsTestApp,
+};
+
+#[test]
+fn enumerate_lists_live_primitives_grouped_by_type() {
+    let mut t = LazySignalsTestApp::new();
+    let (count, label, double, effect) = t.commands(|commands| {
+        let count = LazySignals.state(1u32, commands);
+        let count = LazySignals.label(count, "count", commands);
+        let label = LazySignals.state("one", commands);
+        let double = LazySignals.computed::<(Option<u32>,), u32>(
+            |(count,)| LazySignals::result(count.unwrap_or(0) * 2),
+            vec![count],
+            commands
+        );
+        let effect = LazySignals.effect::<(Option<u32>,)>(
+            |_, _world| None,
+            vec![double],
+            vec![],
+            commands
+        );
+        (count, label, double, effect)
+    });
+    t.step();
+
+    let infos = LazySignals.enumerate(t.world());
+    let find = |entity| infos.iter().find(|info| info.entity == entity).unwrap();
+    assert_eq!(find(count).kind, SignalKind::Signal);
+    assert_eq!(find(count).name.as_deref(), Some("count"));
+    assert_eq!(find(count).type_path, "u32");
+    assert_eq!(find(label).type_path, "&str");
+    assert_eq!(find(double).kind, SignalKind::Computed);
+    assert_eq!(find(effect).kind, SignalKind::Effect);
+    assert_eq!(find(effect).type_path, "(core::option::Option<u32>,)");
+
+    // the u32 signal and computed are next to each other
+    let position = |entity| infos.iter().position(|info| info.entity == entity).unwrap();
+    assert_eq!(position(count).abs_diff(position(double)), 1);
+
+    t.world_mut().despawn(label);
+    assert!(LazySignals.enumerate(t.world()).iter().all(|info| info.entity != label));
+}
@@ -0,0 +1,59 @@
+use bevy_lazy_signals::{ api::LazySignals, framework::*, testing::LazySignalsTestApp };
+
+#[test]
+fn each_level_wraps_the_error_of_its_source() {
+    let mut t = LazySignalsTestApp::new();
+    let (signal, parsed, scaled, shown) = t.commands(|commands| {
+        let signal = LazySignals.state("7".to_string(), commands);
+        let parsed = LazySignals.computed::<(Option<String>,), u32>(
+            |(text,)| match text.unwrap_or_default().parse() {
+                Ok(value) => LazySignals::result(value),
+                Err(_) => LazySignals::error(LazySignalsError::NoSignalError),
+            },
+            vec![signal],
+            commands
+        );
+        let scaled = LazySignals.computed::<(Option<u32>,), u32>(
+            |(value,)| match value {
+                Some(value) => LazySignals::result(value * 10),
+                None => LazySignals::error(LazySignalsError::NoSignalError),
+            },
+            vec![parsed],
+            commands
+        );
+        let shown = LazySignals.computed::<(Option<u32>,), String>(
+            |(value,)| match value {
+                Some(value) => LazySignals::result(format!("{}", value)),
+                None => LazySignals::error(LazySignalsError::NoSignalError),
+            },
+            vec![scaled],
+            commands
+        );
+        (signal, parsed, scaled, shown)
+    });
+    t.step();
+    t.commands(|commands| LazySignals.send(signal, "seven".to_string(), commands));
+    t.step();
+
+    // each memo that fails on a failed source wraps its error instead of replacing it
+    let error = LazySignals.get_error::<String>(shown, t.world()).unwrap();
+    assert_eq!(
+        error,
+        LazySignalsError::ComputeFailed {
+            at: scaled,
+            caused_by: Box::new(LazySignalsError::ComputeFailed {
+                at: parsed,
+                caused_by: Box::new(LazySignalsError::NoSignalError),
+            }),
+        }
+    );
+    assert_eq!(error.origin(), Some(parsed));
+    let message = format!("Computed failed because source {:?}", scaled);
+    assert!(error.to_string().starts_with(&message));
+
+    // fixing the source clears the whole chain
+    t.commands(|commands| LazySignals.send(signal, "8".to_string(), commands));
+    t.step();
+    t.assert_signal_eq(shown, "80".to_string());
+    assert_eq!(LazySignals.get_error::<String>(shown, t.world()), None);
+}
@@ -0,0 +1,82 @@
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    external::{
+        ExternalInputSignals,
+        LazySignalsClipboard,
+        LazySignalsExternalInputPlugin,
+        TextPasted,
+    },
+    testing::LazySignalsTestApp,
+};
+
+#[derive(Resource, Default)]
+struct Received {
+    files: Vec<PathBuf>,
+    texts: Vec<String>,
+}
+
+fn listen(t: &mut LazySignalsTestApp) {
+    t.app.add_plugins(LazySignalsExternalInputPlugin).init_resource::<Received>();
+    let signals = *t.world().resource::<ExternalInputSignals>();
+    t.commands(|commands| {
+        LazySignals.effect::<(Option<Vec<PathBuf>>,)>(
+            |(files,), world| {
+                world.resource_mut::<Received>().files.extend(files.into_iter().flatten());
+                None
+            },
+            vec![signals.files_dropped],
+            vec![],
+            commands
+        );
+        LazySignals.effect::<(Option<String>,)>(
+            |(text,), world| {
+                world.resource_mut::<Received>().texts.extend(text);
+                None
+            },
+            vec![signals.text_pasted],
+            vec![],
+            commands
+        );
+    });
+    t.step();
+}
+
+#[test]
+fn dropped_files_and_pasted_text_fire_payload_triggers() {
+    let mut t = LazySignalsTestApp::new();
+    listen(&mut t);
+    let window = t.world_mut().spawn_empty().id();
+    t.world_mut().send_event(FileDragAndDrop::DroppedFile {
+        window,
+        path_buf: PathBuf::from("level.ron"),
+    });
+    t.world_mut().send_event(TextPasted("hello".to_string()));
+    t.step();
+
+    let received = t.world().resource::<Received>();
+    assert_eq!(received.files, vec![PathBuf::from("level.ron")]);
+    assert_eq!(received.texts, vec!["hello".to_string()]);
+
+    // the payloads are not kept for the next frame
+    t.step();
+    let signals = *t.world().resource::<ExternalInputSignals>();
+    t.assert_signal_none::<String>(signals.text_pasted);
+}
+
+#[test]
+fn paste_shortcut_reads_the_clipboard() {
+    let mut t = LazySignalsTestApp::new();
+    listen(&mut t);
+    let mut keys = ButtonInput::<KeyCode>::default();
+    keys.press(KeyCode::ControlLeft);
+    keys.press(KeyCode::KeyV);
+    t.app
+        .insert_resource(keys)
+        .insert_resource(LazySignalsClipboard::new(|| Some("from clipboard".to_string())));
+    t.step();
+    assert_eq!(t.world().resource::<Received>().texts, vec!["from clipboard".to_string()]);
+}
@@ -0,0 +1,123 @@
+use std::time::Duration;
+
+use bevy::{ ecs::system::RunSystemOnce, prelude::* };
+use bevy_lazy_signals::{
+    api::LazySignals,
+    family::{ LazySignalsFamily, MemoEviction },
+    framework::LazySignalsError,
+    testing::LazySignalsTestApp,
+};
+
+fn member(t: &mut LazySignalsTestApp, family: Entity, key: u32) -> Entity {
+    t.world_mut().run_system_once(
+        move |mut query: Query<&mut LazySignalsFamily>, mut commands: Commands| {
+            query.get_mut(family).unwrap().member(&key, &mut commands)
+        }
+    )
+}
+
+fn family_len(t: &LazySignalsTestApp, family: Entity) -> usize {
+    t.world().get::<LazySignalsFamily>(family).unwrap().len()
+}
+
+#[test]
+fn lru_family_despawns_the_least_recently_used_member() {
+    let mut t = LazySignalsTestApp::new();
+    let family = t.commands(|commands| {
+        LazySignals.family::<u32>(
+            |key, commands| LazySignals.state(*key, commands),
+            MemoEviction::lru(2),
+            commands
+        )
+    });
+
+    let one = member(&mut t, family, 1);
+    t.step();
+    t.assert_signal_eq(one, 1u32);
+    t.advance_clock(Duration::from_secs(1));
+    let two = member(&mut t, family, 2);
+    t.step();
+    t.advance_clock(Duration::from_secs(1));
+
+    // the same key gets the same member
+    assert_eq!(member(&mut t, family, 2), two);
+    let three = member(&mut t, family, 3);
+    t.step().step();
+    assert_eq!(family_len(&t, family), 2);
+    assert!(t.world().get_entity(one).is_none());
+    assert!(t.world().get_entity(two).is_some());
+    t.assert_signal_eq(three, 3u32);
+}
+
+#[test]
+fn ttl_family_despawns_members_not_used_in_time() {
+    let mut t = LazySignalsTestApp::new();
+    let family = t.commands(|commands| {
+        LazySignals.family::<u32>(
+            |key, commands| LazySignals.state(*key, commands),
+            MemoEviction::ttl(Duration::from_secs(5)),
+            commands
+        )
+    });
+
+    let one = member(&mut t, family, 1);
+    let two = member(&mut t, family, 2);
+    t.step();
+
+    t.advance_clock(Duration::from_secs(3));
+    member(&mut t, family, 2);
+    t.step();
+
+    t.advance_clock(Duration::from_secs(3));
+    t.step().step();
+    assert!(t.world().get_entity(one).is_none());
+    assert!(t.world().get_entity(two).is_some());
+    assert_eq!(family_len(&t, family), 1);
+}
+
+#[test]
+fn evicting_a_member_orphans_what_depends_on_it() {
+    let mut t = LazySignalsTestApp::new();
+    let (base, family) = t.commands(|commands| {
+        let base = LazySignals.state(10u32, commands);
+        let family = LazySignals.family::<u32>(
+            move |key, commands| {
+                let key = *key;
+                LazySignals.computed::<(Option<u32>,), u32>(
+                    move |(base,)| LazySignals::option(base.map(|base| base + key)),
+                    vec![base],
+                    commands
+                )
+            },
+            MemoEviction::lru(1),
+            commands
+        );
+        (base, family)
+    });
+
+    let one = member(&mut t, family, 1);
+    t.step();
+    let total = t.commands(|commands| {
+        LazySignals.computed::<(Option<u32>,), u32>(
+            |(one,)| LazySignals::option(one.map(|one| one * 2)),
+            vec![one],
+            commands
+        )
+    });
+    t.commands(|commands| LazySignals.send(base, 20u32, commands));
+    t.step();
+    t.assert_signal_eq(total, 42u32);
+
+    // a second member pushes the first one out, and the memo reading it is told so
+    t.advance_clock(Duration::from_secs(1));
+    member(&mut t, family, 2);
+    t.step().step();
+    assert!(t.world().get_entity(one).is_none());
+    assert!(t.world().get_entity(total).is_some());
+    assert!(
+        matches!(
+            LazySignals.get_error::<u32>(total, t.world()),
+            Some(LazySignalsError::SourceRemoved(removed)) if removed == one
+        )
+    );
+}
@@ -0,0 +1,136 @@
+#![cfg(feature = "net")]
+
+use std::{
+    io::{ Read, Write },
+    net::{ TcpListener, TcpStream },
+    sync::mpsc::{ channel, Receiver },
+    thread,
+    time::{ Duration, Instant },
+};
+
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    fetch::{ FetchStatus, LazySignalsFetchPlugin },
+    testing::LazySignalsTestApp,
+};
+
+// serve each request on its own thread: `/slow` streams its body until the client hangs up (and
+// reports that it did), anything else answers with its own path
+fn serve() -> (String, Receiver<()>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    let (hung_up, receiver) = channel();
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let hung_up = hung_up.clone();
+            thread::spawn(move || respond(stream, || hung_up.send(()).unwrap()));
+        }
+    });
+    (base, receiver)
+}
+
+fn respond(mut stream: TcpStream, on_hang_up: impl FnOnce()) {
+    let mut request = Vec::new();
+    let mut buffer = [0u8; 1024];
+    while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+        let Ok(read @ 1..) = stream.read(&mut buffer) else {
+            return;
+        };
+        request.extend_from_slice(&buffer[..read]);
+    }
+    let request = String::from_utf8_lossy(&request).to_string();
+    let path = request.split_whitespace().nth(1).unwrap_or("/").to_string();
+
+    if path != "/slow" {
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            path.len(),
+            path
+        );
+        let _ = stream.write_all(response.as_bytes());
+        return;
+    }
+
+    let _ = stream.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 100000000\r\n\r\n");
+    let started = Instant::now();
+    while started.elapsed() < Duration::from_secs(10) {
+        if stream.write_all(&[b'x'; 1024]).and_then(|_| stream.flush()).is_err() {
+            on_hang_up();
+            return;
+        }
+        thread::sleep(Duration::from_millis(5));
+    }
+}
+
+fn fetch_app(url: String) -> (LazySignalsTestApp, Entity, Entity, Entity) {
+    let mut t = LazySignalsTestApp::new();
+    t.app.add_plugins(LazySignalsFetchPlugin);
+    let (url, fetch) = t.commands(|commands| {
+        let url = LazySignals.state(url, commands);
+        (url, LazySignals.fetch(url, commands))
+    });
+    (t, url, fetch.status, fetch.body)
+}
+
+// step until the status is no longer `Loading`
+fn settle(t: &mut LazySignalsTestApp, status: Entity) {
+    let started = Instant::now();
+    loop {
+        t.step();
+        if t.read::<FetchStatus>(status) != Some(FetchStatus::Loading) {
+            return;
+        }
+        assert!(started.elapsed() < Duration::from_secs(10), "fetch did not finish");
+        thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn refetches_when_the_url_changes() {
+    let (base, _) = serve();
+    let (mut t, url, status, body) = fetch_app(format!("{}/news", base));
+    t.step();
+    t.assert_signal_eq(status, FetchStatus::Loading);
+    settle(&mut t, status);
+    t.assert_signal_eq(status, FetchStatus::Done(200));
+    t.assert_signal_eq(body, "/news".to_string());
+
+    t.commands(|commands| LazySignals.send(url, format!("{}/leaderboard", base), commands));
+    t.step();
+    t.assert_signal_eq(status, FetchStatus::Loading);
+    // the last body is kept while the next one loads
+    t.assert_signal_eq(body, "/news".to_string());
+    settle(&mut t, status);
+    t.assert_signal_eq(body, "/leaderboard".to_string());
+}
+
+#[test]
+fn cancels_the_request_in_flight() {
+    let (base, hung_up) = serve();
+    let (mut t, url, status, body) = fetch_app(format!("{}/slow", base));
+    t.step_n(3);
+    t.assert_signal_eq(status, FetchStatus::Loading);
+
+    t.commands(|commands| LazySignals.send(url, format!("{}/fast", base), commands));
+    settle(&mut t, status);
+    t.assert_signal_eq(status, FetchStatus::Done(200));
+    t.assert_signal_eq(body, "/fast".to_string());
+
+    // the slow download was dropped, not left to run to the end
+    hung_up.recv_timeout(Duration::from_secs(5)).expect("canceled request kept downloading");
+    t.step_n(3);
+    t.assert_signal_eq(body, "/fast".to_string());
+}
+
+#[test]
+fn goes_idle_without_a_url() {
+    let (base, hung_up) = serve();
+    let (mut t, url, status, _) = fetch_app(format!("{}/slow", base));
+    t.step_n(3);
+    t.commands(|commands| LazySignals.send(url, String::new(), commands));
+    t.step();
+    t.assert_signal_eq(status, FetchStatus::Idle);
+    hung_up.recv_timeout(Duration::from_secs(5)).expect("canceled request kept downloading");
+}
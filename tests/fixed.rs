@@ -0,0 +1,61 @@
+#![cfg(feature = "common_types")]
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    fixed::{ Fixed32, Fixed64 },
+    testing::LazySignalsTestApp,
+};
+
+#[test]
+fn fixed_point_signals_work_with_operators_and_comparisons() {
+    let mut t = LazySignalsTestApp::new();
+    let (speed, boost, moved, fast) = t.commands(|commands| {
+        let speed = LazySignals.state(Fixed32::from_ratio(3, 2), commands);
+        let boost = LazySignals.state(Fixed32::ONE, commands);
+        let moved = LazySignals.mul::<Fixed32>(speed, boost, commands);
+        let fast = LazySignals.greater_than(moved, Fixed32::from_int(4), commands);
+        (speed, boost, moved, fast)
+    });
+    t.commands(|commands| LazySignals.send(boost, Fixed32::from_int(2), commands));
+    t.step();
+    t.assert_signal_eq(moved, Fixed32::from_int(3));
+    t.assert_signal_eq(fast, false);
+
+    // a third is not exact, but the result is the same on every machine
+    t.commands(|commands| LazySignals.send(speed, Fixed32::from_ratio(7, 3), commands));
+    t.step();
+    t.assert_signal_eq(moved, Fixed32::from_raw(Fixed32::from_ratio(7, 3).raw() * 2));
+    t.assert_signal_eq(fast, true);
+}
+
+#[test]
+fn fixed64_holds_more_range() {
+    let mut t = LazySignalsTestApp::new();
+    let (a, total) = t.commands(|commands| {
+        let a = LazySignals.state(Fixed64::ZERO, commands);
+        // past the integer range of a Fixed32
+        let b = LazySignals.state(Fixed64::from_int(1 << 20), commands);
+        (a, LazySignals.add::<Fixed64>(a, b, commands))
+    });
+    t.commands(|commands| LazySignals.send(a, Fixed64::from_ratio(1, 4), commands));
+    t.step();
+    t.assert_signal_eq(total, Fixed64::from_int(1 << 20) + Fixed64::from_ratio(1, 4));
+    assert_eq!(t.read::<Fixed64>(total).unwrap().floor(), 1 << 20);
+}
+
+#[test]
+fn division_rounds_toward_negative_infinity() {
+    // -1/3 is -21845.33 in raw units, which floors to -21846 rather than truncating to -21845
+    assert_eq!((Fixed32::from_int(-1) / Fixed32::from_int(3)).raw(), -21846);
+    assert_eq!((Fixed32::from_int(1) / Fixed32::from_int(-3)).raw(), -21846);
+    assert_eq!((Fixed32::from_int(-1) / Fixed32::from_int(-3)).raw(), 21845);
+    assert_eq!(Fixed32::from_ratio(-1, 3).raw(), -21846);
+    assert_eq!(Fixed32::from_int(-6) / Fixed32::from_int(3), Fixed32::from_int(-2));
+    assert_eq!((Fixed64::from_int(-1) / Fixed64::from_int(3)).floor(), -1);
+}
+
+#[test]
+#[should_panic(expected = "divide by zero")]
+fn from_ratio_panics_on_a_zero_denominator() {
+    Fixed32::from_ratio(1, 0);
+}
@@ -0,0 +1,19 @@
+use bevy_lazy_signals::{ api::LazySignals, formatted, testing::LazySignalsTestApp };
+
+#[test]
+fn formatted_builds_a_string_from_its_sources() {
+    let mut t = LazySignalsTestApp::new();
+    let (hp, hp_max, text) = t.commands(|commands| {
+        let hp = LazySignals.state(80u32, commands);
+        let hp_max = LazySignals.state(100u32, commands);
+        let text = formatted!(commands, "{hp} / {max}", hp: u32 = hp, max: u32 = hp_max);
+        (hp, hp_max, text)
+    });
+    t.commands(|commands| LazySignals.send(hp, 75u32, commands));
+    t.step();
+    t.assert_signal_eq(text, "75 / 100".to_string());
+
+    t.commands(|commands| LazySignals.send(hp_max, 120u32, commands));
+    t.step();
+    t.assert_signal_eq(text, "75 / 120".to_string());
+}
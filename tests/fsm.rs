@@ -0,0 +1,78 @@
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    fsm::FsmBuilder,
+    testing::LazySignalsTestApp,
+    LazySignalsAppExt,
+};
+
+#[derive(Reflect, Clone, Copy, PartialEq, Debug)]
+enum GameState {
+    Menu,
+    Playing,
+    Running,
+    Paused,
+}
+
+#[derive(Resource, Default)]
+struct Log(Vec<&'static str>);
+
+fn logger(name: &'static str, commands: &mut Commands) -> Entity {
+    LazySignals.effect::<()>(
+        move |_, world| {
+            world.resource_mut::<Log>().0.push(name);
+            None
+        },
+        vec![],
+        vec![],
+        commands
+    )
+}
+
+#[test]
+fn guards_move_the_machine_and_run_enter_and_exit_effects() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.register_signal_type::<GameState>().init_resource::<Log>();
+    let (state, start, pause, quit) = t.commands(|commands| {
+        let start = LazySignals.state(false, commands);
+        let pause = LazySignals.state(false, commands);
+        let quit = LazySignals.state(false, commands);
+        let spawn_level = logger("spawn level", commands);
+        let despawn_level = logger("despawn level", commands);
+        let state = LazySignals.fsm(
+            FsmBuilder::new(GameState::Menu)
+                .substate(GameState::Running, GameState::Playing)
+                .substate(GameState::Paused, GameState::Playing)
+                .transition(GameState::Menu, GameState::Running, start)
+                .transition(GameState::Running, GameState::Paused, pause)
+                .transition(GameState::Playing, GameState::Menu, quit)
+                .on_enter(GameState::Playing, spawn_level)
+                .on_exit(GameState::Playing, despawn_level),
+            commands
+        );
+        (state, start, pause, quit)
+    });
+    t.step();
+    t.assert_signal_eq(state, GameState::Menu);
+
+    t.commands(|commands| LazySignals.send(start, true, commands));
+    t.step_n(2);
+    t.assert_signal_eq(state, GameState::Running);
+    assert_eq!(t.world().resource::<Log>().0, vec!["spawn level"]);
+
+    // moving between substates stays inside Playing
+    t.commands(|commands| {
+        LazySignals.send(start, false, commands);
+        LazySignals.send(pause, true, commands);
+    });
+    t.step_n(2);
+    t.assert_signal_eq(state, GameState::Paused);
+    assert_eq!(t.world().resource::<Log>().0, vec!["spawn level"]);
+
+    // the transition of the parent applies to its substates
+    t.commands(|commands| LazySignals.send(quit, true, commands));
+    t.step_n(2);
+    t.assert_signal_eq(state, GameState::Menu);
+    assert_eq!(t.world().resource::<Log>().0, vec!["spawn level", "despawn level"]);
+}
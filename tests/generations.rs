@@ -0,0 +1,38 @@
+use bevy_lazy_signals::{
+    api::LazySignals,
+    framework::LazySignalsError,
+    handles::SignalHandle,
+    testing::LazySignalsTestApp,
+};
+
+#[test]
+fn handle_to_a_despawned_signal_is_stale() {
+    let mut t = LazySignalsTestApp::new();
+    let handle = t.commands(|commands| SignalHandle::create(5u32, commands));
+    t.step();
+    assert_eq!(handle.read(t.world()).unwrap(), Some(5));
+
+    t.commands(|commands| handle.send(6, commands));
+    t.step();
+    assert_eq!(handle.read(t.world()).unwrap(), Some(6));
+
+    let entity = handle.entity_unchecked();
+    t.world_mut().despawn(entity);
+
+    // even with the same entity alive again, the handle does not point at it
+    t.world_mut().get_or_spawn(entity).unwrap();
+    assert!(matches!(handle.read(t.world()), Err(LazySignalsError::StaleHandle(_))));
+    assert!(matches!(handle.send_now(7, t.world_mut()), Err(LazySignalsError::StaleHandle(_))));
+}
+
+#[test]
+fn handles_to_the_same_signal_share_a_generation() {
+    let mut t = LazySignalsTestApp::new();
+    let signal = t.commands(|commands| LazySignals.state(1u32, commands));
+    t.step();
+
+    let first = SignalHandle::<u32>::from_entity(signal, t.world_mut()).unwrap();
+    let second = SignalHandle::<u32>::from_entity(signal, t.world_mut()).unwrap();
+    first.send_now(2, t.world_mut()).unwrap();
+    assert_eq!(second.read(t.world()).unwrap(), Some(2));
+}
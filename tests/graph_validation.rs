@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    testing::LazySignalsTestApp,
+    validation::{ validate_graph, GraphProblem, LazySignalsValidationPlugin },
+};
+
+// a computed whose source was despawned behind the framework's back, next to a healthy one
+fn build_graph(world: &mut World) {
+    let gone = {
+        let commands = &mut world.commands();
+        let score = LazySignals.state(1u32, commands);
+        let gone = LazySignals.state(2u32, commands);
+        LazySignals.computed::<(Option<u32>,), u32>(
+            |(score,)| LazySignals::option(score),
+            vec![score],
+            commands
+        );
+        LazySignals.computed::<(Option<u32>,), u32>(
+            |(gone,)| LazySignals::option(gone),
+            vec![gone],
+            commands
+        );
+        gone
+    };
+    world.flush();
+    world.despawn(gone);
+}
+
+#[test]
+fn validate_graph_lists_every_problem() {
+    let mut t = LazySignalsTestApp::new();
+    build_graph(t.world_mut());
+    let problems = validate_graph(t.world_mut());
+    // both are about the computed that lost its only source
+    assert_eq!(problems.len(), 2);
+    assert!(matches!(problems[0].1, GraphProblem::MissingDependency(_)));
+    assert_eq!(problems[1].1, GraphProblem::NoLiveDependencies);
+}
+
+#[test]
+#[should_panic(expected = "signal graph validation found 2 problem(s)")]
+fn plugin_fails_right_after_startup() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.add_plugins(LazySignalsValidationPlugin::default()).add_systems(Startup, build_graph);
+    t.step();
+}
+
+#[test]
+fn plugin_passes_a_healthy_graph() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.add_plugins(LazySignalsValidationPlugin::default());
+    let (score, double) = t.commands(|commands| {
+        let score = LazySignals.state(1u32, commands);
+        let double = LazySignals.computed::<(Option<u32>,), u32>(
+            |(score,)| LazySignals::result(score.unwrap_or(0) * 2),
+            vec![score],
+            commands
+        );
+        (score, double)
+    });
+    t.step();
+    t.commands(|commands| LazySignals.send(score, 4u32, commands));
+    t.step();
+    t.assert_signal_eq(double, 8u32);
+}
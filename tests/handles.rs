@@ -0,0 +1,45 @@
+use std::sync::atomic::{ AtomicU32, Ordering };
+
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+static LAST: AtomicU32 = AtomicU32::new(0);
+
+#[test]
+fn getter_and_setter_read_and_send_the_same_signal() {
+    let mut t = LazySignalsTestApp::new();
+    let (get, set) = t.commands(|commands| LazySignals.create(1u32, commands));
+    t.step();
+    assert_eq!(get.read(t.world()), Some(1));
+
+    t.commands(|commands| set.send(5, commands));
+    t.step();
+    assert_eq!(get.read(t.world()), Some(5));
+    assert_eq!(get.entity(), set.entity());
+
+    set.send_now(6, t.world_mut());
+    assert_eq!(get.read(t.world()), Some(6));
+}
+
+#[test]
+fn read_only_handle_observes_changes() {
+    let mut t = LazySignalsTestApp::new();
+    let score = t.commands(|commands| LazySignals.create_rw(10u32, commands));
+
+    // a subsystem gets only the read half
+    let (read, write) = score.split();
+    assert_eq!(score.read_only().entity(), read.entity());
+    t.commands(|commands| {
+        read.subscribe(
+            |score, _world| {
+                LAST.store(score.unwrap_or(0), Ordering::SeqCst);
+            },
+            commands
+        )
+    });
+    t.step();
+
+    t.commands(|commands| write.send(42, commands));
+    t.step();
+    assert_eq!(LAST.load(Ordering::SeqCst), 42);
+    assert_eq!(read.read(t.world()), Some(42));
+}
@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+#[derive(Resource, Default)]
+struct Log(Vec<String>);
+
+#[test]
+fn registering_a_name_again_swaps_the_fn_in_place() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.init_resource::<Log>();
+    let (signal, doubled, label) = t.commands(|commands| {
+        LazySignals.register_computed::<(Option<u32>,), u32>(
+            "scale",
+            |(x,)| LazySignals::result(x.unwrap_or(0) * 2),
+            commands
+        );
+        LazySignals.register_effect::<(Option<u32>,)>(
+            "log",
+            |(x,), world| {
+                world.resource_mut::<Log>().0.push(format!("v1 {}", x.unwrap_or(0)));
+                None
+            },
+            commands
+        );
+        let signal = LazySignals.state(0u32, commands);
+        let doubled = LazySignals.computed_by_name::<(Option<u32>,), u32>(
+            "scale",
+            vec![signal],
+            commands
+        );
+        let label = LazySignals.computed::<(Option<u32>,), String>(
+            |(x,)| LazySignals::result(format!("{}", x.unwrap_or(0))),
+            vec![doubled],
+            commands
+        );
+        LazySignals.effect_by_name::<(Option<u32>,)>("log", vec![doubled], vec![], commands);
+        (signal, doubled, label)
+    });
+    t.step();
+    t.commands(|commands| LazySignals.send(signal, 3u32, commands));
+    t.step();
+    t.assert_signal_eq(doubled, 6u32);
+    t.assert_signal_eq(label, "6".to_string());
+
+    // a hot reload registers the same names again with new fns
+    t.commands(|commands| {
+        LazySignals.register_computed::<(Option<u32>,), u32>(
+            "scale",
+            |(x,)| LazySignals::result(x.unwrap_or(0) * 10),
+            commands
+        );
+        LazySignals.register_effect::<(Option<u32>,)>(
+            "log",
+            |(x,), world| {
+                world.resource_mut::<Log>().0.push(format!("v2 {}", x.unwrap_or(0)));
+                None
+            },
+            commands
+        );
+    });
+    t.step();
+    t.assert_signal_eq(doubled, 30u32);
+    t.assert_signal_eq(label, "30".to_string());
+
+    t.commands(|commands| LazySignals.send(signal, 4u32, commands));
+    t.step();
+    t.assert_signal_eq(doubled, 40u32);
+    let log = &t.world().resource::<Log>().0;
+    assert_eq!(log.first().map(String::as_str), Some("v1 6"));
+    assert_eq!(log.last().map(String::as_str), Some("v2 40"));
+}
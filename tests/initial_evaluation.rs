@@ -0,0 +1,44 @@
+use bevy_lazy_signals::{
+    api::LazySignals,
+    framework::{ InitialEvaluation, LazySignalsConfig },
+    testing::LazySignalsTestApp,
+};
+
+#[test]
+fn config_computes_new_memos_without_a_send() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.insert_resource(LazySignalsConfig { initial_evaluation: true });
+    let double = t.commands(|commands| {
+        let signal = LazySignals.state(3u32, commands);
+        LazySignals.computed::<(Option<u32>,), u32>(
+            |(value,)| LazySignals::result(value.unwrap_or(0) * 2),
+            vec![signal],
+            commands
+        )
+    });
+    t.step();
+    t.assert_signal_eq(double, 6u32);
+}
+
+#[test]
+fn per_primitive_flag_overrides_the_config() {
+    let mut t = LazySignalsTestApp::new();
+    let (eager, lazy) = t.commands(|commands| {
+        let signal = LazySignals.state(3u32, commands);
+        let eager = LazySignals.computed::<(Option<u32>,), u32>(
+            |(value,)| LazySignals::result(value.unwrap_or(0) + 1),
+            vec![signal],
+            commands
+        );
+        commands.entity(eager).insert(InitialEvaluation(true));
+        let lazy = LazySignals.computed::<(Option<u32>,), u32>(
+            |(value,)| LazySignals::result(value.unwrap_or(0) + 2),
+            vec![signal],
+            commands
+        );
+        (eager, lazy)
+    });
+    t.step();
+    t.assert_signal_eq(eager, 4u32);
+    t.assert_signal_none::<u32>(lazy);
+}
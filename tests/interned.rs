@@ -0,0 +1,36 @@
+use bevy_lazy_signals::{
+    api::LazySignals,
+    interned::SignalStr,
+    testing::LazySignalsTestApp,
+};
+
+#[test]
+fn interned_text_compares_by_id_and_is_reused() {
+    let mut t = LazySignalsTestApp::new();
+    let (fps, label) = t.commands(|commands| {
+        let fps = LazySignals.state(60u32, commands);
+        let label = LazySignals.lens::<u32, SignalStr>(
+            fps,
+            |fps| SignalStr::format(format_args!("{} FPS", fps)),
+            commands
+        );
+        (fps, label)
+    });
+    t.commands(|commands| LazySignals.send(fps, 59u32, commands));
+    t.step();
+    t.assert_signal_eq(label, SignalStr::new("59 FPS"));
+    assert_eq!(t.read::<SignalStr>(label).unwrap().as_str(), "59 FPS");
+
+    // cycling through text seen before interns nothing new
+    for fps_value in [60u32, 59] {
+        t.commands(|commands| LazySignals.send(fps, fps_value, commands));
+        t.step();
+    }
+    let interned = SignalStr::interned_count();
+    for fps_value in [60u32, 59, 60] {
+        t.commands(|commands| LazySignals.send(fps, fps_value, commands));
+        t.step();
+    }
+    assert_eq!(SignalStr::interned_count(), interned);
+    t.assert_signal_eq(label, SignalStr::new("60 FPS"));
+}
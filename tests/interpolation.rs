@@ -0,0 +1,38 @@
+#![cfg(feature = "common_types")]
+
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+#[test]
+fn render_rate_reads_blend_between_fixed_timesteps() {
+    let mut t = LazySignalsTestApp::new();
+    // only the fixed timestep run below, however long a step takes
+    t.app.insert_resource(Time::<Fixed>::from_seconds(3600.0));
+    let (speed, plain) = t.commands(|commands| {
+        let speed = LazySignals.state(0.0f32, commands);
+        let plain = LazySignals.state(0.0f32, commands);
+        (LazySignals.interpolated(speed, commands), plain)
+    });
+    t.commands(|commands| {
+        LazySignals.send(speed, 10.0f32, commands);
+        LazySignals.send(plain, 10.0f32, commands);
+    });
+    t.step();
+
+    // what a fixed timestep starts with, before the simulation sends the next value
+    t.world_mut().run_schedule(FixedFirst);
+    t.commands(|commands| {
+        LazySignals.send(speed, 20.0f32, commands);
+        LazySignals.send(plain, 20.0f32, commands);
+    });
+    t.step();
+
+    assert_eq!(LazySignals.read_interpolated::<f32>(speed, 0.0, t.world()), Some(10.0));
+    assert_eq!(LazySignals.read_interpolated::<f32>(speed, 0.25, t.world()), Some(12.5));
+    assert_eq!(LazySignals.read_interpolated::<f32>(speed, 1.0, t.world()), Some(20.0));
+    assert_eq!(LazySignals.read_interpolated::<f32>(speed, 2.0, t.world()), Some(20.0));
+
+    // a signal that was not marked is read as is
+    assert_eq!(LazySignals.read_interpolated::<f32>(plain, 0.25, t.world()), Some(20.0));
+}
@@ -0,0 +1,45 @@
+use bevy_lazy_signals::{
+    api::LazySignals,
+    family::LazySignalsMemoKeys,
+    testing::LazySignalsTestApp,
+};
+
+#[test]
+fn keyed_computeds_with_the_same_sources_share_one_memo() {
+    let mut t = LazySignalsTestApp::new();
+    let mut keys = LazySignalsMemoKeys::default();
+    let (volume, rows, other) = t.commands(|commands| {
+        let volume = LazySignals.state(5u32, commands);
+        let music = LazySignals.state(3u32, commands);
+
+        // a widget factory asking for the same memo once per row
+        let rows: Vec<_> = (0..3)
+            .map(|_| {
+                LazySignals.computed_keyed::<(Option<u32>,), String>(
+                    "volume_label",
+                    |(volume,)| LazySignals::result(format!("{}/10", volume.unwrap_or(0))),
+                    vec![volume],
+                    &mut keys,
+                    commands
+                )
+            })
+            .collect();
+
+        // other sources make another memo
+        let other = LazySignals.computed_keyed::<(Option<u32>,), String>(
+            "volume_label",
+            |(volume,)| LazySignals::result(format!("{}/10", volume.unwrap_or(0))),
+            vec![music],
+            &mut keys,
+            commands
+        );
+        (volume, rows, other)
+    });
+    assert!(rows.iter().all(|row| *row == rows[0]));
+    assert_ne!(other, rows[0]);
+    assert_eq!(keys.len(), 2);
+
+    t.commands(|commands| LazySignals.send(volume, 7u32, commands));
+    t.step();
+    t.assert_signal_eq(rows[0], "7/10".to_string());
+}
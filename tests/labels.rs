@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+use bevy_lazy_signals::{ api::LazySignals, framework::entity_label, testing::LazySignalsTestApp };
+
+#[test]
+fn labels_name_primitives_for_logs() {
+    let mut t = LazySignalsTestApp::new();
+    let (volume, unnamed) = t.commands(|commands| {
+        let volume = LazySignals.state(5u32, commands);
+        let volume = LazySignals.label(volume, "volume", commands);
+        let unnamed = LazySignals.state(1u32, commands);
+        (volume, unnamed)
+    });
+    t.step();
+
+    assert_eq!(t.world().get::<Name>(volume).map(Name::as_str), Some("volume"));
+    assert_eq!(entity_label(volume, t.world()), format!("volume ({:?})", volume));
+    assert_eq!(entity_label(unnamed, t.world()), format!("{:?}", unnamed));
+
+    // labeling does not get in the way of the signal itself
+    t.commands(|commands| LazySignals.send(volume, 6u32, commands));
+    t.step();
+    t.assert_signal_eq(volume, 6u32);
+}
@@ -0,0 +1,61 @@
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+#[test]
+fn latch_is_set_and_cleared_by_its_triggers() {
+    let mut t = LazySignalsTestApp::new();
+    let (open, close, door_open) = t.commands(|commands| {
+        let open = LazySignals.state((), commands);
+        let close = LazySignals.state((), commands);
+        let door_open = LazySignals.latch(open, close, commands);
+        (open, close, door_open)
+    });
+    t.step();
+    t.assert_signal_eq(door_open, false);
+
+    t.commands(|commands| LazySignals.trigger(open, commands));
+    t.step();
+    t.assert_signal_eq(door_open, true);
+
+    // it stays set until the other trigger fires
+    t.step_n(3);
+    t.assert_signal_eq(door_open, true);
+
+    t.commands(|commands| LazySignals.trigger(close, commands));
+    t.step();
+    t.assert_signal_eq(door_open, false);
+}
+
+#[test]
+fn reset_wins_when_both_fire_together() {
+    let mut t = LazySignalsTestApp::new();
+    let (open, close, door_open) = t.commands(|commands| {
+        let open = LazySignals.state((), commands);
+        let close = LazySignals.state((), commands);
+        let door_open = LazySignals.latch(open, close, commands);
+        (open, close, door_open)
+    });
+    t.step();
+
+    t.commands(|commands| {
+        LazySignals.trigger(open, commands);
+        LazySignals.trigger(close, commands);
+    });
+    t.step();
+    t.assert_signal_eq(door_open, false);
+
+    // the order they are sent in makes no difference, nor does the latch already being set
+    t.commands(|commands| LazySignals.trigger(open, commands));
+    t.step();
+    t.assert_signal_eq(door_open, true);
+    t.commands(|commands| {
+        LazySignals.trigger(close, commands);
+        LazySignals.trigger(open, commands);
+    });
+    t.step();
+    t.assert_signal_eq(door_open, false);
+
+    // and a set in a later batch still works
+    t.commands(|commands| LazySignals.trigger(open, commands));
+    t.step();
+    t.assert_signal_eq(door_open, true);
+}
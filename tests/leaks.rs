@@ -0,0 +1,40 @@
+use bevy_lazy_signals::{
+    api::LazySignals,
+    leaks::{ LazySignalsLeakDetection, LazySignalsLeakDetectionPlugin, LeakKind },
+    stats::LazySignalsStats,
+    testing::LazySignalsTestApp,
+};
+
+#[test]
+fn orphaned_and_unread_primitives_are_reported() {
+    let mut t = LazySignalsTestApp::new();
+    t.app
+        .add_plugins(LazySignalsLeakDetectionPlugin)
+        .insert_resource(LazySignalsLeakDetection::new(1, 3));
+    let (source, orphan, forgotten, watched) = t.commands(|commands| {
+        let source = LazySignals.state(1u32, commands);
+        let orphan = LazySignals.computed::<(Option<u32>,), u32>(
+            |(value,)| LazySignals::result(value.unwrap_or(0) + 1),
+            vec![source],
+            commands
+        );
+        let forgotten = LazySignals.state(2u32, commands);
+        let watched = LazySignals.state(3u32, commands);
+        LazySignals.effect::<(Option<u32>,)>(|_, _world| None, vec![watched], vec![], commands);
+        (source, orphan, forgotten, watched)
+    });
+    t.step();
+    t.world_mut().despawn(source);
+    t.step_n(5);
+
+    let leaks = t.world().resource::<LazySignalsStats>().leaks().to_vec();
+    assert!(leaks.contains(&(orphan, LeakKind::Orphaned)));
+    assert!(
+        leaks
+            .iter()
+            .any(|leak| matches!(leak, (entity, LeakKind::Unread(_)) if *entity == forgotten))
+    );
+
+    // a signal something still subscribes to is not a leak, however long it goes unread
+    assert!(leaks.iter().all(|(entity, _)| *entity != watched));
+}
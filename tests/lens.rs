@@ -0,0 +1,59 @@
+use std::sync::atomic::{ AtomicU32, Ordering };
+
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp, LazySignalsAppExt };
+
+static VOLUME_RUNS: AtomicU32 = AtomicU32::new(0);
+
+#[derive(Reflect, Clone, Copy, PartialEq, Debug)]
+struct AudioConfig {
+    volume: u32,
+    muted: bool,
+}
+
+#[test]
+fn lens_notifies_only_when_its_field_changes() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.register_signal_type::<AudioConfig>();
+    let (config, volume) = t.commands(|commands| {
+        let config = LazySignals.state(AudioConfig { volume: 5, muted: false }, commands);
+        let volume = LazySignals.lens::<AudioConfig, u32>(config, |config| config.volume, commands);
+        LazySignals.effect::<(Option<u32>,)>(
+            |_, _world| {
+                VOLUME_RUNS.fetch_add(1, Ordering::SeqCst);
+                None
+            },
+            vec![volume],
+            vec![],
+            commands
+        );
+        (config, volume)
+    });
+    t.commands(|commands| {
+        LazySignals.send(config, AudioConfig { volume: 7, muted: false }, commands)
+    });
+    t.step();
+    t.assert_signal_eq(volume, 7u32);
+    let runs = VOLUME_RUNS.load(Ordering::SeqCst);
+
+    // another field changing leaves the lens, and what reads it, alone
+    t.commands(|commands| {
+        LazySignals.send(config, AudioConfig { volume: 7, muted: true }, commands)
+    });
+    t.step();
+    assert_eq!(VOLUME_RUNS.load(Ordering::SeqCst), runs);
+}
+
+#[test]
+fn clamp_keeps_the_projection_in_range() {
+    let mut t = LazySignalsTestApp::new();
+    let (source, clamped) = t.commands(|commands| {
+        let source = LazySignals.state(5u32, commands);
+        let clamped = LazySignals.clamp(source, 0u32, 10u32, commands);
+        (source, clamped)
+    });
+    t.commands(|commands| LazySignals.send(source, 25u32, commands));
+    t.step();
+    t.assert_signal_eq(clamped, 10u32);
+}
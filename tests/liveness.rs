@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{ api::LazySignals, framework::*, testing::LazySignalsTestApp };
+
+#[test]
+fn clears_when_the_target_is_despawned() {
+    let mut t = LazySignalsTestApp::new();
+    let (enemy, target) = t.commands(|commands| {
+        let enemy = commands.spawn_empty().id();
+        let target = LazySignals.entity_state(enemy, LivenessPolicy::Clear, commands);
+        (enemy, target)
+    });
+    t.step();
+    t.assert_signal_eq(target, enemy);
+
+    t.world_mut().despawn(enemy);
+    t.step();
+    t.assert_signal_none::<Entity>(target);
+}
+
+#[test]
+fn errors_when_the_target_is_despawned() {
+    let mut t = LazySignalsTestApp::new();
+    let (enemy, target, label) = t.commands(|commands| {
+        let enemy = commands.spawn_empty().id();
+        let target = LazySignals.entity_state(enemy, LivenessPolicy::Error, commands);
+        let label = LazySignals.computed::<(Option<Entity>,), bool>(
+            |(target,)| LazySignals::result(target.is_some()),
+            vec![target],
+            commands
+        );
+        (enemy, target, label)
+    });
+    t.step();
+
+    t.world_mut().despawn(enemy);
+    t.step();
+    let error = LazySignals.get_error::<Entity>(target, t.world());
+    assert!(matches!(error, Some(LazySignalsError::EntityDespawned(_))), "{:?}", error);
+    t.assert_signal_eq(label, false);
+}
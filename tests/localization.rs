@@ -0,0 +1,47 @@
+#![cfg(feature = "localization")]
+
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    localization::{ LazySignalsLocalizationPlugin, Localization },
+    testing::LazySignalsTestApp,
+};
+
+const TABLE: &[u8] = b"key,en-US,fr-FR\nmenu.play,Play,Jouer\nmenu.quit,Quit,\n";
+
+#[test]
+fn text_follows_the_key_locale_and_table() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.add_plugins((AssetPlugin::default(), LazySignalsLocalizationPlugin));
+    let table = Localization::from_csv(TABLE).unwrap();
+    let handle = t.world_mut().resource_mut::<Assets<Localization>>().add(table);
+    let (key, locale, text) = t.commands(|commands| {
+        let key = LazySignals.state("menu.play", commands);
+        let locale = LazySignals.state("en-US", commands);
+        let text = LazySignals.localize::<&'static str, &'static str>(
+            key,
+            locale,
+            handle.clone(),
+            commands
+        );
+        (key, locale, text)
+    });
+    t.step_n(2);
+    t.assert_signal_eq(text, "Play".to_string());
+
+    t.commands(|commands| LazySignals.send(locale, "fr-FR", commands));
+    t.step();
+    t.assert_signal_eq(text, "Jouer".to_string());
+
+    // a missing translation falls back to the first locale
+    t.commands(|commands| LazySignals.send(key, "menu.quit", commands));
+    t.step();
+    t.assert_signal_eq(text, "Quit".to_string());
+
+    // a hot-reloaded table updates the text without a send
+    let table = Localization::from_csv(b"key,en-US,fr-FR\nmenu.quit,Quit,Quitter\n").unwrap();
+    t.world_mut().resource_mut::<Assets<Localization>>().insert(&handle, table);
+    t.step_n(2);
+    t.assert_signal_eq(text, "Quitter".to_string());
+}
@@ -0,0 +1,46 @@
+use std::sync::atomic::{ AtomicU32, Ordering };
+
+use bevy_lazy_signals::{ api::LazySignals, computed, effect, testing::LazySignalsTestApp };
+
+static TOTAL: AtomicU32 = AtomicU32::new(0);
+
+#[test]
+fn params_line_up_with_their_sources() {
+    let mut t = LazySignalsTestApp::new();
+    let (count, enabled, fire, shown) = t.commands(|commands| {
+        let count = LazySignals.state(2u32, commands);
+        let enabled = LazySignals.state(true, commands);
+        let fire = LazySignals.state((), commands);
+
+        // listed in another order than they were created, to catch a mix-up
+        let shown = computed!(commands, |on: bool = enabled, n: u32 = count| {
+            LazySignals::option(on.zip(n).map(|(on, n)| if on { n } else { 0 }))
+        });
+        effect!(
+            commands,
+            |_world, n: u32 = shown| {
+                TOTAL.store(n.unwrap_or(0), Ordering::SeqCst);
+                None
+            },
+            [fire]
+        );
+        (count, enabled, fire, shown)
+    });
+    t.step();
+
+    t.commands(|commands| LazySignals.send(count, 7u32, commands));
+    t.step();
+    t.assert_signal_eq(shown, 7u32);
+    assert_eq!(TOTAL.load(Ordering::SeqCst), 7);
+
+    t.commands(|commands| LazySignals.send(enabled, false, commands));
+    t.step();
+    t.assert_signal_eq(shown, 0u32);
+    assert_eq!(TOTAL.load(Ordering::SeqCst), 0);
+
+    // the trigger runs the effect without a change
+    TOTAL.store(99, Ordering::SeqCst);
+    t.commands(|commands| LazySignals.trigger(fire, commands));
+    t.step();
+    assert_eq!(TOTAL.load(Ordering::SeqCst), 0);
+}
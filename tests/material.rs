@@ -0,0 +1,35 @@
+#![cfg(feature = "assets")]
+
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+#[derive(Asset, TypePath)]
+struct Dissolve {
+    amount: f64,
+}
+
+#[test]
+fn signal_drives_a_material_field() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.add_plugins(AssetPlugin::default()).init_asset::<Dissolve>();
+    let material = t.world_mut().resource_mut::<Assets<Dissolve>>().add(Dissolve { amount: 0.0 });
+    let amount = t.commands(|commands| {
+        let amount = LazySignals.state(0.0f64, commands);
+        LazySignals.bind_material_field::<Dissolve, f64>(
+            amount,
+            material.clone(),
+            |material, amount| {
+                material.amount = amount;
+            },
+            commands
+        );
+        amount
+    });
+    t.step();
+
+    t.commands(|commands| LazySignals.send(amount, 0.25f64, commands));
+    t.step();
+    let materials = t.world().resource::<Assets<Dissolve>>();
+    assert_eq!(materials.get(&material).unwrap().amount, 0.25);
+}
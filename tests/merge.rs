@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    framework::MergedValue,
+    testing::LazySignalsTestApp,
+    LazySignalsAppExt,
+};
+
+#[derive(Reflect, Clone, Copy, PartialEq, Debug)]
+enum Input {
+    Keyboard(u32),
+    Gamepad(u32),
+}
+
+#[test]
+fn merge_tags_the_latest_value_with_its_source() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.register_signal_type::<MergedValue<u32>>();
+    let (a, b, merged) = t.commands(|commands| {
+        let a = LazySignals.state(0u32, commands);
+        let b = LazySignals.state(0u32, commands);
+        let merged = LazySignals.merge::<u32>(vec![a, b], commands);
+        (a, b, merged)
+    });
+    t.step();
+
+    t.commands(|commands| LazySignals.send(b, 7u32, commands));
+    t.step();
+    let value = t.read::<MergedValue<u32>>(merged).unwrap();
+    assert_eq!((value.index, value.source, value.value), (1, b, 7));
+
+    t.commands(|commands| LazySignals.send(a, 3u32, commands));
+    t.step();
+    let value = t.read::<MergedValue<u32>>(merged).unwrap();
+    assert_eq!((value.index, value.source, value.value), (0, a, 3));
+}
+
+#[test]
+fn merge_with_maps_each_source_into_a_common_type() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.register_signal_type::<Input>();
+    let (keyboard, gamepad, input) = t.commands(|commands| {
+        let keyboard = LazySignals.state(0u32, commands);
+        let gamepad = LazySignals.state(0u32, commands);
+        let input = LazySignals.merge_with::<u32, Input>(
+            vec![keyboard, gamepad],
+            |index, code| {
+                match index {
+                    0 => Input::Keyboard(code),
+                    _ => Input::Gamepad(code),
+                }
+            },
+            commands
+        );
+        (keyboard, gamepad, input)
+    });
+    t.step();
+
+    t.commands(|commands| LazySignals.send(gamepad, 2u32, commands));
+    t.step();
+    t.assert_signal_eq(input, Input::Gamepad(2));
+
+    t.commands(|commands| LazySignals.send(keyboard, 5u32, commands));
+    t.step();
+    t.assert_signal_eq(input, Input::Keyboard(5));
+}
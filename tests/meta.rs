@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    introspection::LazySignalsMeta,
+    testing::LazySignalsTestApp,
+};
+
+#[test]
+fn meta_describes_a_primitive_for_tooling() {
+    let mut t = LazySignalsTestApp::new();
+    let (health, named) = t.commands(|commands| {
+        let health = LazySignals.meta(
+            LazySignals.state(100u32, commands),
+            LazySignalsMeta::new("health")
+                .with_description("hit points of the player")
+                .with_tag("combat")
+                .owned_by(module_path!()),
+            commands
+        );
+        let named = LazySignals.label(LazySignals.state(0u32, commands), "kills", commands);
+        LazySignals.meta(named, LazySignalsMeta::new("frags"), commands);
+        (health, named)
+    });
+    t.step();
+
+    // the meta name becomes the Name unless there already is one
+    assert_eq!(t.world().get::<Name>(health).map(Name::as_str), Some("health"));
+    assert_eq!(t.world().get::<Name>(named).map(Name::as_str), Some("kills"));
+
+    let infos = LazySignals.enumerate(t.world());
+    let meta = infos
+        .iter()
+        .find(|info| info.entity == health)
+        .and_then(|info| info.meta.clone())
+        .unwrap();
+    assert_eq!(meta.description.as_deref(), Some("hit points of the player"));
+    assert_eq!(meta.tags, vec!["combat".to_string()]);
+    assert_eq!(meta.owner_module, Some("meta"));
+}
@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    middleware::MiddlewareDecision,
+    testing::LazySignalsTestApp,
+    LazySignalsAppExt,
+};
+
+#[derive(Resource)]
+struct Locked(Entity);
+
+#[test]
+fn middleware_rewrites_and_drops_sends_in_order() {
+    let mut t = LazySignalsTestApp::new();
+    let (volume, locked) = t.commands(|commands| {
+        let volume = LazySignals.state(5u32, commands);
+        let locked = LazySignals.state(1u32, commands);
+        (volume, locked)
+    });
+    t.app
+        .insert_resource(Locked(locked))
+        .add_send_middleware(|_, value, _world| {
+            if let Some(value) = value.downcast_mut::<u32>() {
+                *value = (*value).min(10);
+            }
+            MiddlewareDecision::Continue
+        })
+        .add_send_middleware(|signal, _, world| {
+            match world.resource::<Locked>().0 == signal {
+                true => MiddlewareDecision::Drop,
+                false => MiddlewareDecision::Continue,
+            }
+        });
+    t.step();
+
+    t.commands(|commands| {
+        LazySignals.send(volume, 42u32, commands);
+        LazySignals.send(locked, 2u32, commands);
+    });
+    t.step();
+    t.assert_signal_eq(volume, 10u32);
+    t.assert_signal_eq(locked, 1u32);
+}
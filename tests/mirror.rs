@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    mirror::{ extract_mirrored, Mirrored, MirroredSignals },
+    testing::LazySignalsTestApp,
+};
+
+#[test]
+fn marked_values_reach_another_world() {
+    let mut t = LazySignalsTestApp::new();
+    let (glow, hidden) = t.commands(|commands| {
+        let glow = LazySignals.state(0.5f64, commands);
+        let hidden = LazySignals.state(1u32, commands);
+        commands.entity(glow).insert(Mirrored);
+        (glow, hidden)
+    });
+    t.step();
+
+    // e.g. the render world, where the signal entities do not exist
+    let mut sub_world = World::new();
+    extract_mirrored(t.world_mut(), &mut sub_world);
+    let mirrored = sub_world.resource::<MirroredSignals>();
+    assert_eq!(mirrored.get::<f64>(glow), Some(0.5));
+    assert_eq!(mirrored.get::<u32>(hidden), None);
+
+    t.commands(|commands| LazySignals.send(glow, 0.75f64, commands));
+    t.step();
+    extract_mirrored(t.world_mut(), &mut sub_world);
+    assert_eq!(sub_world.resource::<MirroredSignals>().get::<f64>(glow), Some(0.75));
+}
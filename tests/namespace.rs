@@ -0,0 +1,59 @@
+use bevy_lazy_signals::{
+    api::LazySignals,
+    namespace::NamespaceSnapshot,
+    testing::LazySignalsTestApp,
+};
+
+#[test]
+fn paths_look_up_single_signals_and_subtrees() {
+    let mut t = LazySignalsTestApp::new();
+    let (volume, muted, health) = t.commands(|commands| {
+        let volume = LazySignals.state(5u32, commands);
+        let muted = LazySignals.state(false, commands);
+        let health = LazySignals.state(100u32, commands);
+        LazySignals.register_path("ui.settings.volume", volume, commands);
+        LazySignals.register_path("ui.settings.muted", muted, commands);
+        LazySignals.register_path("ui.hud.health", health, commands);
+        (volume, muted, health)
+    });
+    t.step();
+
+    assert_eq!(LazySignals.lookup("ui.settings.volume", t.world()), Some(volume));
+    assert_eq!(LazySignals.lookup("ui.hud.health", t.world()), Some(health));
+    assert_eq!(LazySignals.lookup("ui.settings.brightness", t.world()), None);
+
+    let mut settings = LazySignals.lookup_prefix("ui.settings.", t.world());
+    settings.sort();
+    assert_eq!(
+        settings,
+        vec![("ui.settings.muted".to_string(), muted), ("ui.settings.volume".to_string(), volume)]
+    );
+}
+
+#[test]
+fn snapshot_of_a_subtree_restores_its_values() {
+    let mut t = LazySignalsTestApp::new();
+    let (volume, health) = t.commands(|commands| {
+        let volume = LazySignals.state(5u32, commands);
+        let health = LazySignals.state(100u32, commands);
+        LazySignals.register_path("ui.settings.volume", volume, commands);
+        LazySignals.register_path("ui.hud.health", health, commands);
+        (volume, health)
+    });
+    t.step();
+    let snapshot = NamespaceSnapshot::capture("ui.settings.", t.world_mut());
+    assert_eq!(snapshot.get::<u32>("ui.settings.volume"), Some(5));
+    assert_eq!(snapshot.paths().collect::<Vec<_>>(), vec!["ui.settings.volume"]);
+
+    t.commands(|commands| {
+        LazySignals.send(volume, 9u32, commands);
+        LazySignals.send(health, 50u32, commands);
+    });
+    t.step();
+
+    // only the captured subtree goes back
+    snapshot.restore(t.world_mut());
+    t.step();
+    t.assert_signal_eq(volume, 5u32);
+    t.assert_signal_eq(health, 50u32);
+}
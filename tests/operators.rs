@@ -0,0 +1,47 @@
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+#[test]
+fn boolean_operators_follow_their_sources() {
+    let mut t = LazySignalsTestApp::new();
+    let (a, b, and, or, not) = t.commands(|commands| {
+        let a = LazySignals.state(false, commands);
+        let b = LazySignals.state(false, commands);
+        let and = LazySignals.and(a, b, commands);
+        let or = LazySignals.or(a, b, commands);
+        let not = LazySignals.not(a, commands);
+        (a, b, and, or, not)
+    });
+    t.commands(|commands| LazySignals.send(a, true, commands));
+    t.step();
+    t.assert_signal_eq(and, false);
+    t.assert_signal_eq(or, true);
+    t.assert_signal_eq(not, false);
+
+    t.commands(|commands| LazySignals.send(b, true, commands));
+    t.step();
+    t.assert_signal_eq(and, true);
+}
+
+#[test]
+fn arithmetic_operators_follow_their_sources() {
+    let mut t = LazySignalsTestApp::new();
+    let (a, b, ops) = t.commands(|commands| {
+        let a = LazySignals.state(6u32, commands);
+        let b = LazySignals.state(2u32, commands);
+        let ops = [
+            LazySignals.add::<u32>(a, b, commands),
+            LazySignals.sub::<u32>(a, b, commands),
+            LazySignals.mul::<u32>(a, b, commands),
+            LazySignals.min::<u32>(a, b, commands),
+            LazySignals.max::<u32>(a, b, commands),
+        ];
+        (a, b, ops)
+    });
+    t.commands(|commands| {
+        LazySignals.send(a, 9u32, commands);
+        LazySignals.send(b, 3u32, commands);
+    });
+    t.step();
+    let values: Vec<_> = ops.iter().map(|op| t.read::<u32>(*op).unwrap()).collect();
+    assert_eq!(values, vec![12, 6, 27, 3, 9]);
+}
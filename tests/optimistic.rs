@@ -0,0 +1,30 @@
+use std::time::Duration;
+
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+#[test]
+fn unconfirmed_sends_roll_back_after_the_timeout() {
+    let mut t = LazySignalsTestApp::new();
+    let (likes, confirmed) = t.commands(|commands| {
+        (LazySignals.state(10u32, commands), LazySignals.state((), commands))
+    });
+    t.step();
+
+    let timeout = Duration::from_secs(1);
+    t.commands(|commands| LazySignals.send_optimistic(likes, 11u32, confirmed, timeout, commands));
+    t.step();
+    t.assert_signal_eq(likes, 11u32);
+
+    // the server never answers
+    t.advance_clock(Duration::from_secs(2));
+    t.step_n(2);
+    t.assert_signal_eq(likes, 10u32);
+
+    t.commands(|commands| LazySignals.send_optimistic(likes, 12u32, confirmed, timeout, commands));
+    t.step();
+    t.commands(|commands| LazySignals.trigger(confirmed, commands));
+    t.step();
+    t.advance_clock(Duration::from_secs(2));
+    t.step_n(2);
+    t.assert_signal_eq(likes, 12u32);
+}
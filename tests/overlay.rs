@@ -0,0 +1,58 @@
+#![cfg(feature = "overlay")]
+
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    overlay::{ record_overlay, LazySignalsOverlay, NodeActivity, OverlayGraph },
+    testing::LazySignalsTestApp,
+    LazySignalsSystemSet,
+};
+
+#[test]
+fn overlay_lays_out_the_graph_and_lights_up_the_wavefront() {
+    let mut t = LazySignalsTestApp::new();
+    // what `LazySignalsOverlayPlugin` adds, minus drawing, which needs the gizmos of a renderer
+    t.app
+        .init_resource::<LazySignalsOverlay>()
+        .init_resource::<OverlayGraph>()
+        .add_systems(PreUpdate, record_overlay.after(LazySignalsSystemSet));
+    let (signal, double, effect, idle) = t.commands(|commands| {
+        let signal = LazySignals.state(1u32, commands);
+        let double = LazySignals.computed::<(Option<u32>,), u32>(
+            |(value,)| LazySignals::result(value.unwrap_or(0) * 2),
+            vec![signal],
+            commands
+        );
+        let effect = LazySignals.effect::<(Option<u32>,)>(
+            |_, _world| None,
+            vec![double],
+            vec![],
+            commands
+        );
+        let idle = LazySignals.state(5u32, commands);
+        (signal, double, effect, idle)
+    });
+    t.step();
+
+    t.commands(|commands| LazySignals.send(signal, 2u32, commands));
+    t.step();
+    let graph = t.world().resource::<OverlayGraph>();
+    let node = |entity| graph.get(entity).unwrap();
+    assert_eq!((node(signal).depth, node(double).depth, node(effect).depth), (0, 1, 2));
+    assert!(node(effect).is_effect);
+    assert!(graph.edges().contains(&(signal, double)));
+    assert!(graph.edges().contains(&(double, effect)));
+
+    assert_eq!(node(signal).activity.map(|(activity, _)| activity), Some(NodeActivity::Sent));
+    assert_eq!(
+        node(double).activity.map(|(activity, _)| activity),
+        Some(NodeActivity::Recomputed)
+    );
+    assert_eq!(node(effect).activity.map(|(activity, _)| activity), Some(NodeActivity::EffectRan));
+    assert!(node(idle).activity.is_none());
+
+    // deeper nodes light up later
+    let lit_at = |entity| node(entity).activity.unwrap().1;
+    assert!(lit_at(signal) < lit_at(double) && lit_at(double) < lit_at(effect));
+}
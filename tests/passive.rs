@@ -0,0 +1,33 @@
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+#[test]
+fn passive_sources_do_not_trigger_a_recompute() {
+    let mut t = LazySignalsTestApp::new();
+    let (clicks, clock, clicked_at) = t.commands(|commands| {
+        let clicks = LazySignals.state(0u32, commands);
+        let clock = LazySignals.state(0u32, commands);
+        let clicked_at = LazySignals.computed::<(Option<u32>, Option<u32>), u32>(
+            |(_clicks, clock)| LazySignals::result(clock.unwrap_or(0)),
+            vec![clicks, clock],
+            commands
+        );
+        LazySignals.passive_sources(clicked_at, vec![clock], commands);
+        (clicks, clock, clicked_at)
+    });
+    t.step();
+    t.commands(|commands| LazySignals.send(clicks, 1u32, commands));
+    t.step();
+    t.assert_signal_eq(clicked_at, 0u32);
+
+    // the clock ticking alone leaves the memo alone
+    for time in 1..4u32 {
+        t.commands(|commands| LazySignals.send(clock, time, commands));
+        t.step();
+    }
+    t.assert_signal_eq(clicked_at, 0u32);
+
+    // the next click picks up the latest time
+    t.commands(|commands| LazySignals.send(clicks, 2u32, commands));
+    t.step();
+    t.assert_signal_eq(clicked_at, 3u32);
+}
@@ -0,0 +1,32 @@
+use std::sync::atomic::{ AtomicU32, Ordering };
+
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+static CLICKED: AtomicU32 = AtomicU32::new(0);
+
+#[test]
+fn payload_reaches_subscribers_for_one_batch() {
+    let mut t = LazySignalsTestApp::new();
+    let trigger = t.commands(|commands| {
+        let trigger = LazySignals.payload_trigger::<u32>(commands);
+        LazySignals.effect::<(Option<u32>,)>(
+            |(clicked,), _world| {
+                CLICKED.store(clicked.unwrap_or(0), Ordering::SeqCst);
+                None
+            },
+            vec![trigger],
+            vec![],
+            commands
+        );
+        trigger
+    });
+    t.step();
+
+    t.commands(|commands| LazySignals.trigger_with(trigger, 42u32, commands));
+    t.step();
+    assert_eq!(CLICKED.load(Ordering::SeqCst), 42);
+
+    // nothing is kept once the batch is over
+    t.step();
+    t.assert_signal_none::<u32>(trigger);
+}
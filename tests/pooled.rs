@@ -0,0 +1,44 @@
+use bevy::{ ecs::world::CommandQueue, prelude::* };
+
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+#[derive(Component)]
+struct Terrain(u32);
+
+#[test]
+fn pooled_effects_queue_their_changes_for_the_world() {
+    let mut t = LazySignalsTestApp::new();
+    let terrain = t.world_mut().spawn(Terrain(0)).id();
+    let seeds = t.commands(|commands| {
+        let seeds: Vec<_> = (0..3).map(|_| LazySignals.state(0u32, commands)).collect();
+        for (index, seed) in seeds.iter().enumerate() {
+            LazySignals.pooled_effect::<(Option<u32>,)>(
+                move |(seed,)| {
+                    // stand-in for something expensive, with no world in sight
+                    let height = (0..1000u32).fold(seed.unwrap_or(0), |acc, i| acc.wrapping_add(i));
+                    let mut queue = CommandQueue::default();
+                    queue.push(move |world: &mut World| {
+                        world.get_mut::<Terrain>(terrain).unwrap().0 += height + index as u32;
+                    });
+                    queue
+                },
+                vec![*seed],
+                vec![],
+                commands
+            );
+        }
+        seeds
+    });
+    t.step();
+
+    t.commands(|commands| {
+        for seed in seeds.iter() {
+            LazySignals.send(*seed, 1u32, commands);
+        }
+    });
+    t.step();
+
+    // each of the three ran once, and all of their queues were applied
+    let height = (0..1000u32).sum::<u32>() + 1;
+    assert_eq!(t.world().get::<Terrain>(terrain).unwrap().0, height * 3 + 3);
+}
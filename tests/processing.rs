@@ -0,0 +1,119 @@
+use std::sync::atomic::{ AtomicU32, Ordering };
+
+use bevy::ecs::schedule::ScheduleLabel;
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    processing::{ LazySignalsProcessingPlugin, ProcessingClass },
+    testing::LazySignalsTestApp,
+};
+
+static SHOWN: AtomicU32 = AtomicU32::new(0);
+static FLUSHED: AtomicU32 = AtomicU32::new(0);
+static TRIGGERED: AtomicU32 = AtomicU32::new(0);
+
+#[derive(ScheduleLabel, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+struct AfterLayout;
+
+#[test]
+fn classed_effect_runs_in_its_own_schedule() {
+    let mut t = LazySignalsTestApp::new();
+    t.app
+        .init_schedule(AfterLayout)
+        .add_plugins(
+            LazySignalsProcessingPlugin::default().with_schedule(ProcessingClass::Ui, AfterLayout)
+        );
+    let (signal, double) = t.commands(|commands| {
+        let signal = LazySignals.state(1u32, commands);
+        let double = LazySignals.computed::<(Option<u32>,), u32>(
+            |(value,)| LazySignals::result(value.unwrap_or(0) * 2),
+            vec![signal],
+            commands
+        );
+        let effect = LazySignals.effect::<(Option<u32>,)>(
+            |(value,), _world| {
+                SHOWN.store(value.unwrap_or(0), Ordering::SeqCst);
+                None
+            },
+            vec![double],
+            vec![],
+            commands
+        );
+        LazySignals.with_class(ProcessingClass::Ui, effect, commands);
+        (signal, double)
+    });
+    t.commands(|commands| LazySignals.send(signal, 4u32, commands));
+
+    // the memo resolves with the chain, the effect waits for its class
+    t.step();
+    t.assert_signal_eq(double, 8u32);
+    assert_eq!(SHOWN.load(Ordering::SeqCst), 0);
+
+    t.world_mut().run_schedule(AfterLayout);
+    assert_eq!(SHOWN.load(Ordering::SeqCst), 8);
+}
+
+#[test]
+fn flush_leaves_a_classed_effect_for_its_own_schedule() {
+    let mut t = LazySignalsTestApp::new();
+    t.app
+        .init_schedule(AfterLayout)
+        .add_plugins(
+            LazySignalsProcessingPlugin::default().with_schedule(ProcessingClass::Ui, AfterLayout)
+        );
+    let signal = t.commands(|commands| {
+        let signal = LazySignals.state(1u32, commands);
+        let effect = LazySignals.effect::<(Option<u32>,)>(
+            |(value,), _world| {
+                FLUSHED.store(value.unwrap_or(0), Ordering::SeqCst);
+                None
+            },
+            vec![signal],
+            vec![],
+            commands
+        );
+        LazySignals.with_class(ProcessingClass::Ui, effect, commands);
+        signal
+    });
+    t.commands(|commands| LazySignals.send(signal, 5u32, commands));
+
+    // the effect stays marked for its class, which does not keep the flush from settling
+    assert!(LazySignals.flush(t.world_mut()));
+    assert_eq!(FLUSHED.load(Ordering::SeqCst), 0);
+
+    t.world_mut().run_schedule(AfterLayout);
+    assert_eq!(FLUSHED.load(Ordering::SeqCst), 5);
+}
+
+#[test]
+fn flush_leaves_a_triggered_classed_effect_for_its_own_schedule() {
+    let mut t = LazySignalsTestApp::new();
+    t.app
+        .init_schedule(AfterLayout)
+        .add_plugins(
+            LazySignalsProcessingPlugin::default().with_schedule(ProcessingClass::Ui, AfterLayout)
+        );
+    let fire = t.commands(|commands| {
+        let fire = LazySignals.state((), commands);
+        let effect = LazySignals.effect::<()>(
+            |(), _world| {
+                TRIGGERED.fetch_add(1, Ordering::SeqCst);
+                None
+            },
+            vec![],
+            vec![fire],
+            commands
+        );
+        LazySignals.with_class(ProcessingClass::Ui, effect, commands);
+        fire
+    });
+    t.flush();
+    t.commands(|commands| LazySignals.trigger(fire, commands));
+    t.flush();
+    assert_eq!(TRIGGERED.load(Ordering::SeqCst), 0);
+
+    // the trigger was kept for the class pass, which runs the effect once
+    t.world_mut().run_schedule(AfterLayout);
+    t.world_mut().run_schedule(AfterLayout);
+    assert_eq!(TRIGGERED.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+#[test]
+fn in_range_follows_the_distance_between_two_entities() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.add_plugins(TransformPlugin);
+    let player = t.world_mut().spawn(TransformBundle::default()).id();
+    let chest = t.world_mut()
+        .spawn(TransformBundle::from_transform(Transform::from_xyz(10.0, 0.0, 0.0)))
+        .id();
+    let in_range = t.commands(|commands| LazySignals.within_distance(player, chest, 3.0, commands));
+
+    // transforms propagate at the end of a frame, so the signal follows during the next one
+    t.step_n(2);
+    t.assert_signal_eq(in_range, false);
+
+    t.world_mut().get_mut::<Transform>(player).unwrap().translation.x = 8.0;
+    t.step_n(2);
+    t.assert_signal_eq(in_range, true);
+
+    t.world_mut().despawn(chest);
+    t.step_n(2);
+    t.assert_signal_eq(in_range, false);
+}
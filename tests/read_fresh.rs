@@ -0,0 +1,32 @@
+use bevy_lazy_signals::{ api::LazySignals, framework::*, testing::LazySignalsTestApp };
+
+#[test]
+fn read_fresh_fails_once_the_producer_goes_quiet() {
+    let mut t = LazySignalsTestApp::new();
+    let heartbeat = t.commands(|commands| LazySignals.state(0u32, commands));
+    t.step();
+
+    // never sent, so there is nothing fresh yet
+    assert!(matches!(
+        LazySignals.read_fresh::<u32>(heartbeat, 2, t.world()),
+        Err(LazySignalsError::Stale { age: None, .. })
+    ));
+
+    t.commands(|commands| LazySignals.send(heartbeat, 1u32, commands));
+    t.step();
+    assert_eq!(LazySignals.read_fresh::<u32>(heartbeat, 2, t.world()), Ok(Some(1)));
+
+    // the frame count moves on at the end of each frame
+    t.step();
+    assert_eq!(LazySignals.read_fresh::<u32>(heartbeat, 2, t.world()), Ok(Some(1)));
+    t.step();
+    assert_eq!(
+        LazySignals.read_fresh::<u32>(heartbeat, 2, t.world()),
+        Err(LazySignalsError::Stale { entity: heartbeat, age: Some(3) })
+    );
+
+    // a send makes it fresh again
+    t.commands(|commands| LazySignals.send(heartbeat, 2u32, commands));
+    t.step();
+    assert_eq!(LazySignals.read_fresh::<u32>(heartbeat, 2, t.world()), Ok(Some(2)));
+}
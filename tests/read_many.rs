@@ -0,0 +1,26 @@
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+#[test]
+fn read_many_returns_a_typed_tuple() {
+    let mut t = LazySignalsTestApp::new();
+    let (hp, max_hp, name) = t.commands(|commands| {
+        let hp = LazySignals.state(30u32, commands);
+        let max_hp = LazySignals.state(100u32, commands);
+        let name = LazySignals.state("knight".to_string(), commands);
+        (hp, max_hp, name)
+    });
+    t.step();
+    let (hp_value, max_hp_value, name_value): (Option<u32>, Option<u32>, Option<String>) =
+        LazySignals.read_many((hp, max_hp, name), t.world());
+    assert_eq!(hp_value, Some(30));
+    assert_eq!(max_hp_value, Some(100));
+    assert_eq!(name_value, Some("knight".to_string()));
+
+    t.commands(|commands| {
+        LazySignals.send(hp, 20u32, commands);
+        LazySignals.send(max_hp, 120u32, commands);
+    });
+    t.step();
+    let values: (Option<u32>, Option<u32>) = LazySignals.read_many((hp, max_hp), t.world());
+    assert_eq!(values, (Some(20), Some(120)));
+}
@@ -0,0 +1,24 @@
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+#[test]
+fn send_now_is_read_back_in_the_same_system() {
+    let mut t = LazySignalsTestApp::new();
+    let (width, height, area) = t.commands(|commands| {
+        let width = LazySignals.state(1u32, commands);
+        let height = LazySignals.state(1u32, commands);
+        let area = LazySignals.mul::<u32>(width, height, commands);
+        (width, height, area)
+    });
+    t.step();
+
+    // e.g. loading a save: each signal is set and read back before anything else runs
+    let world = t.world_mut();
+    LazySignals.send_now(width, 4u32, world);
+    assert_eq!(LazySignals.read::<u32>(width, world), Some(4));
+    LazySignals.send_now(height, LazySignals.read::<u32>(width, world).unwrap() * 2, world);
+    assert_eq!(LazySignals.read::<u32>(height, world), Some(8));
+
+    // subscribers are notified during the next batch
+    t.step();
+    t.assert_signal_eq(area, 32u32);
+}
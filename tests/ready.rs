@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+#[derive(Resource, Default)]
+struct Shown(Vec<String>);
+
+#[test]
+fn waiting_effects_skip_changes_until_every_source_has_a_value() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.init_resource::<Shown>();
+    let (user, score, name, effect) = t.commands(|commands| {
+        let user = LazySignals.state(0u32, commands);
+        let score = LazySignals.state(0u32, commands);
+
+        // has no value until the user is first sent
+        let name = LazySignals.computed::<(Option<u32>,), String>(
+            |(user,)| LazySignals::result(format!("player{}", user.unwrap_or(0))),
+            vec![user],
+            commands
+        );
+        let effect = LazySignals.effect::<(Option<u32>, Option<String>)>(
+            |(score, name), world| {
+                let line = format!("{}: {}", name.unwrap(), score.unwrap());
+                world.resource_mut::<Shown>().0.push(line);
+                None
+            },
+            vec![score, name],
+            vec![],
+            commands
+        );
+        (user, score, name, LazySignals.wait_for_sources(effect, commands))
+    });
+    t.step();
+    assert!(LazySignals.ready(score, t.world()));
+    assert!(!LazySignals.ready(effect, t.world()));
+
+    t.commands(|commands| LazySignals.send(score, 10u32, commands));
+    t.step();
+    assert!(t.world().resource::<Shown>().0.is_empty());
+
+    t.commands(|commands| LazySignals.send(user, 1u32, commands));
+    t.step();
+    assert!(LazySignals.ready(name, t.world()));
+    assert!(LazySignals.ready(effect, t.world()));
+    assert_eq!(t.world().resource::<Shown>().0, vec!["player1: 10".to_string()]);
+}
+
+#[test]
+fn unwrapped_effects_get_plain_values() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.init_resource::<Shown>();
+    let (user, score) = t.commands(|commands| {
+        let user = LazySignals.state(0u32, commands);
+        let score = LazySignals.state(0u32, commands);
+        let name = LazySignals.computed::<(Option<u32>,), String>(
+            |(user,)| LazySignals::result(format!("player{}", user.unwrap_or(0))),
+            vec![user],
+            commands
+        );
+        LazySignals.effect_unwrapped::<(u32, String)>(
+            |(score, name), world| {
+                world.resource_mut::<Shown>().0.push(format!("{}: {}", name, score));
+                None
+            },
+            vec![score, name],
+            vec![],
+            commands
+        );
+        (user, score)
+    });
+    t.step();
+    t.commands(|commands| LazySignals.send(score, 5u32, commands));
+    t.step();
+    assert!(t.world().resource::<Shown>().0.is_empty());
+
+    t.commands(|commands| LazySignals.send(user, 2u32, commands));
+    t.step();
+    assert_eq!(t.world().resource::<Shown>().0, vec!["player2: 5".to_string()]);
+}
@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp, LazySignalsAppExt };
+
+#[derive(Reflect, Clone, Copy, PartialEq, Debug)]
+struct Health {
+    current: u32,
+    max: u32,
+}
+
+#[test]
+fn one_call_registers_a_custom_type_for_signals_and_args() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.register_signal_type::<Health>();
+    let (health, low) = t.commands(|commands| {
+        let health = LazySignals.state(Health { current: 80, max: 100 }, commands);
+        let low = LazySignals.computed::<(Option<Health>,), bool>(
+            |(health,)| LazySignals::option(health.map(|h| h.current * 4 < h.max)),
+            vec![health],
+            commands
+        );
+        (health, low)
+    });
+    t.step();
+
+    t.commands(|commands| LazySignals.send(health, Health { current: 20, max: 100 }, commands));
+    t.step();
+    t.assert_signal_eq(low, true);
+
+    t.commands(|commands| LazySignals.send(health, Health { current: 90, max: 100 }, commands));
+    t.step();
+    t.assert_signal_eq(low, false);
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "replicon")]
+
+use bevy::prelude::*;
+use bevy_replicon::{ prelude::*, test_app::ServerTestAppExt };
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    replication::Replicated,
+    replicon::LazySignalsRepliconPlugin,
+    testing::LazySignalsTestApp,
+};
+
+fn app() -> LazySignalsTestApp {
+    let mut t = LazySignalsTestApp::new();
+    t.app.add_plugins((
+        RepliconPlugins.set(ServerPlugin { tick_policy: TickPolicy::EveryFrame, ..default() }),
+        LazySignalsRepliconPlugin,
+    ));
+    t
+}
+
+fn score(t: &mut LazySignalsTestApp, value: u32) -> Entity {
+    let signal = t.commands(|commands| {
+        let signal = LazySignals.state(value, commands);
+        commands.entity(signal).insert(Replicated { name: "score".to_string() });
+        signal
+    });
+    t.step();
+    signal
+}
+
+// run the server batch, deliver what it sent, and let the client apply it
+fn exchange(server: &mut LazySignalsTestApp, client: &mut LazySignalsTestApp) {
+    server.app.exchange_with_client(&mut client.app);
+    client.step();
+}
+
+#[test]
+fn client_catches_up_on_connect() {
+    let mut server = app();
+    let mut client = app();
+    score(&mut server, 3);
+    let client_score = score(&mut client, 0);
+
+    server.app.connect_client(&mut client.app);
+    exchange(&mut server, &mut client);
+    client.assert_signal_eq(client_score, 3u32);
+}
+
+#[test]
+fn client_follows_server_changes() {
+    let mut server = app();
+    let mut client = app();
+    let server_score = score(&mut server, 0);
+    let client_score = score(&mut client, 0);
+    server.app.connect_client(&mut client.app);
+    exchange(&mut server, &mut client);
+
+    server.commands(|commands| LazySignals.send(server_score, 7u32, commands));
+    server.step();
+    exchange(&mut server, &mut client);
+    client.assert_signal_eq(client_score, 7u32);
+
+    // the client copy only takes values while its guard allows it
+    client.commands(|commands| LazySignals.write_guard(client_score, |_| false, commands));
+    server.commands(|commands| LazySignals.send(server_score, 8u32, commands));
+    server.step();
+    exchange(&mut server, &mut client);
+    client.assert_signal_eq(client_score, 7u32);
+}
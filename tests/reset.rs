@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+#[test]
+fn reset_restores_the_initial_value_and_propagates() {
+    let mut t = LazySignalsTestApp::new();
+    let (signal, double) = t.commands(|commands| {
+        let signal = LazySignals.state(2u32, commands);
+        let double = LazySignals.computed::<(Option<u32>,), u32>(
+            |(value,)| LazySignals::result(value.unwrap_or(0) * 2),
+            vec![signal],
+            commands
+        );
+        (signal, double)
+    });
+    t.commands(|commands| LazySignals.send(signal, 7u32, commands));
+    t.step();
+    t.assert_signal_eq(double, 14u32);
+
+    t.commands(|commands| LazySignals.reset(signal, commands));
+    t.step();
+    t.assert_signal_eq(signal, 2u32);
+    t.assert_signal_eq(double, 4u32);
+}
+
+#[test]
+fn reset_scope_restores_every_descendant() {
+    let mut t = LazySignalsTestApp::new();
+    let (scope, volume, muted, other) = t.commands(|commands| {
+        let volume = LazySignals.state(5u32, commands);
+        let muted = LazySignals.state(false, commands);
+        let other = LazySignals.state(1u32, commands);
+        let scope = commands.spawn_empty().push_children(&[volume, muted]).id();
+        (scope, volume, muted, other)
+    });
+    t.commands(|commands| {
+        LazySignals.send(volume, 9u32, commands);
+        LazySignals.send(muted, true, commands);
+        LazySignals.send(other, 3u32, commands);
+    });
+    t.step();
+
+    t.commands(|commands| LazySignals.reset_scope(scope, commands));
+    t.step();
+    t.assert_signal_eq(volume, 5u32);
+    t.assert_signal_eq(muted, false);
+    t.assert_signal_eq(other, 3u32);
+}
@@ -0,0 +1,74 @@
+use std::{
+    sync::atomic::{ AtomicU32, Ordering },
+    thread,
+    time::{ Duration, Instant },
+};
+
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    retry::{ ResolveStatus, RetryPolicy },
+    testing::LazySignalsTestApp,
+};
+
+static ATTEMPTS: AtomicU32 = AtomicU32::new(0);
+
+// step until the status is the expected one, giving the attempts time to run
+fn settle(t: &mut LazySignalsTestApp, status: Entity, expected: ResolveStatus) {
+    let started = Instant::now();
+    while t.read::<ResolveStatus>(status) != Some(expected.clone()) {
+        assert!(started.elapsed() < Duration::from_secs(10), "resolve did not finish");
+        thread::sleep(Duration::from_millis(5));
+        t.step();
+    }
+}
+
+#[test]
+fn failed_attempts_are_retried_with_backoff() {
+    let mut t = LazySignalsTestApp::new();
+    let policy = RetryPolicy { initial_delay: Duration::from_secs(1), ..default() };
+    let resolve = t.commands(|commands| {
+        let level = LazySignals.state(3u32, commands);
+        LazySignals.resolve::<(Option<u32>,), String>(
+            |(level,)| match ATTEMPTS.fetch_add(1, Ordering::SeqCst) {
+                0 | 1 => Err("offline".to_string()),
+                _ => Ok(format!("level{}.map", level.unwrap_or(0))),
+            },
+            vec![level],
+            policy,
+            commands
+        )
+    });
+    t.step();
+    settle(&mut t, resolve.status, ResolveStatus::Retrying(1));
+    t.assert_signal_none::<String>(resolve.value);
+
+    // each retry waits for the backoff
+    t.step_n(3);
+    t.assert_signal_eq(resolve.status, ResolveStatus::Retrying(1));
+    t.advance_clock(Duration::from_secs(1));
+    settle(&mut t, resolve.status, ResolveStatus::Retrying(2));
+
+    t.advance_clock(Duration::from_secs(2));
+    settle(&mut t, resolve.status, ResolveStatus::Ready);
+    t.assert_signal_eq(resolve.value, "level3.map".to_string());
+    assert_eq!(ATTEMPTS.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn gives_up_after_the_last_attempt() {
+    let mut t = LazySignalsTestApp::new();
+    let resolve = t.commands(|commands| {
+        let level = LazySignals.state(1u32, commands);
+        LazySignals.resolve::<(Option<u32>,), String>(
+            |_| Err("offline".to_string()),
+            vec![level],
+            RetryPolicy::never(),
+            commands
+        )
+    });
+    t.step();
+    settle(&mut t, resolve.status, ResolveStatus::Failed("offline".to_string()));
+    t.assert_signal_none::<String>(resolve.value);
+}
@@ -0,0 +1,119 @@
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{ api::LazySignals, framework::*, testing::LazySignalsTestApp };
+
+fn rejected(t: &LazySignalsTestApp) -> Vec<LazySignalsError> {
+    let events = t.world().resource::<Events<SignalNodeRejected>>();
+    events
+        .get_reader()
+        .read(events)
+        .map(|rejected| rejected.error.clone())
+        .collect()
+}
+
+#[test]
+fn retarget_moves_a_computed_to_new_sources() {
+    let mut t = LazySignalsTestApp::new();
+    let (a, b, doubled) = t.commands(|commands| {
+        let a = LazySignals.state(1u32, commands);
+        let b = LazySignals.state(100u32, commands);
+        let doubled = LazySignals.computed::<(Option<u32>,), u32>(
+            |(value,)| LazySignals::option(value.map(|value| value * 2)),
+            vec![a],
+            commands
+        );
+        (a, b, doubled)
+    });
+    t.step();
+    t.commands(|commands| LazySignals.send(a, 2u32, commands));
+    t.step();
+    t.assert_signal_eq(doubled, 4u32);
+
+    t.commands(|commands| LazySignals.retarget(doubled, vec![b], vec![], commands));
+    t.step();
+    t.assert_signal_eq(doubled, 200u32);
+
+    // a is no longer a source
+    t.commands(|commands| LazySignals.send(a, 3u32, commands));
+    t.step();
+    t.assert_signal_eq(doubled, 200u32);
+}
+
+#[test]
+fn retarget_refuses_a_different_arity() {
+    let mut t = LazySignalsTestApp::new();
+    let (a, b, out, effect) = t.commands(|commands| {
+        let a = LazySignals.state(1u32, commands);
+        let b = LazySignals.state(2u32, commands);
+        let out = LazySignals.state(0u32, commands);
+        let effect = LazySignals.effect::<(Option<u32>,)>(
+            move |(value,), world| {
+                LazySignals.send_now(out, value.unwrap_or(0), world);
+                None
+            },
+            vec![a],
+            vec![],
+            commands
+        );
+        (a, b, out, effect)
+    });
+    t.step();
+    t.commands(|commands| LazySignals.retarget(effect, vec![a, b], vec![], commands));
+    assert_eq!(rejected(&t), vec![LazySignalsError::ArityMismatch { sources: 2, args: 1 }]);
+
+    // still runs with its old source
+    t.step();
+    t.commands(|commands| LazySignals.send(a, 5u32, commands));
+    t.step();
+    t.assert_signal_eq(out, 5u32);
+}
+
+#[test]
+fn retarget_refuses_a_source_of_another_type() {
+    let mut t = LazySignalsTestApp::new();
+    let (a, text, doubled) = t.commands(|commands| {
+        let a = LazySignals.state(1u32, commands);
+        let text = LazySignals.state("one", commands);
+        let doubled = LazySignals.computed::<(Option<u32>,), u32>(
+            |(value,)| LazySignals::option(value.map(|value| value * 2)),
+            vec![a],
+            commands
+        );
+        (a, text, doubled)
+    });
+    t.step();
+    t.commands(|commands| LazySignals.retarget(doubled, vec![text], vec![], commands));
+    assert!(
+        matches!(rejected(&t)[..], [LazySignalsError::TypeMismatch { .. }]),
+        "{:?}",
+        rejected(&t)
+    );
+
+    t.step();
+    t.commands(|commands| LazySignals.send(a, 4u32, commands));
+    t.step();
+    t.assert_signal_eq(doubled, 8u32);
+}
+
+#[test]
+fn repoint_refuses_a_target_of_another_type() {
+    let mut t = LazySignalsTestApp::new();
+    let (hard, text, alias) = t.commands(|commands| {
+        let easy = LazySignals.state(1u32, commands);
+        let hard = LazySignals.state(3u32, commands);
+        let text = LazySignals.state("hard", commands);
+        let alias = LazySignals.alias::<u32>(easy, commands);
+        (hard, text, alias)
+    });
+    t.step();
+    t.assert_signal_eq(alias, 1u32);
+
+    t.commands(|commands| LazySignals.repoint(alias, text, commands));
+    t.step();
+    assert_eq!(rejected(&t).len(), 1);
+    t.assert_signal_eq(alias, 1u32);
+
+    t.commands(|commands| LazySignals.repoint(alias, hard, commands));
+    t.step();
+    t.assert_signal_eq(alias, 3u32);
+}
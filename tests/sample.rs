@@ -0,0 +1,27 @@
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+#[test]
+fn sample_captures_the_value_only_when_triggered() {
+    let mut t = LazySignalsTestApp::new();
+    let (aim, shoot, shot) = t.commands(|commands| {
+        let aim = LazySignals.state(10u32, commands);
+        let shoot = LazySignals.state((), commands);
+        let shot = LazySignals.sample::<u32>(aim, shoot, commands);
+        (aim, shoot, shot)
+    });
+    t.step();
+    t.assert_signal_none::<u32>(shot);
+
+    t.commands(|commands| LazySignals.trigger(shoot, commands));
+    t.step();
+    t.assert_signal_eq(shot, 10u32);
+
+    // moving the aim alone does not change the shot
+    t.commands(|commands| LazySignals.send(aim, 20u32, commands));
+    t.step();
+    t.assert_signal_eq(shot, 10u32);
+
+    t.commands(|commands| LazySignals.trigger(shoot, commands));
+    t.step();
+    t.assert_signal_eq(shot, 20u32);
+}
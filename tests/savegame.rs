@@ -0,0 +1,56 @@
+#![cfg(feature = "savegame")]
+
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+#[test]
+fn save_and_load_round_trip_a_subtree() {
+    let mut t = LazySignalsTestApp::new();
+    let (volume, health) = t.commands(|commands| {
+        let volume = LazySignals.state(5u32, commands);
+        let health = LazySignals.state(100u32, commands);
+        LazySignals.register_path("ui.settings.volume", volume, commands);
+        LazySignals.register_path("ui.hud.health", health, commands);
+        (volume, health)
+    });
+    t.step();
+    let json = LazySignals.save("ui.settings.", t.world_mut()).unwrap();
+    assert_eq!(json, r#"{"ui.settings.volume":{"value":5,"version":0}}"#);
+
+    t.commands(|commands| {
+        LazySignals.send(volume, 9u32, commands);
+        LazySignals.send(health, 50u32, commands);
+    });
+    t.step();
+
+    LazySignals.load(&json, t.world_mut()).unwrap();
+    t.step();
+    t.assert_signal_eq(volume, 5u32);
+    t.assert_signal_eq(health, 50u32);
+}
+
+#[test]
+fn older_saves_are_migrated_on_load() {
+    let mut t = LazySignalsTestApp::new();
+    let volume = t.commands(|commands| {
+        // version 0 saved the volume as 0..=10, version 1 holds it as 0.0..=1.0
+        let volume = LazySignals.state(1.0f32, commands);
+        LazySignals.register_path("ui.settings.volume", volume, commands);
+        LazySignals.migration("ui.settings.volume", 0, |old: u32| (old as f32) / 10.0, commands);
+        volume
+    });
+    t.step();
+
+    LazySignals.load(r#"{"ui.settings.volume":{"version":0,"value":5}}"#, t.world_mut()).unwrap();
+    t.step();
+    t.assert_signal_eq(volume, 0.5f32);
+
+    // saves now carry the version the migration raised the path to
+    let json = LazySignals.save("ui.", t.world_mut()).unwrap();
+    assert_eq!(json, r#"{"ui.settings.volume":{"value":0.5,"version":1}}"#);
+
+    // a save from a newer version than the schema is left out
+    t.commands(|commands| LazySignals.schema_version("ui.settings.volume", 1, commands));
+    LazySignals.load(r#"{"ui.settings.volume":{"version":2,"value":0.9}}"#, t.world_mut()).unwrap();
+    t.step();
+    t.assert_signal_eq(volume, 0.5f32);
+}
@@ -0,0 +1,50 @@
+use std::sync::atomic::{ AtomicU32, Ordering };
+
+use bevy::{ ecs::system::RunSystemOnce, prelude::* };
+use bevy_lazy_signals::{
+    api::LazySignals,
+    family::{ LazySignalsFamily, MemoEviction },
+    testing::LazySignalsTestApp,
+};
+
+static COMPUTES: AtomicU32 = AtomicU32::new(0);
+
+fn member(t: &mut LazySignalsTestApp, selector: Entity, key: u32) -> Entity {
+    t.world_mut().run_system_once(
+        move |mut query: Query<&mut LazySignalsFamily>, mut commands: Commands| {
+            query.get_mut(selector).unwrap().member(&key, &mut commands)
+        }
+    )
+}
+
+#[test]
+fn readers_of_the_same_key_share_one_memo() {
+    let mut t = LazySignalsTestApp::new();
+    let (items, selector) = t.commands(|commands| {
+        let items = LazySignals.state(10u32, commands);
+        let selector = LazySignals.selector::<(Option<u32>,), u32, u32>(
+            vec![items],
+            |rarity, (items,)| {
+                COMPUTES.fetch_add(1, Ordering::SeqCst);
+                LazySignals::result(items.unwrap_or(0) * rarity)
+            },
+            MemoEviction::default(),
+            commands
+        );
+        (items, selector)
+    });
+
+    let first = member(&mut t, selector, 2);
+    let second = member(&mut t, selector, 2);
+    let other = member(&mut t, selector, 3);
+    assert_eq!(first, second);
+    assert_ne!(first, other);
+
+    t.commands(|commands| LazySignals.send(items, 5u32, commands));
+    t.step();
+    t.assert_signal_eq(first, 10u32);
+    t.assert_signal_eq(other, 15u32);
+
+    // one run per key, not per reader
+    assert_eq!(COMPUTES.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    backpressure::Backpressure,
+    middleware::MiddlewareDecision,
+    testing::LazySignalsTestApp,
+    LazySignalsAppExt,
+};
+
+type Cells = (Option<u32>, Option<u32>, Option<u32>, Option<u32>);
+
+#[test]
+fn one_command_sends_a_whole_batch() {
+    let mut t = LazySignalsTestApp::new();
+    let (cells, total) = t.commands(|commands| {
+        let cells: Vec<_> = (0..4).map(|_| LazySignals.state(0u32, commands)).collect();
+        let total = LazySignals.computed::<Cells, u32>(
+            |(a, b, c, d)| {
+                LazySignals::result(
+                    a.unwrap_or(0) + b.unwrap_or(0) + c.unwrap_or(0) + d.unwrap_or(0)
+                )
+            },
+            cells.clone(),
+            commands
+        );
+        (cells, total)
+    });
+    t.step();
+
+    let batch: Vec<_> = cells.iter().copied().zip(1..=4u32).collect();
+    t.commands(|commands| LazySignals.send_many(batch, commands));
+    t.step();
+    for (cell, value) in cells.iter().zip(1..=4u32) {
+        t.assert_signal_eq(*cell, value);
+    }
+    t.assert_signal_eq(total, 10u32);
+
+    // the same signal twice in a batch ends up with the last value
+    t.commands(|commands| LazySignals.send_many([(cells[0], 5u32), (cells[0], 6)], commands));
+    t.step();
+    t.assert_signal_eq(cells[0], 6u32);
+    t.assert_signal_eq(total, 15u32);
+}
+
+#[derive(Resource, Default)]
+struct Seen(Vec<u32>);
+
+#[test]
+fn repeated_signals_send_every_value() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.init_resource::<Seen>().add_send_middleware(|_, value, _| {
+        if let Some(value) = value.downcast_mut::<u32>() {
+            *value *= 10;
+        }
+        MiddlewareDecision::Continue
+    });
+    let signal = t.commands(|commands| {
+        let signal = LazySignals.state(0u32, commands);
+        LazySignals.backpressure(signal, Backpressure::Block(4), commands);
+        LazySignals.effect::<(Option<u32>,)>(
+            |(value,), world| {
+                world.resource_mut::<Seen>().0.extend(value);
+                None
+            },
+            vec![signal],
+            vec![],
+            commands
+        );
+        signal
+    });
+    t.step();
+    t.world_mut().resource_mut::<Seen>().0.clear();
+
+    // a blocking signal queues the values after the first instead of losing them
+    t.commands(|commands| {
+        LazySignals.send_many([(signal, 1u32), (signal, 2), (signal, 3)], commands)
+    });
+    t.step_n(4);
+    assert_eq!(t.world().resource::<Seen>().0, vec![10, 20, 30]);
+    t.assert_signal_eq(signal, 30u32);
+}
@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    sequence::LazySignalsSequence,
+    testing::LazySignalsTestApp,
+};
+
+#[derive(Resource, Default)]
+struct Log(Vec<&'static str>);
+
+fn step(name: &'static str, commands: &mut Commands) -> Entity {
+    LazySignals.effect::<()>(
+        move |_, world| {
+            world.resource_mut::<Log>().0.push(name);
+            None
+        },
+        vec![],
+        vec![],
+        commands
+    )
+}
+
+fn log(t: &LazySignalsTestApp) -> Vec<&'static str> {
+    t.world().resource::<Log>().0.clone()
+}
+
+#[test]
+fn steps_run_one_after_another() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.init_resource::<Log>();
+    let sequence = t.commands(|commands| {
+        let steps = vec![step("save", commands), step("upload", commands), step("toast", commands)];
+        LazySignals.sequence(steps, None, commands)
+    });
+    t.step();
+    assert!(log(&t).is_empty());
+
+    t.commands(|commands| LazySignals.trigger(sequence, commands));
+    t.step_n(4);
+    assert_eq!(log(&t), vec!["save", "upload", "toast"]);
+    assert!(!t.world().get::<LazySignalsSequence>(sequence).unwrap().is_running());
+}
+
+#[test]
+fn cancel_stops_the_remaining_steps() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.init_resource::<Log>();
+    let (sequence, cancel) = t.commands(|commands| {
+        let cancel = LazySignals.state((), commands);
+        let steps = vec![step("save", commands), step("upload", commands), step("toast", commands)];
+        (LazySignals.sequence(steps, Some(cancel), commands), cancel)
+    });
+    t.step();
+
+    t.commands(|commands| LazySignals.trigger(sequence, commands));
+    t.step_n(2);
+    assert_eq!(log(&t), vec!["save"]);
+
+    t.commands(|commands| LazySignals.trigger(cancel, commands));
+    t.step_n(4);
+
+    // upload was already scheduled for the pass the cancel fired in, toast never is
+    assert_eq!(log(&t), vec!["save", "upload"]);
+    assert!(!t.world().get::<LazySignalsSequence>(sequence).unwrap().is_running());
+}
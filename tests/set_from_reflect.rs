@@ -0,0 +1,47 @@
+use bevy_lazy_signals::{ api::LazySignals, framework::*, testing::LazySignalsTestApp };
+
+#[test]
+fn reflected_values_are_converted_and_propagated() {
+    let mut t = LazySignalsTestApp::new();
+    let (volume, double) = t.commands(|commands| {
+        let volume = LazySignals.state(5u32, commands);
+        let double = LazySignals.computed::<(Option<u32>,), u32>(
+            |(volume,)| LazySignals::result(volume.unwrap_or(0) * 2),
+            vec![volume],
+            commands
+        );
+        (volume, double)
+    });
+    t.step();
+
+    // an editor only knows it has some integer
+    LazySignals.set_from_reflect(volume, &8i64, t.world_mut()).unwrap();
+    t.step();
+    t.assert_signal_eq(volume, 8u32);
+    t.assert_signal_eq(double, 16u32);
+}
+
+#[test]
+fn refused_values_are_errors() {
+    let mut t = LazySignalsTestApp::new();
+    let volume = t.commands(|commands| {
+        let volume = LazySignals.state(5u32, commands);
+        LazySignals.validate(
+            volume,
+            |volume: &u32| {
+                match *volume <= 10 {
+                    true => Ok(()),
+                    false => Err("too loud".to_string()),
+                }
+            },
+            commands
+        );
+        volume
+    });
+    t.step();
+
+    let result = LazySignals.set_from_reflect(volume, &42u32, t.world_mut());
+    assert!(matches!(result, Err(LazySignalsError::SendRefused(_))));
+    t.step();
+    t.assert_signal_eq(volume, 5u32);
+}
@@ -0,0 +1,58 @@
+use std::sync::{ atomic::{ AtomicU32, Ordering }, Arc, Mutex };
+
+use bevy::reflect::TypePath;
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    framework::*,
+    testing::LazySignalsTestApp,
+    LazySignalsAppExt,
+};
+
+// a large value that is not `Clone`
+#[derive(TypePath)]
+struct Grid {
+    cells: Vec<u8>,
+}
+
+static RUNS: AtomicU32 = AtomicU32::new(0);
+static SEEN: Mutex<Option<Arc<Grid>>> = Mutex::new(None);
+
+#[test]
+fn subscribers_share_the_sent_arc() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.register_signal_type::<LazySignalsShared<Grid>>();
+    let grid = t.commands(|commands| {
+        let grid = LazySignals.state_shared(Grid { cells: vec![0; 16] }, commands);
+        LazySignals.effect::<(Option<LazySignalsShared<Grid>>,)>(
+            |(grid,), _world| {
+                RUNS.fetch_add(1, Ordering::SeqCst);
+                *SEEN.lock().unwrap() = grid.map(|grid| Arc::clone(grid.arc()));
+                None
+            },
+            vec![grid],
+            vec![],
+            commands
+        );
+        grid
+    });
+    t.step();
+
+    let big = Arc::new(Grid { cells: vec![1; 1 << 16] });
+    t.commands(|commands| LazySignals.send(grid, LazySignalsShared::from(big.clone()), commands));
+    t.step();
+    assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+    let seen = SEEN.lock().unwrap().clone().unwrap();
+    assert!(Arc::ptr_eq(&seen, &big));
+    assert_eq!(seen.cells.len(), 1 << 16);
+
+    // the same allocation again is not a change
+    t.commands(|commands| LazySignals.send(grid, LazySignalsShared::from(big.clone()), commands));
+    t.step();
+    assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+
+    // new data always is
+    t.commands(|commands| LazySignals.send_shared(grid, Grid { cells: vec![1; 4] }, commands));
+    t.step();
+    assert_eq!(RUNS.load(Ordering::SeqCst), 2);
+}
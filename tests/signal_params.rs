@@ -0,0 +1,39 @@
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+bevy_lazy_signals::signal_params! {
+    struct DamageParams(DamageSources) {
+        attack: u32,
+        armor: u32,
+        critical: bool,
+    }
+}
+
+#[test]
+fn named_accessors_follow_the_declared_sources() {
+    let mut t = LazySignalsTestApp::new();
+    let (armor, critical, damage) = t.commands(|commands| {
+        let attack = LazySignals.state(10u32, commands);
+        let armor = LazySignals.state(3u32, commands);
+        let critical = LazySignals.state(false, commands);
+
+        // the sources are named, so listing them in another order can't swap attack and armor
+        let damage = LazySignals.computed::<DamageParams, u32>(
+            |p| {
+                let attack = p.attack().copied().unwrap_or(0);
+                let base = attack.saturating_sub(p.armor().copied().unwrap_or(0));
+                LazySignals::result(if p.critical() == Some(&true) { base * 2 } else { base })
+            },
+            DamageSources { critical, armor, attack }.into(),
+            commands
+        );
+        (armor, critical, damage)
+    });
+    t.step();
+    t.commands(|commands| LazySignals.send(armor, 4u32, commands));
+    t.step();
+    t.assert_signal_eq(damage, 6u32);
+
+    t.commands(|commands| LazySignals.send(critical, true, commands));
+    t.step();
+    t.assert_signal_eq(damage, 12u32);
+}
@@ -0,0 +1,51 @@
+use bevy_lazy_signals::{
+    api::LazySignals,
+    namespace::SignalDiff,
+    testing::LazySignalsTestApp,
+};
+
+#[test]
+fn diff_lists_added_removed_and_changed_paths() {
+    let mut t = LazySignalsTestApp::new();
+    let (volume, muted) = t.commands(|commands| {
+        let volume = LazySignals.state(5u32, commands);
+        let muted = LazySignals.state(false, commands);
+        LazySignals.register_path("settings.volume", volume, commands);
+        LazySignals.register_path("settings.muted", muted, commands);
+        (volume, muted)
+    });
+    t.step();
+    let before = t.snapshot("settings.");
+
+    t.commands(|commands| {
+        LazySignals.send(volume, 7u32, commands);
+        let brightness = LazySignals.state(3u32, commands);
+        LazySignals.register_path("settings.brightness", brightness, commands);
+    });
+    t.world_mut().despawn(muted);
+    t.step();
+
+    let diffs = before.diff(&t.snapshot("settings."));
+    let lines: Vec<String> = diffs.iter().map(SignalDiff::to_string).collect();
+    assert_eq!(
+        lines,
+        vec!["+ settings.brightness: 3", "- settings.muted: false", "~ settings.volume: 5 -> 7"]
+    );
+}
+
+#[test]
+#[should_panic(expected = "~ settings.volume: 5 -> 7")]
+fn assert_changed_lists_the_unexpected_changes() {
+    let mut t = LazySignalsTestApp::new();
+    let volume = t.commands(|commands| {
+        let volume = LazySignals.state(5u32, commands);
+        LazySignals.register_path("settings.volume", volume, commands);
+        volume
+    });
+    t.step();
+    let before = t.snapshot("settings.");
+
+    t.commands(|commands| LazySignals.send(volume, 7u32, commands));
+    t.step();
+    t.assert_changed(&before, "settings.", &[]);
+}
@@ -0,0 +1,36 @@
+use bevy::{ ecs::event::ManualEventReader, prelude::* };
+
+use bevy_lazy_signals::{ api::LazySignals, framework::*, testing::LazySignalsTestApp };
+
+fn mismatches(
+    t: &LazySignalsTestApp,
+    reader: &mut ManualEventReader<SourceTypeMismatch>
+) -> Vec<SourceTypeMismatch> {
+    let events = t.world().resource::<Events<SourceTypeMismatch>>();
+    reader.read(events).cloned().collect()
+}
+
+#[test]
+fn mismatched_source_is_reported_when_subscribing() {
+    let mut t = LazySignalsTestApp::new();
+    let mut reader = t.world().resource::<Events<SourceTypeMismatch>>().get_reader();
+    let (label, computed) = t.commands(|commands| {
+        let count = LazySignals.state(1u32, commands);
+        let label = LazySignals.state("one", commands);
+        let computed = LazySignals.computed::<(Option<u32>, Option<u32>), u32>(
+            |(a, b)| LazySignals::result(a.unwrap_or(0) + b.unwrap_or(0)),
+            vec![count, label],
+            commands
+        );
+        (label, computed)
+    });
+    t.step();
+
+    let found = mismatches(&t, &mut reader);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].subscriber, computed);
+    assert_eq!(found[0].source, label);
+    assert_eq!(found[0].index, 1);
+    assert_eq!(found[0].expected, "core::option::Option<u32>");
+    assert_eq!(found[0].found, "core::option::Option<&str>");
+}
@@ -0,0 +1,115 @@
+use bevy_lazy_signals::{ api::LazySignals, stats::LazySignalsStats, testing::LazySignalsTestApp };
+
+#[test]
+fn stats_count_recomputes_that_change_nothing() {
+    let mut t = LazySignalsTestApp::new();
+    let (signal, parity) = t.commands(|commands| {
+        let signal = LazySignals.state(0u32, commands);
+        let parity = LazySignals.computed::<(Option<u32>,), bool>(
+            |(value,)| LazySignals::result(value.unwrap_or(0) % 2 == 0),
+            vec![signal],
+            commands
+        );
+        (signal, parity)
+    });
+    t.step();
+    t.commands(|commands| LazySignals.send(signal, 2u32, commands));
+    t.step();
+    t.commands(|commands| LazySignals.send(signal, 4u32, commands));
+    t.step();
+
+    let stats = t.world().resource::<LazySignalsStats>().get(parity).copied().unwrap();
+    assert_eq!(stats.recomputes, 1);
+    assert_eq!(stats.wasted_recomputes, 1);
+}
+
+#[test]
+fn stats_count_runs_per_frame_unless_kept() {
+    let mut t = LazySignalsTestApp::new();
+    let (signal, double, effect) = t.commands(|commands| {
+        let signal = LazySignals.state(0u32, commands);
+        let double = LazySignals.computed::<(Option<u32>,), u32>(
+            |(value,)| LazySignals::result(value.unwrap_or(0) * 2),
+            vec![signal],
+            commands
+        );
+        let effect = LazySignals.effect::<(Option<u32>,)>(
+            |_, _world| None,
+            vec![double],
+            vec![],
+            commands
+        );
+        (signal, double, effect)
+    });
+    t.step();
+    t.commands(|commands| LazySignals.send(signal, 1u32, commands));
+    t.step();
+    let stats = t.world().resource::<LazySignalsStats>();
+    assert_eq!(stats.get(double).map(|stats| stats.recomputes), Some(1));
+    assert_eq!(stats.get(effect).map(|stats| stats.runs), Some(1));
+    assert_eq!(stats.iter().count(), 2);
+
+    // a quiet frame clears them
+    t.step();
+    assert_eq!(t.world().resource::<LazySignalsStats>().get(double), None);
+
+    // or they add up across frames
+    t.world_mut().resource_mut::<LazySignalsStats>().reset_each_frame = false;
+    for value in [2u32, 3] {
+        t.commands(|commands| LazySignals.send(signal, value, commands));
+        t.step();
+    }
+    let stats = t.world().resource::<LazySignalsStats>();
+    assert_eq!(stats.get(double).map(|stats| stats.recomputes), Some(2));
+    assert_eq!(stats.get(effect).map(|stats| stats.runs), Some(2));
+}
+
+// the streaks behind the wasted recompute warning are only kept in debug builds
+#[cfg(debug_assertions)]
+mod wasted {
+    use std::time::Duration;
+
+    use bevy::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn wasted_recompute_warning_after_many_frames_in_a_row() {
+        let mut stats = LazySignalsStats::default();
+        stats.wasted_recompute_warning = 3;
+        let entity = Entity::from_raw(1);
+        assert!(!stats.record_recompute(entity, Duration::ZERO, false));
+        assert!(!stats.record_recompute(entity, Duration::ZERO, false));
+        assert!(stats.record_recompute(entity, Duration::ZERO, false));
+
+        // only warned about once per streak, and a change starts it over
+        assert!(!stats.record_recompute(entity, Duration::ZERO, false));
+        assert!(!stats.record_recompute(entity, Duration::ZERO, true));
+        assert_eq!(stats.wasted_streak(entity), 0);
+    }
+
+    #[test]
+    fn wasted_streaks_span_frames() {
+        let mut t = LazySignalsTestApp::new();
+        t.world_mut().resource_mut::<LazySignalsStats>().wasted_recompute_warning = 3;
+        let (signal, parity) = t.commands(|commands| {
+            let signal = LazySignals.state(0u32, commands);
+            let parity = LazySignals.computed::<(Option<u32>,), bool>(
+                |(value,)| LazySignals::result(value.unwrap_or(0) % 2 == 0),
+                vec![signal],
+                commands
+            );
+            (signal, parity)
+        });
+        t.step();
+        for value in [2u32, 4, 6] {
+            t.commands(|commands| LazySignals.send(signal, value, commands));
+            t.step();
+        }
+
+        // the per-frame counters were reset, the streak was not
+        let stats = t.world().resource::<LazySignalsStats>();
+        assert_eq!(stats.get(parity).map(|stats| stats.wasted_recomputes), Some(1));
+        assert_eq!(stats.wasted_streak(parity), 2);
+    }
+}
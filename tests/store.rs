@@ -0,0 +1,41 @@
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+enum Counter {
+    Add(u32),
+    Reset,
+}
+
+fn reduce(count: &u32, action: Counter) -> u32 {
+    match action {
+        Counter::Add(amount) => count + amount,
+        Counter::Reset => 0,
+    }
+}
+
+#[test]
+fn dispatched_actions_go_through_the_reducer() {
+    let mut t = LazySignalsTestApp::new();
+    let (store, double) = t.commands(|commands| {
+        let store = LazySignals.store::<u32, Counter>(1, reduce, commands);
+        let double = LazySignals.computed::<(Option<u32>,), u32>(
+            |(count,)| LazySignals::result(count.unwrap_or(0) * 2),
+            vec![store],
+            commands
+        );
+        (store, double)
+    });
+    t.step();
+
+    t.commands(|commands| {
+        LazySignals.dispatch::<u32, Counter>(store, Counter::Add(2), commands);
+        LazySignals.dispatch::<u32, Counter>(store, Counter::Add(3), commands);
+    });
+    t.step();
+    t.assert_signal_eq(store, 6u32);
+    t.assert_signal_eq(double, 12u32);
+
+    t.commands(|commands| LazySignals.dispatch::<u32, Counter>(store, Counter::Reset, commands));
+    t.step();
+    t.assert_signal_eq(store, 0u32);
+    t.assert_signal_eq(double, 0u32);
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "async")]
+
+use bevy::tasks::{ block_on, futures_lite::{ future, stream, StreamExt } };
+
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+#[test]
+fn into_stream_yields_each_new_value() {
+    let mut t = LazySignalsTestApp::new();
+    let (signal, mut values) = t.commands(|commands| {
+        let signal = LazySignals.state(1u32, commands);
+        let values = LazySignals.into_stream::<u32>(signal, commands);
+        (signal, Box::pin(values))
+    });
+    t.step();
+
+    // the value it already held is not yielded
+    assert_eq!(block_on(future::poll_once(values.next())), None);
+
+    for value in [2u32, 3] {
+        t.commands(|commands| LazySignals.send(signal, value, commands));
+        t.step();
+    }
+    assert_eq!(block_on(values.next()), Some(2));
+    assert_eq!(block_on(values.next()), Some(3));
+}
+
+#[test]
+fn from_stream_sends_each_item() {
+    let mut t = LazySignalsTestApp::new();
+    let (signal, double) = t.commands(|commands| {
+        let signal = LazySignals.from_stream(stream::iter(vec![4u32]), commands);
+        let double = LazySignals.computed::<(Option<u32>,), u32>(
+            |(value,)| LazySignals::result(value.unwrap_or(0) * 2),
+            vec![signal],
+            commands
+        );
+        (signal, double)
+    });
+    t.assert_signal_none::<u32>(signal);
+
+    // the items arrive from a task, so give it a few frames
+    for _ in 0..100 {
+        t.step();
+        if t.read::<u32>(double).is_some() {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+    t.assert_signal_eq(signal, 4u32);
+    t.assert_signal_eq(double, 8u32);
+}
@@ -0,0 +1,59 @@
+use std::sync::atomic::{ AtomicU32, Ordering };
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    strict::{ CountingAllocator, LazySignalsStrictMode, LazySignalsStrictModePlugin },
+    testing::LazySignalsTestApp,
+};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+static SEEN: AtomicU32 = AtomicU32::new(0);
+
+#[test]
+fn propagation_makes_no_heap_allocations() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.add_plugins(LazySignalsStrictModePlugin);
+    let (signal, double) = t.commands(|commands| {
+        let signal = LazySignals.state(1u32, commands);
+        let double = LazySignals.computed::<(Option<u32>,), u32>(
+            |(value,)| LazySignals::result(value.unwrap_or(0) * 2),
+            vec![signal],
+            commands
+        );
+        LazySignals.effect::<(Option<u32>,)>(
+            |(value,), _world| {
+                SEEN.store(value.unwrap_or(0), Ordering::SeqCst);
+                None
+            },
+            vec![double],
+            vec![],
+            commands
+        );
+        (signal, double)
+    });
+
+    // building the graph and the first runs may allocate, e.g. while Bevy caches the archetype
+    // each marker component moves an entity to
+    for value in 2..4u32 {
+        t.commands(|commands| LazySignals.send(signal, value, commands));
+        t.step().step();
+    }
+    let warmup = t.world().resource::<LazySignalsStrictMode>().violations;
+
+    for value in 4..8u32 {
+        t.commands(|commands| LazySignals.send(signal, value, commands));
+        t.step();
+        t.assert_signal_eq(double, value * 2);
+        assert_eq!(SEEN.load(Ordering::SeqCst), value * 2);
+        let strict = t.world().resource::<LazySignalsStrictMode>();
+        assert_eq!(strict.last_pass_allocations, Some(0));
+
+        // nor does a pass with nothing to do
+        t.step();
+        let strict = t.world().resource::<LazySignalsStrictMode>();
+        assert_eq!(strict.last_pass_allocations, Some(0));
+        assert_eq!(strict.violations, warmup);
+    }
+}
@@ -0,0 +1,82 @@
+use std::sync::atomic::{ AtomicBool, Ordering };
+
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    processing::{ LazySignalsProcessingPlugin, ProcessingClass },
+    testing::LazySignalsTestApp,
+    LazySignalsPlugin,
+};
+
+#[test]
+fn process_subgraph_only_resolves_what_is_reachable_from_the_root() {
+    let mut t = LazySignalsTestApp::new();
+    let (dialog, title, heading, score) = t.commands(|commands| {
+        let title = LazySignals.state(String::new(), commands);
+        let heading = LazySignals.computed::<(Option<String>,), String>(
+            |(title,)| LazySignals::result(title.unwrap_or_default().to_uppercase()),
+            vec![title],
+            commands
+        );
+        let dialog = commands.spawn_empty().push_children(&[title]).id();
+        let score = LazySignals.state(0u32, commands);
+        (dialog, title, heading, score)
+    });
+    t.step();
+
+    t.commands(|commands| {
+        LazySignals.send(title, "quit?".to_string(), commands);
+        LazySignals.send(score, 10u32, commands);
+    });
+    assert!(LazySignals.process_subgraph(dialog, t.world_mut()));
+    t.assert_signal_eq(title, "quit?".to_string());
+    t.assert_signal_eq(heading, "QUIT?".to_string());
+
+    // the rest of the graph waits for the next regular pass
+    t.assert_signal_eq(score, 0u32);
+    t.step();
+    t.assert_signal_eq(score, 10u32);
+}
+
+static SHOWN: AtomicBool = AtomicBool::new(false);
+
+#[test]
+fn process_subgraph_settles_around_other_contexts_and_classes() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.add_plugins((LazySignalsPlugin::labeled("sim"), LazySignalsProcessingPlugin::default()));
+    let (dialog, title, mirrored) = t.commands(|commands| {
+        let title = LazySignals.state(String::new(), commands);
+        let mirrored = LazySignals.computed::<(Option<String>,), String>(
+            |(title,)| LazySignals::option(title),
+            vec![title],
+            commands
+        );
+        let mirrored = LazySignals.in_context("sim", mirrored, commands);
+        let shown = LazySignals.effect::<(Option<String>,)>(
+            |_, _world| {
+                SHOWN.store(true, Ordering::SeqCst);
+                None
+            },
+            vec![title],
+            vec![],
+            commands
+        );
+        LazySignals.with_class(ProcessingClass::Ui, shown, commands);
+        let dialog = commands.spawn_empty().push_children(&[title]).id();
+        (dialog, title, mirrored)
+    });
+    t.step();
+    SHOWN.store(false, Ordering::SeqCst);
+
+    // both subscribers are in the subgraph but wait for their own schedules
+    t.commands(|commands| LazySignals.send(title, "quit?".to_string(), commands));
+    assert!(LazySignals.process_subgraph(dialog, t.world_mut()));
+    t.assert_signal_eq(title, "quit?".to_string());
+    t.assert_signal_none::<String>(mirrored);
+    assert!(!SHOWN.load(Ordering::SeqCst));
+
+    t.step();
+    t.assert_signal_eq(mirrored, "quit?".to_string());
+    assert!(SHOWN.load(Ordering::SeqCst));
+}
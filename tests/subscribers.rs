@@ -0,0 +1,36 @@
+use bevy_lazy_signals::{ api::LazySignals, framework::*, testing::LazySignalsTestApp };
+
+#[test]
+fn wide_fan_out_is_indexed_and_propagated() {
+    let mut t = LazySignalsTestApp::new();
+    let (signal, trigger, memos, effect) = t.commands(|commands| {
+        let signal = LazySignals.state(1u32, commands);
+        let trigger = LazySignals.state((), commands);
+        let memos: Vec<_> = (0..500u32)
+            .map(|offset| {
+                LazySignals.computed::<(Option<u32>,), u32>(
+                    move |(value,)| LazySignals::result(value.unwrap_or(0) + offset),
+                    vec![signal],
+                    commands
+                )
+            })
+            .collect();
+        let effect = LazySignals.effect::<()>(|_, _world| None, vec![], vec![trigger], commands);
+        (signal, trigger, memos, effect)
+    });
+    t.step();
+
+    let dependents = t.world().resource::<LazySignalsDependents>();
+    let mut indexed: Vec<_> = dependents.get(signal).collect();
+    indexed.sort();
+    let mut expected = memos.clone();
+    expected.sort();
+    assert_eq!(indexed, expected);
+    assert_eq!(dependents.get(trigger).collect::<Vec<_>>(), vec![effect]);
+
+    t.commands(|commands| LazySignals.send(signal, 10u32, commands));
+    t.step();
+    for (offset, memo) in memos.iter().enumerate() {
+        t.assert_signal_eq(*memo, 10 + offset as u32);
+    }
+}
@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    framework::*,
+    introspection::LazySignalsMeta,
+    testing::LazySignalsTestApp,
+};
+
+fn combat(entity: Entity, commands: &mut Commands) -> Entity {
+    LazySignals.meta(entity, LazySignalsMeta::default().with_tag("combat"), commands)
+}
+
+#[test]
+fn tagged_operations_apply_to_a_whole_feature() {
+    let mut t = LazySignalsTestApp::new();
+    let (health, damage, music) = t.commands(|commands| {
+        let health = combat(LazySignals.state(100u32, commands), commands);
+        let damage = LazySignals.computed::<(Option<u32>,), u32>(
+            |(health,)| LazySignals::result(100 - health.unwrap_or(0)),
+            vec![health],
+            commands
+        );
+        let damage = combat(damage, commands);
+        let music = LazySignals.state(1u32, commands);
+        (health, damage, music)
+    });
+    t.commands(|commands| LazySignals.send(health, 80u32, commands));
+    t.step();
+    t.assert_signal_eq(damage, 20u32);
+
+    // a paused feature holds its sends until it resumes
+    t.commands(|commands| {
+        LazySignals.pause_tagged("combat", commands);
+        LazySignals.send(health, 50u32, commands);
+        LazySignals.send(music, 2u32, commands);
+    });
+    t.step();
+    t.assert_signal_eq(health, 80u32);
+    t.assert_signal_eq(music, 2u32);
+    t.commands(|commands| LazySignals.resume_tagged("combat", commands));
+    t.step();
+    t.assert_signal_eq(damage, 50u32);
+
+    let snapshot = LazySignals.snapshot_tagged("combat", t.world_mut());
+    assert_eq!(snapshot.paths().count(), 2);
+
+    t.commands(|commands| LazySignals.reset_tagged("combat", commands));
+    t.step();
+    t.assert_signal_eq(health, 100u32);
+    t.assert_signal_eq(damage, 0u32);
+
+    snapshot.restore(t.world_mut());
+    t.step();
+    t.assert_signal_eq(health, 50u32);
+
+    t.commands(|commands| LazySignals.despawn_tagged("combat", DependentPolicy::Error, commands));
+    t.step();
+    assert!(t.world().get_entity(health).is_none());
+    assert!(t.world().get_entity(damage).is_none());
+    t.assert_signal_eq(music, 2u32);
+}
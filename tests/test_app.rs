@@ -0,0 +1,268 @@
+use std::{ sync::atomic::{ AtomicU32, Ordering }, time::Duration };
+
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{ api::LazySignals, framework::*, testing::LazySignalsTestApp };
+
+// a signal feeding a computed feeding a computed
+fn chain(t: &mut LazySignalsTestApp) -> (Entity, Entity, Entity) {
+    t.commands(|commands| {
+        let signal = LazySignals.state(1u32, commands);
+        let double = LazySignals.computed::<(Option<u32>,), u32>(
+            |(value,)| LazySignals::result(value.unwrap_or(0) * 2),
+            vec![signal],
+            commands
+        );
+        let plus_one = LazySignals.computed::<(Option<u32>,), u32>(
+            |(value,)| LazySignals::result(value.unwrap_or(0) + 1),
+            vec![double],
+            commands
+        );
+        (signal, double, plus_one)
+    })
+}
+
+#[test]
+fn step_propagates_a_send() {
+    let mut t = LazySignalsTestApp::new();
+    let (signal, double, plus_one) = chain(&mut t);
+    t.step();
+    t.assert_signal_eq(signal, 1u32);
+
+    t.commands(|commands| LazySignals.send(signal, 3u32, commands));
+    t.step();
+    t.assert_signal_eq(signal, 3u32);
+    t.assert_signal_eq(double, 6u32);
+    t.assert_signal_eq(plus_one, 7u32);
+}
+
+#[test]
+fn flush_settles_without_an_update() {
+    let mut t = LazySignalsTestApp::new();
+    let (signal, _, plus_one) = chain(&mut t);
+    let out = t.commands(|commands| {
+        let out = LazySignals.state(0u32, commands);
+        LazySignals.effect::<(Option<u32>,)>(
+            move |(value,), world| {
+                LazySignals.send_now(out, value.unwrap_or(0) * 10, world);
+                None
+            },
+            vec![plus_one],
+            vec![],
+            commands
+        );
+        out
+    });
+    t.flush();
+
+    t.commands(|commands| LazySignals.send(signal, 10u32, commands));
+    t.flush();
+    t.assert_signal_eq(plus_one, 21u32);
+    t.assert_signal_eq(out, 210u32);
+}
+
+#[test]
+fn effect_runs_once_per_change() {
+    static RUNS: AtomicU32 = AtomicU32::new(0);
+
+    let mut t = LazySignalsTestApp::new();
+    let signal = t.commands(|commands| {
+        let signal = LazySignals.state(false, commands);
+        let not = LazySignals.computed::<(Option<bool>,), bool>(
+            |(value,)| LazySignals::result(!value.unwrap_or(false)),
+            vec![signal],
+            commands
+        );
+        LazySignals.effect::<(Option<bool>,)>(
+            |_, _| {
+                RUNS.fetch_add(1, Ordering::SeqCst);
+                None
+            },
+            vec![not],
+            vec![],
+            commands
+        );
+        signal
+    });
+    t.step();
+    t.commands(|commands| LazySignals.send(signal, true, commands));
+    t.step_n(2);
+    assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+
+    // the same value again is not a change
+    t.commands(|commands| LazySignals.send(signal, true, commands));
+    t.step();
+    assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn send_now_merges_immediately() {
+    let mut t = LazySignalsTestApp::new();
+    let (signal, double, _) = chain(&mut t);
+    t.step();
+
+    LazySignals.send_now(signal, 5u32, t.world_mut());
+    t.assert_signal_eq(signal, 5u32);
+    t.step();
+    t.assert_signal_eq(double, 10u32);
+}
+
+#[test]
+fn assert_signal_none_for_a_trigger() {
+    let mut t = LazySignalsTestApp::new();
+    let trigger = t.commands(|commands| LazySignals.payload_trigger::<u32>(commands));
+    t.step();
+    t.assert_signal_none::<u32>(trigger);
+}
+
+#[test]
+fn errors_chain_to_their_origin() {
+    let mut t = LazySignalsTestApp::new();
+    let (signal, checked, last) = t.commands(|commands| {
+        let signal = LazySignals.state(1u32, commands);
+        let checked = LazySignals.computed::<(Option<u32>,), u32>(
+            |(value,)| match value {
+                Some(0) => LazySignals::error(LazySignalsError::ReadError(Entity::PLACEHOLDER)),
+                value => LazySignals::result(value.unwrap_or(0)),
+            },
+            vec![signal],
+            commands
+        );
+        let last = LazySignals.computed::<(Option<u32>,), u32>(
+            |(value,)| match value {
+                Some(value) => LazySignals::result(value + 1),
+                None => LazySignals::error(LazySignalsError::NoSignalError),
+            },
+            vec![checked],
+            commands
+        );
+        (signal, checked, last)
+    });
+    t.step();
+    t.commands(|commands| LazySignals.send(signal, 0u32, commands));
+    t.step();
+    let error = LazySignals.get_error::<u32>(last, t.world()).unwrap();
+    assert_eq!(error.origin(), Some(checked));
+    assert_eq!(*error.root_cause(), LazySignalsError::ReadError(Entity::PLACEHOLDER));
+
+    t.commands(|commands| LazySignals.send(signal, 5u32, commands));
+    t.step();
+    t.assert_signal_eq(last, 6u32);
+}
+
+#[test]
+fn snapshot_and_assert_changed() {
+    let mut t = LazySignalsTestApp::new();
+    let (volume, _) = t.commands(|commands| {
+        let volume = LazySignals.state(5u32, commands);
+        let muted = LazySignals.state(false, commands);
+        LazySignals.register_path("settings.volume", volume, commands);
+        LazySignals.register_path("settings.muted", muted, commands);
+        (volume, muted)
+    });
+    t.step();
+    let before = t.snapshot("settings.");
+
+    t.commands(|commands| LazySignals.send(volume, 7u32, commands));
+    t.step();
+    t.assert_changed(&before, "settings.", &["settings.volume"]);
+}
+
+#[test]
+fn advance_clock_expires_a_cooldown() {
+    let mut t = LazySignalsTestApp::new();
+    let (fire, cooling) = t.commands(|commands| {
+        let fire = LazySignals.state((), commands);
+        let cooling = LazySignals.cooldown(fire, Duration::from_secs(5), commands);
+        (fire, cooling)
+    });
+    t.advance_clock(Duration::ZERO).step();
+    t.commands(|commands| LazySignals.trigger(fire, commands));
+    t.flush();
+    t.assert_signal_eq(cooling, true);
+
+    t.advance_clock(Duration::from_secs(1)).step_n(2);
+    t.assert_signal_eq(cooling, true);
+    t.advance_clock(Duration::from_secs(5)).step_n(2);
+    t.assert_signal_eq(cooling, false);
+}
+
+#[test]
+fn flush_advances_cooldowns() {
+    let mut t = LazySignalsTestApp::new();
+    let (fire, cooling) = t.commands(|commands| {
+        let fire = LazySignals.state((), commands);
+        let cooling = LazySignals.cooldown(fire, Duration::from_secs(5), commands);
+        (fire, cooling)
+    });
+    t.advance_clock(Duration::ZERO).step();
+    t.commands(|commands| LazySignals.trigger(fire, commands));
+    t.flush();
+    t.assert_signal_eq(cooling, true);
+
+    // no update runs, so only the pre-pass in the flush schedule can end the cooldown
+    t.advance_clock(Duration::from_secs(6)).flush();
+    t.assert_signal_eq(cooling, false);
+}
+
+#[test]
+fn nothing_below_an_unchanged_computed_runs() {
+    static RUNS: AtomicU32 = AtomicU32::new(0);
+
+    let mut t = LazySignalsTestApp::new();
+    let (signal, parity, below) = t.commands(|commands| {
+        let signal = LazySignals.state(1u32, commands);
+        let parity = LazySignals.computed::<(Option<u32>,), bool>(
+            |(value,)| LazySignals::result(value.unwrap_or(0) % 2 == 0),
+            vec![signal],
+            commands
+        );
+        let below = LazySignals.computed::<(Option<bool>,), bool>(
+            |(even,)| LazySignals::result(!even.unwrap_or(false)),
+            vec![parity],
+            commands
+        );
+        LazySignals.effect::<(Option<bool>,)>(
+            |_, _| {
+                RUNS.fetch_add(1, Ordering::SeqCst);
+                None
+            },
+            vec![parity],
+            vec![],
+            commands
+        );
+        (signal, parity, below)
+    });
+    t.step();
+    t.commands(|commands| LazySignals.send(signal, 2u32, commands));
+    t.step();
+    assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+
+    // still even, so the parity recomputes without changing and nothing below it runs
+    t.commands(|commands| LazySignals.send(signal, 4u32, commands));
+    t.step();
+    assert_eq!(t.recompute_order(), vec![parity]);
+    assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+    t.assert_signal_eq(below, false);
+}
+
+#[test]
+fn flush_settles_a_three_deep_graph() {
+    let mut t = LazySignalsTestApp::new();
+    let (signal, _, plus_one) = chain(&mut t);
+    let squared = t.commands(|commands| {
+        LazySignals.computed::<(Option<u32>,), u32>(
+            |(value,)| LazySignals::result(value.unwrap_or(0).pow(2)),
+            vec![plus_one],
+            commands
+        )
+    });
+    t.flush();
+    t.assert_signal_none::<u32>(squared);
+
+    // one flush is enough for the change to reach the bottom, without stepping
+    t.commands(|commands| LazySignals.send(signal, 4u32, commands));
+    t.flush();
+    t.assert_signal_eq(plus_one, 9u32);
+    t.assert_signal_eq(squared, 81u32);
+}
@@ -0,0 +1,46 @@
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+#[test]
+fn comparisons_against_thresholds_and_each_other() {
+    let mut t = LazySignalsTestApp::new();
+    let (health, max, low, full, same) = t.commands(|commands| {
+        let health = LazySignals.state(100u32, commands);
+        let max = LazySignals.state(100u32, commands);
+        let low = LazySignals.less_than(health, 20u32, commands);
+        let full = LazySignals.greater_than(health, 99u32, commands);
+        let same = LazySignals.equals::<u32>(health, max, commands);
+        (health, max, low, full, same)
+    });
+    t.commands(|commands| {
+        LazySignals.send(max, 100u32, commands);
+        LazySignals.send(health, 15u32, commands);
+    });
+    t.step();
+    t.assert_signal_eq(low, true);
+    t.assert_signal_eq(full, false);
+    t.assert_signal_eq(same, false);
+
+    t.commands(|commands| LazySignals.send(health, 100u32, commands));
+    t.step();
+    t.assert_signal_eq(low, false);
+    t.assert_signal_eq(full, true);
+    t.assert_signal_eq(same, true);
+}
+
+#[test]
+fn hysteresis_does_not_flicker_between_thresholds() {
+    let mut t = LazySignalsTestApp::new();
+    let (heat, overheated) = t.commands(|commands| {
+        let heat = LazySignals.state(0u32, commands);
+        let overheated = LazySignals.hysteresis(heat, 80u32, 60u32, commands);
+        (heat, overheated)
+    });
+
+    let mut seen = Vec::new();
+    for value in [50u32, 85, 70, 79, 55, 70] {
+        t.commands(|commands| LazySignals.send(heat, value, commands));
+        t.step();
+        seen.push(t.read::<bool>(overheated).unwrap());
+    }
+    assert_eq!(seen, vec![false, true, true, true, false, false]);
+}
@@ -0,0 +1,47 @@
+use std::sync::atomic::{ AtomicU32, Ordering };
+
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+static RUNS: AtomicU32 = AtomicU32::new(0);
+
+#[test]
+fn tracked_computed_subscribes_only_to_the_branch_it_read() {
+    let mut t = LazySignalsTestApp::new();
+    let (flag, a, b, picked) = t.commands(|commands| {
+        let flag = LazySignals.state(true, commands);
+        let a = LazySignals.state(1u32, commands);
+        let b = LazySignals.state(10u32, commands);
+        let picked = LazySignals.tracked_computed::<u32>(
+            move |ctx| {
+                RUNS.fetch_add(1, Ordering::SeqCst);
+                let value = if ctx.get::<bool>(flag).unwrap_or(false) {
+                    ctx.get::<u32>(a)
+                } else {
+                    ctx.get::<u32>(b)
+                };
+                LazySignals::result(value.unwrap_or(0))
+            },
+            commands
+        );
+        (flag, a, b, picked)
+    });
+    t.step();
+    t.assert_signal_eq(picked, 1u32);
+
+    // b was not read, so sending it does not recompute
+    let runs = RUNS.load(Ordering::SeqCst);
+    t.commands(|commands| LazySignals.send(b, 20u32, commands));
+    t.step();
+    assert_eq!(RUNS.load(Ordering::SeqCst), runs);
+
+    t.commands(|commands| LazySignals.send(flag, false, commands));
+    t.step();
+    t.assert_signal_eq(picked, 20u32);
+
+    // now a is the one left out
+    let runs = RUNS.load(Ordering::SeqCst);
+    t.commands(|commands| LazySignals.send(a, 2u32, commands));
+    t.step();
+    assert_eq!(RUNS.load(Ordering::SeqCst), runs);
+    t.assert_signal_eq(picked, 20u32);
+}
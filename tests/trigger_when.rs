@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+#[derive(Resource, Default)]
+struct Armed(bool);
+
+#[derive(Resource, Default)]
+struct Fired(u32);
+
+#[test]
+fn trigger_fires_on_the_rising_edge_of_a_condition() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.init_resource::<Armed>().init_resource::<Fired>();
+    let trigger = LazySignals.trigger_when(|armed: Res<Armed>| armed.0, &mut t.app);
+    t.commands(|commands| {
+        LazySignals.effect::<()>(
+            |(), world| {
+                world.resource_mut::<Fired>().0 += 1;
+                None
+            },
+            vec![],
+            vec![trigger],
+            commands
+        )
+    });
+    t.step();
+    assert_eq!(t.world().resource::<Fired>().0, 0);
+
+    t.world_mut().resource_mut::<Armed>().0 = true;
+    t.step_n(3);
+    assert_eq!(t.world().resource::<Fired>().0, 1);
+
+    // it has to go false before it fires again
+    t.world_mut().resource_mut::<Armed>().0 = false;
+    t.step();
+    t.world_mut().resource_mut::<Armed>().0 = true;
+    t.step();
+    assert_eq!(t.world().resource::<Fired>().0, 2);
+}
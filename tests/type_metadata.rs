@@ -0,0 +1,63 @@
+use std::any::TypeId;
+
+use bevy::{ ecs::event::ManualEventReader, prelude::* };
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    framework::{ lazy_immutable::{ LazySignalsState, ReflectLazySignalsDataType }, * },
+    testing::LazySignalsTestApp,
+};
+
+#[test]
+fn init_checks_source_types_from_the_cached_metadata() {
+    let mut t = LazySignalsTestApp::new();
+    let mut reader = ManualEventReader::<SourceTypeMismatch>::default();
+    let count = t.commands(|commands| LazySignals.state(1u32, commands));
+    t.step();
+
+    // warm the cache with a subscriber of the right type
+    t.commands(|commands| {
+        LazySignals.computed::<(Option<u32>,), u32>(
+            |(count,)| LazySignals::option(count),
+            vec![count],
+            commands
+        )
+    });
+    t.step();
+    let component_id = t.world().component_id::<LazySignalsState<u32>>().unwrap();
+    let accessors = t.world().resource::<LazySignalsAccessors>();
+    let accessor = accessors.get(component_id).unwrap();
+    assert_eq!(accessor.param_type().map(|(id, _)| id), Some(TypeId::of::<Option<u32>>()));
+    assert_eq!(accessor.data_type().map(|info| info.type_path()), Some("u32"));
+
+    // a later subscriber of the wrong type is still caught from the cached metadata
+    let wrong = t.commands(|commands| {
+        LazySignals.computed::<(Option<String>,), String>(
+            |(text,)| LazySignals::option(text),
+            vec![count],
+            commands
+        )
+    });
+    t.step();
+    let events = t.world().resource::<Events<SourceTypeMismatch>>();
+    let found: Vec<_> = reader.read(events).cloned().collect();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].subscriber, wrong);
+    assert_eq!(found[0].expected, "core::option::Option<alloc::string::String>");
+    assert_eq!(found[0].found, "core::option::Option<u32>");
+}
+
+#[test]
+fn registration_records_the_types_of_a_state() {
+    let t = LazySignalsTestApp::new();
+    let type_registry = t.world().resource::<AppTypeRegistry>().read();
+    let state_id = TypeId::of::<LazySignalsState<String>>();
+    let types = type_registry.get_type_data::<ReflectLazySignalsDataType>(state_id).unwrap();
+    assert_eq!(types.param_type.0, TypeId::of::<Option<String>>());
+    assert_eq!(types.param_type.1, "core::option::Option<alloc::string::String>");
+    assert_eq!(types.data_type.type_id(), TypeId::of::<String>());
+
+    let accessor = ObservableAccessor::resolve(state_id, &type_registry).unwrap();
+    assert_eq!(accessor.param_type(), Some(types.param_type));
+    assert_eq!(accessor.data_type().map(|info| info.type_path()), Some("alloc::string::String"));
+}
@@ -0,0 +1,38 @@
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+#[test]
+fn unwrapped_computeds_wait_for_every_source() {
+    let mut t = LazySignalsTestApp::new();
+    let (logged_in, user, greeting) = t.commands(|commands| {
+        let logged_in = LazySignals.state(false, commands);
+        let user = LazySignals.state(0u32, commands);
+        let name = LazySignals.computed::<(Option<u32>,), String>(
+            |(user,)| LazySignals::result(format!("player{}", user.unwrap_or(0))),
+            vec![user],
+            commands
+        );
+        let greeting = LazySignals.computed_unwrapped::<(bool, String), String>(
+            |(logged_in, name)| {
+                LazySignals::result(match logged_in {
+                    true => format!("hi {}", name),
+                    false => "bye".to_string(),
+                })
+            },
+            vec![logged_in, name],
+            commands
+        );
+        (logged_in, user, greeting)
+    });
+    t.step();
+    t.commands(|commands| LazySignals.send(logged_in, true, commands));
+    t.step();
+    t.assert_signal_none::<String>(greeting);
+
+    t.commands(|commands| LazySignals.send(user, 7u32, commands));
+    t.step();
+    t.assert_signal_eq(greeting, "hi player7".to_string());
+
+    t.commands(|commands| LazySignals.send(logged_in, false, commands));
+    t.step();
+    t.assert_signal_eq(greeting, "bye".to_string());
+}
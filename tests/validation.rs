@@ -0,0 +1,57 @@
+use bevy::{ ecs::event::ManualEventReader, prelude::* };
+
+use bevy_lazy_signals::{ api::LazySignals, framework::*, testing::LazySignalsTestApp };
+
+fn in_range(volume: &u32) -> Result<(), String> {
+    match *volume <= 10 {
+        true => Ok(()),
+        false => Err(format!("volume {} is over 10", volume)),
+    }
+}
+
+fn rejections(
+    t: &LazySignalsTestApp,
+    reader: &mut ManualEventReader<SignalRejected>
+) -> Vec<(Entity, bool)> {
+    let events = t.world().resource::<Events<SignalRejected>>();
+    reader.read(events).map(|rejected| (rejected.entity, rejected.clamped)).collect()
+}
+
+#[test]
+fn invalid_values_are_dropped_with_an_event() {
+    let mut t = LazySignalsTestApp::new();
+    let volume = t.commands(|commands| {
+        let volume = LazySignals.state(5u32, commands);
+        LazySignals.validate(volume, in_range, commands);
+        volume
+    });
+    t.step();
+    let mut reader = t.world().resource::<Events<SignalRejected>>().get_reader();
+
+    t.commands(|commands| LazySignals.send(volume, 11u32, commands));
+    t.step();
+    t.assert_signal_eq(volume, 5u32);
+    assert_eq!(rejections(&t, &mut reader), vec![(volume, false)]);
+
+    t.commands(|commands| LazySignals.send(volume, 8u32, commands));
+    t.step();
+    t.assert_signal_eq(volume, 8u32);
+    assert!(rejections(&t, &mut reader).is_empty());
+}
+
+#[test]
+fn clamped_values_are_sent_instead() {
+    let mut t = LazySignalsTestApp::new();
+    let volume = t.commands(|commands| {
+        let volume = LazySignals.state(5u32, commands);
+        LazySignals.validate_clamped(volume, in_range, |volume: u32| volume.min(10), commands);
+        volume
+    });
+    t.step();
+    let mut reader = t.world().resource::<Events<SignalRejected>>().get_reader();
+
+    t.commands(|commands| LazySignals.send(volume, 42u32, commands));
+    t.step();
+    t.assert_signal_eq(volume, 10u32);
+    assert_eq!(rejections(&t, &mut reader), vec![(volume, true)]);
+}
@@ -0,0 +1,43 @@
+use std::sync::atomic::{ AtomicU32, Ordering };
+
+use bevy_lazy_signals::{ api::LazySignals, framework::*, testing::LazySignalsTestApp };
+
+static RUNS: AtomicU32 = AtomicU32::new(0);
+
+#[test]
+fn computed_skips_a_recompute_when_its_source_versions_match() {
+    let mut t = LazySignalsTestApp::new();
+    let (score, tens, label) = t.commands(|commands| {
+        let score = LazySignals.state(0u32, commands);
+        let tens = LazySignals.computed::<(Option<u32>,), u32>(
+            |(score,)| LazySignals::result(score.unwrap_or(0) / 10),
+            vec![score],
+            commands
+        );
+        let label = LazySignals.computed::<(Option<u32>,), String>(
+            |(tens,)| {
+                RUNS.fetch_add(1, Ordering::SeqCst);
+                LazySignals::result(format!("{}0+", tens.unwrap_or(0)))
+            },
+            vec![tens],
+            commands
+        );
+        (score, tens, label)
+    });
+    t.commands(|commands| LazySignals.send(score, 11u32, commands));
+    t.step();
+    t.assert_signal_eq(label, "10+".to_string());
+    assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+    assert_eq!(t.world().get::<SourceVersions>(label).unwrap().versions.len(), 1);
+
+    // the score changing marks the whole chain, but tens keeps its value and version
+    t.commands(|commands| LazySignals.send(score, 12u32, commands));
+    t.step();
+    t.assert_signal_eq(tens, 1u32);
+    assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+
+    t.commands(|commands| LazySignals.send(score, 25u32, commands));
+    t.step();
+    t.assert_signal_eq(label, "20+".to_string());
+    assert_eq!(RUNS.load(Ordering::SeqCst), 2);
+}
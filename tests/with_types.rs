@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp, LazySignalsPlugin };
+
+#[derive(Reflect, Clone, Copy, PartialEq, Debug)]
+struct Health {
+    current: u32,
+    max: u32,
+}
+
+#[derive(Reflect, Clone, Copy, PartialEq, Debug)]
+enum Stance {
+    Standing,
+    Crouching,
+}
+
+#[test]
+fn with_types_registers_a_tuple_of_types() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, LazySignalsPlugin::with_types::<(Health, Stance)>()));
+    let mut t = LazySignalsTestApp { app };
+    let (health, stance, low) = t.commands(|commands| {
+        let health = LazySignals.state(Health { current: 80, max: 100 }, commands);
+        let stance = LazySignals.state(Stance::Standing, commands);
+        let low = LazySignals.computed::<(Option<Health>,), bool>(
+            |(health,)| LazySignals::option(health.map(|h| h.current * 4 < h.max)),
+            vec![health],
+            commands
+        );
+        (health, stance, low)
+    });
+    t.step();
+
+    t.commands(|commands| {
+        LazySignals.send(stance, Stance::Crouching, commands);
+        LazySignals.send(health, Health { current: 20, max: 100 }, commands);
+    });
+    t.step();
+    t.assert_signal_eq(stance, Stance::Crouching);
+    t.assert_signal_eq(low, true);
+
+    let registry = t.world().resource::<AppTypeRegistry>().read();
+    assert!(registry.contains(std::any::TypeId::of::<(Option<Health>,)>()));
+    assert!(registry.contains(std::any::TypeId::of::<(Option<Stance>,)>()));
+}
@@ -0,0 +1,149 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    framework::*,
+    testing::LazySignalsTestApp,
+    LazySignalsAppExt,
+};
+
+// whether the guarded signal may be written, standing in for "the server sync system is running"
+#[derive(Resource)]
+struct Unlocked(bool);
+
+fn guarded(t: &mut LazySignalsTestApp) -> Entity {
+    t.world_mut().insert_resource(Unlocked(true));
+    let signal = t.commands(|commands| {
+        let signal = LazySignals.state(1u32, commands);
+        LazySignals.write_guard(signal, |world| world.resource::<Unlocked>().0, commands);
+        LazySignals.register_path("match.score", signal, commands);
+        signal
+    });
+    t.step();
+    signal
+}
+
+fn lock(t: &mut LazySignalsTestApp) {
+    t.world_mut().resource_mut::<Unlocked>().0 = false;
+}
+
+fn denied(t: &LazySignalsTestApp) -> usize {
+    let events = t.world().resource::<Events<SignalWriteDenied>>();
+    events.get_reader().read(events).count()
+}
+
+#[test]
+fn guard_refuses_a_send() {
+    let mut t = LazySignalsTestApp::new();
+    let signal = guarded(&mut t);
+    lock(&mut t);
+    t.commands(|commands| LazySignals.send(signal, 2u32, commands));
+    t.step();
+    t.assert_signal_eq(signal, 1u32);
+    assert_eq!(denied(&t), 1);
+}
+
+#[test]
+fn guard_refuses_set_from_reflect() {
+    let mut t = LazySignalsTestApp::new();
+    let signal = guarded(&mut t);
+    LazySignals.set_from_reflect(signal, &2u32, t.world_mut()).unwrap();
+    t.step();
+    t.assert_signal_eq(signal, 2u32);
+
+    lock(&mut t);
+    let refused = LazySignals.set_from_reflect(signal, &3i64, t.world_mut());
+    assert_eq!(refused, Err(LazySignalsError::SendRefused(signal)));
+    t.step();
+    t.assert_signal_eq(signal, 2u32);
+    assert_eq!(denied(&t), 1);
+}
+
+#[test]
+fn guard_refuses_a_snapshot_restore() {
+    let mut t = LazySignalsTestApp::new();
+    let signal = guarded(&mut t);
+    let snapshot = t.snapshot("match.");
+    t.commands(|commands| LazySignals.send(signal, 5u32, commands));
+    t.step();
+
+    lock(&mut t);
+    snapshot.restore(t.world_mut());
+    t.step();
+    t.assert_signal_eq(signal, 5u32);
+    assert_eq!(denied(&t), 1);
+}
+
+#[test]
+fn guard_refuses_an_optimistic_rollback() {
+    let mut t = LazySignalsTestApp::new();
+    let signal = guarded(&mut t);
+    let confirmed = t.commands(|commands| {
+        let confirmed = LazySignals.state((), commands);
+        LazySignals.send_optimistic(signal, 9u32, confirmed, Duration::from_secs(1), commands);
+        confirmed
+    });
+    t.advance_clock(Duration::ZERO).step();
+    t.assert_signal_eq(signal, 9u32);
+
+    // never confirmed, but the signal is locked by the time the rollback comes due
+    lock(&mut t);
+    t.advance_clock(Duration::from_secs(2)).step_n(2);
+    t.assert_signal_eq(signal, 9u32);
+    assert_eq!(denied(&t), 1);
+    assert!(t.world().get_entity(confirmed).is_some());
+}
+
+#[test]
+fn reflected_writes_run_middleware_and_validators() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.add_send_middleware(|_, value, _| {
+        if let Some(value) = value.downcast_mut::<u32>() {
+            *value += 1;
+        }
+        bevy_lazy_signals::middleware::MiddlewareDecision::Continue
+    });
+    let signal = t.commands(|commands| {
+        let signal = LazySignals.state(0u32, commands);
+        LazySignals.validate::<u32>(
+            signal,
+            |value| if *value < 10 { Ok(()) } else { Err("too big".to_string()) },
+            commands
+        );
+        signal
+    });
+    t.step();
+
+    LazySignals.set_from_reflect(signal, &4i64, t.world_mut()).unwrap();
+    t.step();
+    t.assert_signal_eq(signal, 5u32);
+
+    // 9 becomes 10 in the middleware, which the validator rejects
+    let refused = LazySignals.set_from_reflect(signal, &9u32, t.world_mut());
+    assert_eq!(refused, Err(LazySignalsError::SendRefused(signal)));
+    t.step();
+    t.assert_signal_eq(signal, 5u32);
+    let rejected = t.world().resource::<Events<SignalRejected>>();
+    assert_eq!(rejected.get_reader().read(rejected).count(), 1);
+}
+
+#[cfg(feature = "replication")]
+#[test]
+fn guard_refuses_a_replicated_value() {
+    use bevy_lazy_signals::replication::*;
+
+    let mut t = LazySignalsTestApp::new();
+    t.app.add_plugins(LazySignalsReplicationPlugin::client());
+    let signal = guarded(&mut t);
+    t.world_mut().entity_mut(signal).insert(Replicated { name: "score".to_string() });
+    lock(&mut t);
+    t.world_mut().send_event(ApplyReplicatedSignal {
+        name: "score".to_string(),
+        value: Some("{\"u32\":7}".to_string()),
+    });
+    t.step_n(2);
+    t.assert_signal_eq(signal, 1u32);
+    assert_eq!(denied(&t), 1);
+}
@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{ api::LazySignals, testing::LazySignalsTestApp };
+
+#[derive(Component, Default)]
+struct Label(String);
+
+#[test]
+fn computed_writes_its_value_into_the_target_component() {
+    let mut t = LazySignalsTestApp::new();
+    let target = t.world_mut().spawn(Label::default()).id();
+    let (score, text) = t.commands(|commands| {
+        let score = LazySignals.state(3u32, commands);
+        let text = LazySignals.computed_into::<Label, (Option<u32>,), String>(
+            target,
+            |(score,)| LazySignals::result(format!("score: {}", score.unwrap_or(0))),
+            vec![score],
+            |label, text| {
+                label.0 = text.clone();
+            },
+            commands
+        );
+        (score, text)
+    });
+
+    // the target starts out in sync, without a send
+    t.step();
+    assert_eq!(t.world().get::<Label>(target).unwrap().0, "score: 3");
+
+    t.commands(|commands| LazySignals.send(score, 10u32, commands));
+    t.step();
+    assert_eq!(t.world().get::<Label>(target).unwrap().0, "score: 10");
+    t.assert_signal_eq(text, "score: 10".to_string());
+}
@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+
+use bevy_lazy_signals::{
+    api::LazySignals,
+    testing::LazySignalsTestApp,
+    writer::{ LazySignalsSendQueue, SignalWriter },
+    LazySignalsAppExt,
+};
+
+#[derive(Resource)]
+struct Cells(Vec<Entity>);
+
+fn fill_cells(cells: Res<Cells>, mut writer: SignalWriter<u32>) {
+    for (index, cell) in cells.0.iter().enumerate() {
+        writer.send(*cell, index as u32 + 1);
+    }
+}
+
+#[test]
+fn writer_sends_are_applied_in_the_same_frame() {
+    let mut t = LazySignalsTestApp::new();
+    t.app.register_signal_writer::<u32>();
+    let (cells, total) = t.commands(|commands| {
+        let cells: Vec<_> = (0..3).map(|_| LazySignals.state(0u32, commands)).collect();
+        let total = LazySignals.computed::<(Option<u32>, Option<u32>, Option<u32>), u32>(
+            |(a, b, c)| LazySignals::result(a.unwrap_or(0) + b.unwrap_or(0) + c.unwrap_or(0)),
+            cells.clone(),
+            commands
+        );
+        (cells, total)
+    });
+    t.app.insert_resource(Cells(cells.clone())).add_systems(First, fill_cells);
+    t.step();
+
+    for (cell, value) in cells.iter().zip(1..=3u32) {
+        t.assert_signal_eq(*cell, value);
+    }
+    t.assert_signal_eq(total, 6u32);
+
+    // nothing is left over for the next frame
+    assert!(t.world().resource::<LazySignalsSendQueue<u32>>().is_empty());
+}